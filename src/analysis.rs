@@ -0,0 +1,229 @@
+use board::Board;
+use moves::Move::{self, Place};
+use player::strategy::unbeatable::{self, Unbeatable, WIN_SCORE};
+use rules;
+use solver;
+use token::Token::{self, Cross, Nought};
+
+const MAX_OPEN_SPOTS: usize = 10;
+const FORK: usize = 2;
+
+/// What a move leads to with perfect play from both sides, counting the
+/// plies until the game ends, the move itself included.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Value {
+    Win(usize),
+    Draw,
+    Loss(usize),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Reason {
+    WinsNow,
+    BlocksWin,
+    CreatesFork,
+    BlocksFork,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Analysis {
+    pub choice: Move,
+    pub value: Value,
+    pub reasons: Vec<Reason>,
+}
+
+impl Value {
+    pub fn to_text(&self) -> String {
+        match *self {
+            Value::Win(plies) => format!("wins in {}", plies),
+            Value::Draw => String::from("draws"),
+            Value::Loss(plies) => format!("loses in {}", plies),
+        }
+    }
+
+    /// Win, draw or loss, ignoring how soon it comes: 1, 0 or -1.
+    pub fn outcome(&self) -> i8 {
+        match *self {
+            Value::Win(_) => 1,
+            Value::Draw => 0,
+            Value::Loss(_) => -1,
+        }
+    }
+
+    pub fn outcome_str(&self) -> &str {
+        match *self {
+            Value::Win(_) => "a win",
+            Value::Draw => "a draw",
+            Value::Loss(_) => "a loss",
+        }
+    }
+
+    // Sooner wins first, then draws, then later losses.
+    fn rank(&self) -> i64 {
+        match *self {
+            Value::Win(plies) => i64::from(WIN_SCORE) * 100 - plies as i64,
+            Value::Draw => 0,
+            Value::Loss(plies) => plies as i64 - i64::from(WIN_SCORE) * 100,
+        }
+    }
+}
+
+impl Reason {
+    pub fn to_str(&self) -> &str {
+        match *self {
+            Reason::WinsNow => "wins immediately",
+            Reason::BlocksWin => "blocks a win",
+            Reason::CreatesFork => "creates a fork",
+            Reason::BlocksFork => "blocks a fork",
+        }
+    }
+}
+
+/// Every legal move for a token, best first, with its value and the tactical
+/// reasons for it. Only boards the full search can finish are analysed.
+pub fn analyse(board: &Board, token: &Token) -> Result<Vec<Analysis>, String> {
+    if !solver::is_plain(board) {
+        return Err(String::from("Only open two-player k in a row boards can be analysed."));
+    }
+    if board.empty_cells().len() > MAX_OPEN_SPOTS {
+        return Err(format!(
+            "Positions with over {} open spots are too large to analyse.",
+            MAX_OPEN_SPOTS
+        ));
+    }
+    if rules::is_game_over(board) {
+        return Ok(Vec::new());
+    }
+
+    let depth = unbeatable::horizon(board);
+    let mut analyses: Vec<Analysis> = Unbeatable::new(*token)
+        .score_moves(board)
+        .into_iter()
+        .map(|(choice, score)| Analysis {
+            choice,
+            value: value(score, depth),
+            reasons: reasons(board, choice, token),
+        })
+        .collect();
+
+    analyses.sort_by_key(|analysis| -analysis.value.rank());
+    Ok(analyses)
+}
+
+fn value(score: i32, depth: i32) -> Value {
+    match score {
+        0 => Value::Draw,
+        _ if score > 0 => Value::Win((depth - (score - WIN_SCORE)) as usize),
+        _ => Value::Loss((depth - (-score - WIN_SCORE)) as usize),
+    }
+}
+
+fn reasons(board: &Board, choice: Move, token: &Token) -> Vec<Reason> {
+    let cell = match choice {
+        Place(cell) => cell,
+        _ => return Vec::new(),
+    };
+    let opponent = match *token {
+        Cross => Nought,
+        _ => Cross,
+    };
+    let after = board.clone().apply(choice, *token);
+    let wins_now = rules::get_winner(&after) == token;
+    let blocks_win = winning_spots(board, &opponent).contains(&cell);
+    let theirs = board.clone().apply(choice, opponent);
+    let mut reasons = Vec::new();
+
+    if wins_now {
+        reasons.push(Reason::WinsNow);
+    }
+    if blocks_win {
+        reasons.push(Reason::BlocksWin);
+    }
+    if !wins_now && winning_spots(&after, token).len() >= FORK {
+        reasons.push(Reason::CreatesFork);
+    }
+    if !blocks_win && winning_spots(&theirs, &opponent).len() >= FORK {
+        reasons.push(Reason::BlocksFork);
+    }
+
+    reasons
+}
+
+fn winning_spots(board: &Board, token: &Token) -> Vec<usize> {
+    board
+        .empty_cells()
+        .into_iter()
+        .filter(|cell| rules::get_winner(&board.clone().apply(Place(*cell), *token)) == token)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best(board: &Board, token: &Token) -> Analysis {
+        analyse(board, token).expect("Unanalysable board")[0].clone()
+    }
+
+    #[test]
+    fn it_rates_every_legal_move() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought);
+        let analyses = analyse(&board, &Cross).expect("Unanalysable board");
+
+        assert_eq!(7, analyses.len());
+        assert!(analyses.iter().all(|analysis| analysis.value == Value::Draw));
+    }
+
+    #[test]
+    fn it_finds_an_immediate_win_before_a_block() {
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(3, Nought)
+            .update(1, Cross)
+            .update(4, Nought);
+        let analysis = best(&board, &Cross);
+
+        assert_eq!(Place(2), analysis.choice);
+        assert_eq!(Value::Win(1), analysis.value);
+        assert_eq!(Reason::WinsNow, analysis.reasons[0]);
+    }
+
+    #[test]
+    fn it_counts_plies_to_a_forced_win_or_loss() {
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(1, Nought)
+            .update(4, Cross)
+            .update(8, Nought);
+        let analyses = analyse(&board, &Cross).expect("Unanalysable board");
+
+        assert_eq!(Value::Win(3), analyses[0].value);
+        assert!(analyses[0].reasons.contains(&Reason::CreatesFork));
+        assert_eq!(Value::Loss(2), best(&board.update(6, Cross), &Nought).value);
+    }
+
+    #[test]
+    fn it_explains_blocking_a_win_and_a_fork() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought).update(8, Cross);
+        let analyses = analyse(&board, &Nought).expect("Unanalysable board");
+        let corner = analyses
+            .iter()
+            .find(|analysis| analysis.choice == Place(2))
+            .expect("Missing move");
+
+        assert_eq!(Value::Loss(4), corner.value);
+        assert!(best(&Board::new(3).update(0, Cross).update(1, Cross), &Nought)
+            .reasons
+            .contains(&Reason::BlocksWin));
+        assert_eq!(Value::Draw, analyses[0].value);
+    }
+
+    #[test]
+    fn it_refuses_boards_it_cannot_search() {
+        assert!(analyse(&Board::wild(3), &Cross).is_err());
+        assert!(analyse(&Board::new(4), &Cross).is_err());
+
+        let won = Board::new(3).update(0, Cross).update(1, Cross).update(2, Cross);
+        assert_eq!(Ok(Vec::new()), analyse(&won, &Nought));
+    }
+}
@@ -1,14 +1,78 @@
 extern crate ttt_lib;
 
-use ttt_lib::run;
+use std::env;
+use std::process;
+use ttt_lib::cli::{self, Command};
+use ttt_lib::{build_book_with, generate_with, history_with, ratings_with, run_with};
+use ttt_lib::{replay_with, solve_with, tournament_with, train_with};
 use ttt_lib::ui::color::Color::Dim;
 use ttt_lib::ui::input::UserInput;
+use ttt_lib::ui::script::Script::Usage;
 use ttt_lib::ui::view::View;
 
 fn main() {
     let output = std::io::stdout();
     let user_input = UserInput::new();
     let mut view = View::new(output);
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    run(user_input, &mut view, &Dim);
+    match cli::parse(&args) {
+        Ok(Command::Play(settings)) => {
+            run_with(&settings, user_input, &mut view, &Dim);
+        }
+        Ok(Command::Solve(problem)) => {
+            if let Err(message) = solve_with(&problem, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Tablebase(problem)) => {
+            if let Err(message) = generate_with(&problem, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Book(problem, self_play, history)) => {
+            let history = history.as_ref();
+            if let Err(message) = build_book_with(&problem, &self_play, history, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Train(problem, training)) => {
+            if let Err(message) = train_with(&problem, &training, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Tournament(tournament, ratings, history)) => {
+            let (ratings, history) = (ratings.as_ref(), history.as_ref());
+            if let Err(message) = tournament_with(&tournament, ratings, history, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Ratings(path, system)) => {
+            if let Err(message) = ratings_with(&path, system, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::History(path, query)) => {
+            if let Err(message) = history_with(&path, &query, &mut view) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Ok(Command::Replay(path, game, delay)) => {
+            if let Err(message) = replay_with(&path, game, delay, user_input, &mut view, &Dim) {
+                view.append_with(&message);
+                process::exit(1);
+            }
+        }
+        Err(message) => {
+            view.append_with(&format!("{}\n{}", message, Usage.to_str()));
+            process::exit(1);
+        }
+    }
 }
@@ -1,12 +1,72 @@
-use token::Token::{self, Empty};
+use moves::Move::{self, Choose, Collapse, Defer, Entangle, Hint, Mark, Place, Slide};
+use opening::{Opening, Phase, Seat};
+use quantum::{self, Spooky};
+use std::cmp;
+use token::PLAYER_TOKENS;
+use token::Token::{self, Blocked, Cross, Empty, Nought, Number};
 
 const MODIFIER: usize = 1;
+const CAPTURE_REACH: isize = 3;
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
 type CellMatrix = Vec<Vec<Token>>;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    Vanish(usize),
+    Slide(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Topology {
+    Flat,
+    Torus,
+}
+
+impl Topology {
+    pub fn from_name(name: &str) -> Option<Topology> {
+        match name {
+            "flat" => Some(Topology::Flat),
+            "torus" => Some(Topology::Torus),
+            _ => None,
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match *self {
+            Topology::Flat => "flat",
+            Topology::Torus => "torus",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scoring {
+    Symbol,
+    Mover,
+    Role,
+    Misere,
+    Sum,
+    Quantum,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Board {
     size: usize,
     cells: Vec<Token>,
+    ages: Vec<usize>,
+    limit: Option<Limit>,
+    scoring: Scoring,
+    win_length: usize,
+    boards: usize,
+    spooky: Vec<Spooky>,
+    topology: Topology,
+    opening: Opening,
+    decisions: Vec<Move>,
+    capturing: bool,
+    captures: Vec<Token>,
+    players: usize,
+    passing: bool,
+    first_turn: usize,
 }
 
 impl Board {
@@ -14,6 +74,163 @@ impl Board {
         Board {
             size,
             cells: vec![Empty; (size * size)],
+            ages: vec![0; size * size],
+            limit: None,
+            scoring: Scoring::Symbol,
+            win_length: size,
+            boards: 1,
+            spooky: Vec::new(),
+            topology: Topology::Flat,
+            opening: Opening::Free,
+            decisions: Vec::new(),
+            capturing: false,
+            captures: Vec::new(),
+            players: 2,
+            passing: false,
+            first_turn: 0,
+        }
+    }
+
+    pub fn wild(size: usize) -> Board {
+        Board {
+            scoring: Scoring::Mover,
+            ..Board::new(size)
+        }
+    }
+
+    pub fn order_and_chaos(size: usize, win_length: usize) -> Board {
+        Board {
+            scoring: Scoring::Role,
+            win_length,
+            ..Board::new(size)
+        }
+    }
+
+    pub fn gomoku(size: usize, win_length: usize) -> Board {
+        Board {
+            win_length,
+            ..Board::new(size)
+        }
+    }
+
+    pub fn pente(size: usize, win_length: usize) -> Board {
+        Board {
+            capturing: true,
+            ..Board::gomoku(size, win_length)
+        }
+    }
+
+    pub fn numerical(size: usize) -> Board {
+        Board {
+            scoring: Scoring::Sum,
+            ..Board::new(size)
+        }
+    }
+
+    pub fn quantum(size: usize) -> Board {
+        Board {
+            scoring: Scoring::Quantum,
+            ..Board::new(size)
+        }
+    }
+
+    pub fn notakto(boards: usize) -> Board {
+        let size = 3;
+        Board {
+            cells: vec![Empty; boards * size * size],
+            ages: vec![0; boards * size * size],
+            scoring: Scoring::Misere,
+            boards,
+            ..Board::new(size)
+        }
+    }
+
+    pub fn with_limit(size: usize, limit: Limit) -> Board {
+        Board {
+            limit: Some(limit),
+            ..Board::new(size)
+        }
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Board {
+        Board { topology, ..self }
+    }
+
+    pub fn with_players(self, players: usize) -> Board {
+        Board { players, ..self }
+    }
+
+    /// Lets a player skip a turn without marking the board.
+    pub fn with_passing(self) -> Board {
+        Board {
+            passing: true,
+            ..self
+        }
+    }
+
+    pub fn with_opening(self, opening: Opening) -> Board {
+        Board { opening, ..self }
+    }
+
+    /// Sets a cell before the first move. Whoever holds the fewest handicap
+    /// stones then moves first, so a side given stones moves after the other.
+    pub fn with_preset(self, cell: usize, token: Token) -> Board {
+        let mut cells = self.cells;
+        if cells[cell] == Empty {
+            cells[cell] = token;
+        }
+        let ages = &self.ages;
+        let handicap = |seat: &usize| {
+            cells
+                .iter()
+                .zip(ages.iter())
+                .filter(|&(cell, age)| *cell == PLAYER_TOKENS[*seat] && *age == 0)
+                .count()
+        };
+        let first_turn = (0..self.players).min_by_key(handicap).unwrap_or(0);
+
+        Board {
+            cells,
+            first_turn,
+            ..self
+        }
+    }
+
+    pub fn get_topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn get_player_count(&self) -> usize {
+        self.players
+    }
+
+    /// The place in token order of the player who moves first.
+    pub fn get_first_turn(&self) -> usize {
+        self.first_turn
+    }
+
+    /// The token whose turn it is when the players take turns in token order.
+    pub fn token_to_move(&self) -> Token {
+        PLAYER_TOKENS[(self.first_turn + self.moves_played()) % self.players]
+    }
+
+    pub fn get_opening(&self) -> Opening {
+        self.opening
+    }
+
+    pub fn opening_phase(&self) -> Phase {
+        self.opening.phase(self.moves_played(), &self.decisions)
+    }
+
+    pub fn must_choose(&self) -> bool {
+        let phase = self.opening_phase();
+        phase == Phase::Choice(Seat::First) || phase == Phase::Choice(Seat::Second)
+    }
+
+    pub fn opening_choices(&self) -> Vec<Move> {
+        match self.must_choose() {
+            true => self.opening.choices(&self.decisions),
+            false => Vec::new(),
         }
     }
 
@@ -26,28 +243,400 @@ impl Board {
     }
 
     pub fn get_length(&self) -> usize {
+        self.boards * self.get_board_length()
+    }
+
+    pub fn get_board_length(&self) -> usize {
         self.size * self.size
     }
 
+    pub fn get_board_count(&self) -> usize {
+        self.boards
+    }
+
+    pub fn get_limit(&self) -> Option<Limit> {
+        self.limit
+    }
+
+    pub fn get_win_length(&self) -> usize {
+        self.win_length
+    }
+
+    pub fn get_scoring(&self) -> Scoring {
+        self.scoring
+    }
+
+    pub fn is_wild(&self) -> bool {
+        self.scoring == Scoring::Mover || self.scoring == Scoring::Role
+    }
+
+    pub fn allows_pass(&self) -> bool {
+        self.passing
+    }
+
+    pub fn is_misere(&self) -> bool {
+        self.scoring == Scoring::Misere
+    }
+
+    pub fn is_numerical(&self) -> bool {
+        self.scoring == Scoring::Sum
+    }
+
+    pub fn is_quantum(&self) -> bool {
+        self.scoring == Scoring::Quantum
+    }
+
+    pub fn is_capturing(&self) -> bool {
+        self.capturing
+    }
+
+    pub fn captured_pairs(&self, token: &Token) -> usize {
+        self.captures.iter().filter(|capturer| *capturer == token).count()
+    }
+
+    /// The stones a `token` placed in `cell` would take: every pair of
+    /// matching opposing stones it flanks together with another of its own.
+    pub fn captures_at(&self, cell: usize, token: &Token) -> Vec<usize> {
+        let mut captured = Vec::new();
+        if !self.capturing {
+            return captured;
+        }
+
+        for &(row_step, column_step) in DIRECTIONS.iter() {
+            for &sign in [1, -1].iter() {
+                let flank: Vec<usize> = (1..CAPTURE_REACH + 1)
+                    .filter_map(|steps| {
+                        self.step_from(cell, row_step * sign, column_step * sign, steps)
+                    })
+                    .collect();
+
+                if let [first, second, last] = flank[..] {
+                    let pair = self.cells[first];
+                    let opposing = pair != *token && pair != Empty && pair != Blocked;
+                    if opposing && self.cells[second] == pair && self.cells[last] == *token {
+                        captured.push(first);
+                        captured.push(second);
+                    }
+                }
+            }
+        }
+
+        captured
+    }
+
+    pub fn get_spooky_marks(&self) -> &Vec<Spooky> {
+        &self.spooky
+    }
+
+    pub fn spooky_marks_in(&self, cell: usize) -> Vec<Spooky> {
+        self.spooky
+            .iter()
+            .filter(|mark| mark.touches(cell))
+            .cloned()
+            .collect()
+    }
+
+    pub fn must_collapse(&self) -> bool {
+        quantum::has_cycle(&self.spooky, self.get_length())
+    }
+
+    /// Whether any cell was set before the first move, as blocked cells and
+    /// handicap stones are.
+    pub fn has_preset(&self) -> bool {
+        self.cells.iter().enumerate().any(|(cell, token)| match *token {
+            Empty => false,
+            Blocked => true,
+            _ => self.ages[cell] == 0,
+        })
+    }
+
+    pub fn get_age(&self, cell: usize) -> usize {
+        self.ages[cell]
+    }
+
+    pub fn get_target(&self) -> usize {
+        self.size * (self.get_board_length() + MODIFIER) / 2
+    }
+
+    pub fn pool(&self, token: &Token) -> Vec<usize> {
+        let parity = match *token {
+            Cross => 1,
+            _ => 0,
+        };
+
+        (1..self.get_board_length() + MODIFIER)
+            .filter(|number| number % 2 == parity)
+            .filter(|number| !self.cells.contains(&Number(*number)))
+            .collect()
+    }
+
+    pub fn moves_played(&self) -> usize {
+        let spooky_turns = self.spooky.iter().map(|mark| mark.turn);
+        self.ages.iter().cloned().chain(spooky_turns).max().unwrap_or(0)
+    }
+
+    pub fn apply(self, cell_move: Move, token: Token) -> Board {
+        match cell_move {
+            Choose(_) | Defer if self.opening_choices().contains(&cell_move) => {
+                self.decide(cell_move)
+            }
+            Choose(_) | Defer | Hint => self,
+            Move::Resign | Move::OfferDraw | Move::AcceptDraw | Move::DeclineDraw => self,
+            Move::Pass => self,
+            _ if self.must_choose() => self,
+            Place(cell) if self.is_quantum() && !self.quantum_moves().contains(&Place(cell)) => {
+                self
+            }
+            Place(cell) if self.is_misere() => self.update(cell, Cross),
+            Place(cell) => self.update(cell, token),
+            Slide(from, to) => self.slide(from, to, token),
+            Mark(cell, Number(number)) if self.pool(&token).contains(&number) => {
+                self.update(cell, Number(number))
+            }
+            Mark(cell, symbol) if self.is_wild() => self.update(cell, symbol),
+            Mark(_, _) => self,
+            Entangle(first, second) => self.entangle(first, second, token),
+            Collapse(cell) => self.collapse(cell),
+        }
+    }
+
+    pub fn decide(self, decision: Move) -> Board {
+        let mut decisions = self.decisions;
+        decisions.push(decision);
+
+        Board { decisions, ..self }
+    }
+
+    pub fn entangle(self, first: usize, second: usize, token: Token) -> Board {
+        if self.can_entangle(first, second) {
+            let mark = Spooky::new(token, self.moves_played() + MODIFIER, (first, second));
+            let mut spooky = self.spooky;
+
+            spooky.push(mark);
+            return Board { spooky, ..self };
+        };
+
+        self
+    }
+
+    pub fn collapse(self, cell: usize) -> Board {
+        let last = self.spooky.iter().max_by_key(|mark| mark.turn).cloned();
+
+        match last {
+            Some(mark) if self.must_collapse() && mark.touches(cell) => {
+                let (spooky, resolved) = quantum::collapse(&self.spooky, mark, cell);
+                let mut cells = self.cells;
+                let mut ages = self.ages;
+
+                for (cell, mark) in resolved {
+                    cells[cell] = mark.token;
+                    ages[cell] = mark.turn;
+                }
+                Board {
+                    cells,
+                    ages,
+                    spooky,
+                    ..self
+                }
+            }
+            _ => self,
+        }
+    }
+
     pub fn update(self, cell_move: usize, token: Token) -> Board {
-        if self.is_empty_cell(cell_move) {
+        if self.is_empty_cell(cell_move) && self.is_live_cell(cell_move)
+            && !self.must_slide(&token)
+        {
+            let age = self.moves_played() + MODIFIER;
+            let vanishing = self.vanishing_cell(&token);
+            let captured = self.captures_at(cell_move, &token);
             let mut cells = self.cells;
+            let mut ages = self.ages;
+            let mut captures = self.captures;
+
+            if let Some(oldest) = vanishing {
+                cells[oldest] = Empty;
+                ages[oldest] = 0;
+            }
+
+            for cell in captured.iter() {
+                cells[*cell] = Empty;
+                ages[*cell] = 0;
+            }
+            captures.extend(captured.chunks(2).map(|_| token));
+
             cells[cell_move] = token;
+            ages[cell_move] = age;
             return Board {
-                size: self.size,
                 cells,
+                ages,
+                captures,
+                ..self
             };
         };
 
         self
     }
 
+    pub fn slide(self, from: usize, to: usize, token: Token) -> Board {
+        if self.can_slide(from, to, &token) {
+            let age = self.moves_played() + MODIFIER;
+            let mut cells = self.cells;
+            let mut ages = self.ages;
+
+            cells[from] = Empty;
+            ages[from] = 0;
+            cells[to] = token;
+            ages[to] = age;
+            return Board {
+                cells,
+                ages,
+                ..self
+            };
+        };
+
+        self
+    }
+
+    pub fn legal_moves(&self, token: &Token) -> Vec<Move> {
+        if self.must_choose() {
+            return self.opening_choices();
+        }
+
+        if self.is_quantum() {
+            return self.quantum_moves();
+        }
+
+        if self.is_wild() {
+            return self.empty_cells()
+                .into_iter()
+                .flat_map(|cell| vec![Mark(cell, Cross), Mark(cell, Nought)])
+                .collect();
+        }
+
+        if self.is_numerical() {
+            let pool = self.pool(token);
+            return self.empty_cells()
+                .into_iter()
+                .flat_map(|cell| pool.iter().map(move |number| Mark(cell, Number(*number))))
+                .collect();
+        }
+
+        if !self.must_slide(token) {
+            return self.empty_cells()
+                .into_iter()
+                .filter(|cell| self.is_live_cell(*cell))
+                .map(Place)
+                .collect();
+        }
+
+        let mut moves = Vec::new();
+        for from in self.cells_for(token) {
+            for to in self.empty_cells() {
+                if self.is_adjacent(from, to) {
+                    moves.push(Slide(from, to));
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub fn must_slide(&self, token: &Token) -> bool {
+        match self.limit {
+            Some(Limit::Slide(limit)) => self.cells_for(token).len() >= limit,
+            _ => false,
+        }
+    }
+
+    pub fn is_adjacent(&self, from: usize, to: usize) -> bool {
+        let (from_row, from_column) = (from / self.size, from % self.size);
+        let (to_row, to_column) = (to / self.size, to % self.size);
+        let rows_apart = self.steps_apart(from_row, to_row);
+        let columns_apart = self.steps_apart(from_column, to_column);
+        let row_end = self.size - MODIFIER;
+        let on_left_diagonal = from_row == from_column && to_row == to_column;
+        let on_right_diagonal =
+            from_row + from_column == row_end && to_row + to_column == row_end;
+
+        match (rows_apart, columns_apart) {
+            (0, 1) | (1, 0) => true,
+            (1, 1) => on_left_diagonal || on_right_diagonal,
+            _ => false,
+        }
+    }
+
+    pub fn vanishing_cell(&self, token: &Token) -> Option<usize> {
+        let marks = self.cells_for(token);
+
+        match self.limit {
+            Some(Limit::Vanish(limit)) if marks.len() >= limit => {
+                marks.into_iter().min_by_key(|i| self.ages[*i])
+            }
+            _ => None,
+        }
+    }
+
+    /// The same marks in the same spots, vanishing in the same order, with
+    /// the same player to move going by the moves played.
+    pub fn repeats(&self, other: &Board) -> bool {
+        self.same_marks(other)
+            && self.moves_played() % self.players == other.moves_played() % other.players
+    }
+
+    /// The same marks in the same spots, vanishing in the same order,
+    /// whoever is to move.
+    pub fn same_marks(&self, other: &Board) -> bool {
+        let same_ages = match self.limit {
+            Some(Limit::Vanish(_)) => self.age_ranks() == other.age_ranks(),
+            _ => true,
+        };
+
+        self.cells == other.cells && same_ages
+    }
+
     pub fn partition(&self) -> CellMatrix {
-        let rows = self.rows();
-        let columns = self.columns(&rows);
-        let diagonals = self.diagonals(&rows);
+        self.lines()
+            .iter()
+            .map(|line| line.iter().map(|i| self.cells[*i]).collect())
+            .collect()
+    }
 
-        [&rows[..], &columns[..], &diagonals[..]].concat()
+    pub fn lines(&self) -> Vec<Vec<usize>> {
+        (0..self.boards)
+            .flat_map(|index| self.board_lines(index))
+            .collect()
+    }
+
+    pub fn boards(&self) -> Vec<Board> {
+        let length = self.get_board_length();
+
+        (0..self.boards)
+            .map(|index| Board {
+                cells: self.cells[index * length..(index + MODIFIER) * length].to_vec(),
+                ages: self.ages[index * length..(index + MODIFIER) * length].to_vec(),
+                scoring: Scoring::Symbol,
+                boards: 1,
+                ..self.clone()
+            })
+            .collect()
+    }
+
+    pub fn live_boards(&self) -> Vec<usize> {
+        (0..self.boards)
+            .filter(|index| !self.is_dead_board(*index))
+            .collect()
+    }
+
+    pub fn is_dead_board(&self, index: usize) -> bool {
+        self.board_lines(index).iter().any(|line| {
+            let first = self.cells[line[0]];
+            first != Empty && first != Blocked && line.iter().all(|cell| self.cells[*cell] == first)
+        })
+    }
+
+    pub fn is_live_cell(&self, index: usize) -> bool {
+        !self.is_misere() || !self.is_dead_board(index / self.get_board_length())
     }
 
     pub fn is_empty_cell(&self, index: usize) -> bool {
@@ -69,48 +658,159 @@ impl Board {
         empty_cells
     }
 
-    fn rows(&self) -> CellMatrix {
-        self.cells.chunks(self.size).map(|x| x.to_vec()).collect()
+    fn board_lines(&self, index: usize) -> Vec<Vec<usize>> {
+        let offset = index * self.get_board_length();
+        // Only a line wrapping all the way round a torus is found again from
+        // each of its cells, so only then are repeats looked for.
+        let repeats = self.topology == Topology::Torus && self.win_length == self.size;
+        let mut lines: Vec<Vec<usize>> = Vec::new();
+
+        for &(row_step, column_step) in DIRECTIONS.iter() {
+            for start in 0..self.get_board_length() {
+                let line = match self.topology {
+                    Topology::Flat => self.line_from(start, row_step, column_step),
+                    Topology::Torus => self.wrapped_line_from(start, row_step, column_step),
+                };
+
+                if let Some(line) = line {
+                    let line: Vec<usize> = line.into_iter().map(|cell| cell + offset).collect();
+                    if !repeats || !lines.iter().any(|other| same_cells(other, &line)) {
+                        lines.push(line);
+                    }
+                }
+            }
+        }
+
+        lines
+    }
+
+    fn wrapped_line_from(&self, start: usize, row_step: isize, column_step: isize) -> Option<Vec<usize>> {
+        let size = self.size as isize;
+        let (row, column) = ((start / self.size) as isize, (start % self.size) as isize);
+
+        if self.win_length > self.size {
+            return None;
+        }
+
+        let line = (0..self.win_length as isize)
+            .map(|step| {
+                let wrapped_row = (row + row_step * step).rem_euclid(size);
+                let wrapped_column = (column + column_step * step).rem_euclid(size);
+                (wrapped_row * size + wrapped_column) as usize
+            })
+            .collect();
+
+        Some(line)
+    }
+
+    fn line_from(&self, start: usize, row_step: isize, column_step: isize) -> Option<Vec<usize>> {
+        let size = self.size as isize;
+        let (row, column) = ((start / self.size) as isize, (start % self.size) as isize);
+        let reach = (self.win_length - MODIFIER) as isize;
+        let (last_row, last_column) = (row + row_step * reach, column + column_step * reach);
+
+        if last_row >= size || last_column < 0 || last_column >= size {
+            return None;
+        }
+
+        let line = (0..self.win_length as isize)
+            .map(|step| ((row + row_step * step) * size + column + column_step * step) as usize)
+            .collect();
+
+        Some(line)
     }
 
-    fn columns(&self, rows: &CellMatrix) -> CellMatrix {
-        let mut columns = rows.to_vec();
+    fn quantum_moves(&self) -> Vec<Move> {
+        let empty_cells = self.empty_cells();
+
+        if self.must_collapse() {
+            let last = self.spooky.iter().max_by_key(|mark| mark.turn);
+            return last.map_or(Vec::new(), |mark| {
+                vec![Collapse(mark.cells.0), Collapse(mark.cells.1)]
+            });
+        }
+
+        if empty_cells.len() == 1 {
+            return vec![Place(empty_cells[0])];
+        }
 
-        for i in 0..self.size {
-            for j in i + MODIFIER..self.size {
-                let temp = columns[i][j];
-                columns[i][j] = columns[j][i];
-                columns[j][i] = temp;
+        let mut moves = Vec::new();
+        for (i, first) in empty_cells.iter().enumerate() {
+            for second in empty_cells[i + MODIFIER..].iter() {
+                moves.push(Entangle(*first, *second));
             }
         }
 
-        columns
+        moves
     }
 
-    fn diagonals(&self, rows: &CellMatrix) -> CellMatrix {
-        let left_diagonal = self.left_diagonal(rows);
-        let right_diagonal = self.right_diagonal(rows);
+    fn can_entangle(&self, first: usize, second: usize) -> bool {
+        let length = self.get_length();
 
-        vec![left_diagonal, right_diagonal]
+        self.is_quantum() && first < length && second < length && first != second
+            && self.is_empty_cell(first) && self.is_empty_cell(second)
+            && !self.must_collapse() && self.empty_cells().len() > 1
     }
 
-    fn left_diagonal(&self, rows: &CellMatrix) -> Vec<Token> {
-        rows.iter().enumerate().map(|(i, x)| x[i]).collect()
+    fn step_from(&self, cell: usize, row_step: isize, column_step: isize, steps: isize) -> Option<usize> {
+        let size = self.size as isize;
+        let row = (cell / self.size) as isize + row_step * steps;
+        let column = (cell % self.size) as isize + column_step * steps;
+
+        match self.topology {
+            Topology::Torus => Some((row.rem_euclid(size) * size + column.rem_euclid(size)) as usize),
+            Topology::Flat if row >= 0 && row < size && column >= 0 && column < size => {
+                Some((row * size + column) as usize)
+            }
+            Topology::Flat => None,
+        }
     }
 
-    fn right_diagonal(&self, rows: &CellMatrix) -> Vec<Token> {
-        let row_end = self.size - MODIFIER;
+    fn steps_apart(&self, from: usize, to: usize) -> usize {
+        let apart = distance(from, to);
+
+        match self.topology {
+            Topology::Flat => apart,
+            Topology::Torus => cmp::min(apart, self.size - apart),
+        }
+    }
+
+    fn can_slide(&self, from: usize, to: usize, token: &Token) -> bool {
+        let length = self.get_length();
+
+        from < length && to < length && self.cells[from] == *token && self.is_empty_cell(to)
+            && self.must_slide(token) && self.is_adjacent(from, to)
+    }
+
+    fn cells_for(&self, token: &Token) -> Vec<usize> {
+        (0..self.cells.len())
+            .filter(|i| self.cells[*i] == *token)
+            .collect()
+    }
 
-        rows.iter()
-            .enumerate()
-            .map(|(i, x)| x[(row_end - i)])
+    fn age_ranks(&self) -> Vec<usize> {
+        self.ages
+            .iter()
+            .map(|age| self.ages.iter().filter(|other| *other <= age && **other > 0).count())
             .collect()
     }
 }
 
+fn same_cells(line: &[usize], other: &[usize]) -> bool {
+    line.len() == other.len() && line.iter().all(|cell| other.contains(cell))
+}
+
+fn distance(a: usize, b: usize) -> usize {
+    match a > b {
+        true => a - b,
+        false => b - a,
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use token::Token::Triangle;
     use token::Token::{Cross, Nought};
 
     pub fn create_patterned_board(size: usize, indices: Vec<usize>) -> Board {
@@ -122,20 +822,70 @@ pub mod tests {
             }
         }
 
-        Board { size, cells }
+        create_sized_board(size, cells)
     }
 
     pub fn create_tied_board(size: usize) -> Board {
-        Board {
+        create_sized_board(
             size,
-            cells: vec![
+            vec![
                 Cross, Nought, Cross, Cross, Nought, Nought, Nought, Cross, Cross
             ],
-        }
+        )
     }
 
     pub fn create_board_from_cells(cells: Vec<Token>) -> Board {
-        Board { size: 3, cells }
+        create_sized_board(3, cells)
+    }
+
+    pub fn create_limited_board(limit: usize, moves: Vec<usize>) -> Board {
+        play_moves(Board::with_limit(3, Limit::Vanish(limit)), moves)
+    }
+
+    pub fn create_sliding_board(moves: Vec<usize>) -> Board {
+        play_moves(Board::with_limit(3, Limit::Slide(3)), moves)
+    }
+
+    fn play_moves(mut board: Board, moves: Vec<usize>) -> Board {
+        for (turn, i) in moves.iter().enumerate() {
+            let token = match turn % 2 {
+                0 => Cross,
+                _ => Nought,
+            };
+            board = board.update(*i, token);
+        }
+
+        board
+    }
+
+    fn create_sized_board(size: usize, cells: Vec<Token>) -> Board {
+        let mut ages = vec![0; size * size];
+        let mut age = 0;
+        for (i, cell) in cells.iter().enumerate() {
+            if *cell != Empty {
+                age += 1;
+                ages[i] = age;
+            }
+        }
+
+        Board {
+            size,
+            cells,
+            ages,
+            limit: None,
+            scoring: Scoring::Symbol,
+            win_length: size,
+            boards: 1,
+            spooky: Vec::new(),
+            topology: Topology::Flat,
+            opening: Opening::Free,
+            decisions: Vec::new(),
+            capturing: false,
+            captures: Vec::new(),
+            players: 2,
+            passing: false,
+            first_turn: 0,
+        }
     }
 
     #[test]
@@ -187,6 +937,369 @@ pub mod tests {
         assert_eq!(vec![1, 3, 4, 6, 8], board.empty_cells());
     }
 
+    #[test]
+    fn it_creates_board_with_mark_limit() {
+        let board = Board::with_limit(3, Limit::Vanish(3));
+        assert_eq!(Some(Limit::Vanish(3)), board.get_limit());
+        assert_eq!(None, Board::new(3).get_limit());
+    }
+
+    #[test]
+    fn it_counts_moves_played() {
+        let board = Board::new(3).update(4, Cross).update(0, Nought);
+        assert_eq!(2, board.moves_played());
+    }
+
+    #[test]
+    fn it_ignores_update_of_filled_cell() {
+        let board = Board::new(3).update(4, Cross);
+        assert_eq!(board.clone(), board.update(4, Nought));
+    }
+
+    #[test]
+    fn it_finds_no_vanishing_cell_below_limit() {
+        let board = create_limited_board(3, vec![0, 1, 2, 3]);
+        assert_eq!(None, board.vanishing_cell(&Cross));
+        assert_eq!(None, Board::new(3).update(0, Cross).vanishing_cell(&Cross));
+    }
+
+    #[test]
+    fn it_finds_oldest_mark_at_limit() {
+        let board = create_limited_board(3, vec![4, 0, 2, 6, 8, 1]);
+        assert_eq!(Some(4), board.vanishing_cell(&Cross));
+        assert_eq!(Some(0), board.vanishing_cell(&Nought));
+    }
+
+    #[test]
+    fn it_removes_oldest_mark_when_placing_over_limit() {
+        let board = create_limited_board(3, vec![4, 0, 2, 6, 8, 1, 3]);
+        assert_eq!(Empty, board.cells[4]);
+        assert_eq!(Cross, board.cells[3]);
+        assert_eq!(vec![2, 3, 8], board.cells_for(&Cross));
+        assert_eq!(Some(2), board.vanishing_cell(&Cross));
+    }
+
+    #[test]
+    fn it_detects_repeated_positions_regardless_of_move_number() {
+        let first = create_limited_board(1, vec![0, 1]);
+        let second = create_limited_board(1, vec![2, 3, 0, 1]);
+        let reordered = create_limited_board(2, vec![0, 1, 2, 3]);
+        let swapped = create_limited_board(2, vec![2, 3, 0, 1]);
+
+        assert!(first.repeats(&second));
+        assert!(!reordered.repeats(&swapped));
+    }
+
+    #[test]
+    fn it_requires_sliding_once_all_pieces_are_placed() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7]);
+        assert!(board.must_slide(&Cross));
+        assert!(!board.must_slide(&Nought));
+        assert_eq!(board.clone(), board.clone().update(8, Cross));
+        assert_eq!(Nought, board.update(8, Nought).cells[8]);
+    }
+
+    #[test]
+    fn it_slides_token_to_adjacent_empty_cell() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        let moved = board.apply(Slide(0, 4), Cross);
+        assert_eq!(Empty, moved.cells[0]);
+        assert_eq!(Cross, moved.cells[4]);
+        assert_eq!(7, moved.moves_played());
+    }
+
+    #[test]
+    fn it_ignores_illegal_slides() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        assert_eq!(board.clone(), board.clone().slide(0, 6, Cross));
+        assert_eq!(board.clone(), board.clone().slide(1, 2, Cross));
+        assert_eq!(board.clone(), board.clone().slide(0, 1, Cross));
+        assert_eq!(board.clone(), board.clone().slide(0, 9, Cross));
+    }
+
+    #[test]
+    fn it_informs_if_cells_are_adjacent() {
+        let board = Board::new(3);
+        assert!(board.is_adjacent(0, 1));
+        assert!(board.is_adjacent(0, 3));
+        assert!(board.is_adjacent(0, 4));
+        assert!(board.is_adjacent(2, 4));
+        assert!(board.is_adjacent(4, 6));
+        assert!(!board.is_adjacent(1, 3));
+        assert!(!board.is_adjacent(1, 5));
+        assert!(!board.is_adjacent(2, 3));
+        assert!(!board.is_adjacent(0, 8));
+    }
+
+    #[test]
+    fn it_treats_opposite_edges_of_a_torus_as_adjacent() {
+        let board = Board::new(3).with_topology(Topology::Torus);
+        assert!(board.is_adjacent(0, 2));
+        assert!(board.is_adjacent(1, 7));
+        assert!(!board.is_adjacent(0, 5));
+    }
+
+    #[test]
+    fn it_lists_legal_moves() {
+        let placing = create_sliding_board(vec![0, 1, 5, 3]);
+        assert_eq!(
+            vec![Place(2), Place(4), Place(6), Place(7), Place(8)],
+            placing.legal_moves(&Cross)
+        );
+
+        let sliding = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        assert_eq!(
+            vec![Slide(0, 4), Slide(5, 2), Slide(5, 4), Slide(7, 4), Slide(7, 6)],
+            sliding.legal_moves(&Cross)
+        );
+    }
+
+    #[test]
+    fn it_detects_repeated_positions_after_sliding() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        let returned = board
+            .clone()
+            .slide(0, 4, Cross)
+            .slide(1, 2, Nought)
+            .slide(4, 0, Cross)
+            .slide(2, 1, Nought);
+        assert!(returned.repeats(&board));
+    }
+
+    #[test]
+    fn it_marks_either_symbol_on_wild_board() {
+        let board = Board::wild(3)
+            .apply(Mark(0, Nought), Cross)
+            .apply(Mark(4, Nought), Nought);
+        assert!(board.is_wild());
+        assert_eq!(Nought, board.cells[0]);
+        assert_eq!(Nought, board.cells[4]);
+        assert_eq!(2, board.moves_played());
+    }
+
+    #[test]
+    fn it_ignores_marks_on_regular_board() {
+        let board = Board::new(3);
+        assert_eq!(board.clone(), board.clone().apply(Mark(0, Nought), Cross));
+    }
+
+    #[test]
+    fn it_lists_both_symbols_as_legal_wild_moves() {
+        let board = Board::wild(3).update(0, Cross).update(1, Cross).update(2, Nought);
+        let moves = board.legal_moves(&Cross);
+        assert_eq!(12, moves.len());
+        assert_eq!(vec![Mark(3, Cross), Mark(3, Nought)], moves[0..2].to_vec());
+    }
+
+    #[test]
+    fn it_creates_order_and_chaos_board() {
+        let board = Board::order_and_chaos(6, 5);
+        assert_eq!(36, board.get_length());
+        assert_eq!(5, board.get_win_length());
+        assert_eq!(Scoring::Role, board.get_scoring());
+        assert!(board.is_wild());
+    }
+
+    #[test]
+    fn it_lists_every_winning_window_for_shorter_lines() {
+        let lines = Board::order_and_chaos(6, 5).lines();
+        assert_eq!(32, lines.len());
+        assert_eq!(vec![0, 1, 2, 3, 4], lines[0]);
+        assert!(lines.contains(&vec![1, 8, 15, 22, 29]));
+        assert!(lines.contains(&vec![11, 16, 21, 26, 31]));
+        assert!(!lines.contains(&vec![4, 5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn it_keeps_a_pool_of_unused_numbers_per_player() {
+        let board = Board::numerical(3)
+            .apply(Mark(4, Number(5)), Cross)
+            .apply(Mark(0, Number(2)), Nought);
+        assert_eq!(15, board.get_target());
+        assert_eq!(vec![1, 3, 7, 9], board.pool(&Cross));
+        assert_eq!(vec![4, 6, 8], board.pool(&Nought));
+        assert_eq!(28, board.legal_moves(&Cross).len());
+    }
+
+    #[test]
+    fn it_ignores_numbers_outside_the_players_pool() {
+        let board = Board::numerical(3).apply(Mark(4, Number(5)), Cross);
+        assert_eq!(board.clone(), board.clone().apply(Mark(0, Number(5)), Cross));
+        assert_eq!(board.clone(), board.clone().apply(Mark(0, Number(4)), Cross));
+        assert_eq!(board.clone(), board.clone().apply(Mark(0, Cross), Cross));
+    }
+
+    #[test]
+    fn it_entangles_two_open_cells_with_spooky_mark() {
+        let board = Board::quantum(3).apply(Entangle(0, 4), Cross);
+        assert_eq!(vec![Spooky::new(Cross, 1, (0, 4))], *board.get_spooky_marks());
+        assert_eq!(1, board.moves_played());
+        assert_eq!(36, Board::quantum(3).legal_moves(&Cross).len());
+        assert_eq!(board.clone(), board.clone().apply(Entangle(2, 2), Nought));
+        assert_eq!(board.clone(), board.clone().apply(Place(2), Nought));
+    }
+
+    #[test]
+    fn it_offers_collapse_of_the_mark_closing_a_cycle() {
+        let board = Board::quantum(3)
+            .entangle(0, 4, Cross)
+            .entangle(4, 8, Nought)
+            .entangle(8, 0, Cross);
+        assert!(board.must_collapse());
+        assert_eq!(vec![Collapse(8), Collapse(0)], board.legal_moves(&Nought));
+        assert_eq!(board.clone(), board.clone().apply(Collapse(4), Nought));
+
+        let collapsed = board.apply(Collapse(0), Nought);
+        assert_eq!(vec![Cross, Empty, Empty, Empty, Cross, Empty, Empty, Empty, Nought], collapsed.cells);
+        assert_eq!(vec![3, 0, 0, 0, 1, 0, 0, 0, 2], collapsed.ages);
+        assert!(!collapsed.must_collapse());
+        assert_eq!(3, collapsed.moves_played());
+    }
+
+    #[test]
+    fn it_places_classical_mark_in_last_open_cell() {
+        let mut board = Board::quantum(3);
+        for &(first, chosen) in [(0, 0), (2, 3), (4, 4), (6, 7)].iter() {
+            board = board
+                .entangle(first, first + 1, Cross)
+                .entangle(first, first + 1, Nought)
+                .collapse(chosen);
+        }
+        assert_eq!(8, board.moves_played());
+        assert_eq!(vec![Place(8)], board.legal_moves(&Cross));
+        assert_eq!(Cross, board.apply(Place(8), Cross).cells[8]);
+    }
+
+    #[test]
+    fn it_wraps_lines_around_the_edges_of_a_torus() {
+        let lines = Board::new(3).with_topology(Topology::Torus).lines();
+        assert_eq!(12, lines.len());
+        assert!(lines.contains(&vec![1, 5, 6]));
+        assert!(lines.contains(&vec![0, 5, 7]));
+        assert_eq!(8, Board::new(3).lines().len());
+    }
+
+    #[test]
+    fn it_generates_every_broken_window_on_larger_torus() {
+        let board = Board::order_and_chaos(4, 3).with_topology(Topology::Torus);
+        let lines = board.lines();
+        assert_eq!(64, lines.len());
+        assert!(lines.contains(&vec![3, 0, 1]));
+        assert!(lines.contains(&vec![15, 0, 5]));
+        assert!(lines.contains(&vec![13, 0, 7]));
+    }
+
+    #[test]
+    fn it_presets_blocked_cells_and_handicap_stones() {
+        let board = Board::new(3)
+            .with_preset(4, Blocked)
+            .with_preset(0, Nought)
+            .with_preset(4, Cross);
+        assert_eq!(Blocked, board.cells[4]);
+        assert_eq!(Nought, board.cells[0]);
+        assert_eq!(0, board.moves_played());
+        assert!(board.has_preset());
+        assert_eq!(Cross, board.token_to_move());
+        assert_eq!(Nought, Board::new(3).with_preset(4, Cross).token_to_move());
+        assert!(!Board::new(3).update(4, Cross).has_preset());
+        assert_eq!(vec![1, 2, 3, 5, 6, 7, 8], board.empty_cells());
+        assert_eq!(board.clone(), board.clone().update(4, Cross));
+    }
+
+    #[test]
+    fn it_never_kills_a_board_with_blocked_cells() {
+        let board = Board::notakto(1)
+            .with_preset(0, Blocked)
+            .with_preset(1, Blocked)
+            .with_preset(2, Blocked);
+        assert!(!board.is_dead_board(0));
+    }
+
+    #[test]
+    fn it_lists_colour_choices_once_the_opening_stones_are_down() {
+        let board = Board::gomoku(15, 5)
+            .with_opening(Opening::Swap)
+            .apply(Place(112), Cross)
+            .apply(Place(113), Nought)
+            .apply(Place(96), Cross);
+
+        assert!(board.must_choose());
+        assert_eq!(vec![Choose(Cross), Choose(Nought)], board.legal_moves(&Nought));
+        assert_eq!(board, board.clone().apply(Place(0), Nought));
+        assert!(!board.apply(Choose(Cross), Nought).must_choose());
+    }
+
+    #[test]
+    fn it_leaves_the_board_alone_for_moves_about_the_game() {
+        let board = Board::new(3).update(4, Cross);
+        for choice in [Move::Resign, Move::OfferDraw, Move::AcceptDraw, Move::Pass] {
+            assert_eq!(board, board.clone().apply(choice, Nought));
+        }
+        assert!(!board.allows_pass());
+        assert!(board.with_passing().allows_pass());
+    }
+
+    #[test]
+    fn it_ignores_choices_outside_the_opening() {
+        let board = Board::gomoku(15, 5).with_opening(Opening::Swap2);
+        assert_eq!(board, board.clone().apply(Defer, Cross));
+        assert_eq!(225, board.legal_moves(&Cross).len());
+    }
+
+    #[test]
+    fn it_captures_a_flanked_pair() {
+        let board = Board::pente(19, 5)
+            .update(0, Cross)
+            .update(1, Nought)
+            .update(20, Cross)
+            .update(2, Nought)
+            .update(40, Nought)
+            .update(60, Cross);
+
+        assert_eq!(vec![2, 1], board.captures_at(3, &Cross));
+        let board = board.update(3, Cross);
+        assert_eq!(Empty, board.get_cells()[1]);
+        assert_eq!(Empty, board.get_cells()[2]);
+        assert_eq!(Nought, board.get_cells()[40]);
+        assert_eq!(1, board.captured_pairs(&Cross));
+        assert_eq!(0, board.captured_pairs(&Nought));
+    }
+
+    #[test]
+    fn it_only_captures_exactly_two_stones() {
+        let board = Board::pente(19, 5)
+            .update(0, Cross)
+            .update(1, Nought)
+            .update(2, Nought)
+            .update(3, Nought);
+
+        assert!(board.captures_at(4, &Cross).is_empty());
+        assert!(Board::gomoku(15, 5).update(0, Cross).update(1, Nought).update(2, Nought)
+            .captures_at(3, &Cross).is_empty());
+    }
+
+    #[test]
+    fn it_lets_a_stone_move_safely_between_two_opponents() {
+        let board = Board::pente(19, 5)
+            .update(0, Cross)
+            .update(1, Nought)
+            .update(3, Cross)
+            .update(2, Nought);
+
+        assert_eq!(Nought, board.get_cells()[1]);
+        assert_eq!(Nought, board.get_cells()[2]);
+    }
+
+    #[test]
+    fn it_rotates_turns_between_three_players() {
+        let board = Board::gomoku(4, 3).with_players(3);
+        assert_eq!(Cross, board.token_to_move());
+
+        let board = board.update(0, Cross).update(5, Nought);
+        assert_eq!(Triangle, board.token_to_move());
+        assert_eq!(Cross, board.update(10, Triangle).token_to_move());
+    }
+
     #[test]
     fn it_partitions_board_into_rows_diagonals_columns() {
         let board = create_patterned_board(3, vec![0, 2, 3, 4, 7, 8]);
@@ -0,0 +1,412 @@
+use board::Board;
+use moves::Move::{self, Place};
+use player::strategy::Strategy;
+use player::strategy::greedy::Greedy;
+use rand::{Rng, SeedableRng, StdRng};
+use rules;
+use solver::{self, BLOCKED, FIRST, OPEN, SECOND};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use token::Token::{self, Empty};
+
+const ROW_SEPARATOR: char = '/';
+const WEIGHT_SEPARATOR: char = ':';
+const COMMENT: char = ';';
+const WIN_POINTS: u32 = 2;
+const DRAW_POINTS: u32 = 1;
+
+/// Recommended moves for the positions of one board configuration, each
+/// with a weight. Positions are kept once per rotation or reflection and
+/// written as plain text, one position per line:
+///
+/// ```text
+/// 4x4 k4 Flat
+/// ; comments start with a semicolon
+/// x.../.o../..../.... 6:12 11:3
+/// ```
+///
+/// A position lists every spot row by row as in a layout, followed by spots
+/// numbered from 1 and their weights.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Book {
+    signature: String,
+    size: usize,
+    symmetries: Vec<Vec<usize>>,
+    entries: BTreeMap<Vec<u8>, BTreeMap<usize, u32>>,
+}
+
+/// A batch of self-play games: random moves for the first plies, then
+/// greedy play to the end. Every move in the random part is credited with
+/// the points its player scored.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SelfPlay {
+    pub games: usize,
+    pub plies: usize,
+    pub seed: usize,
+}
+
+impl Book {
+    pub fn new(board: &Board) -> Result<Book, String> {
+        if !solver::is_plain(board) {
+            return Err(String::from("Only open two-player k in a row boards have opening books."));
+        }
+
+        Ok(Book {
+            signature: solver::signature(board),
+            size: board.get_row_size(),
+            symmetries: solver::symmetries(board.get_row_size()),
+            entries: BTreeMap::new(),
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Book, String> {
+        let mut lines = text.lines()
+            .map(|line| line.trim())
+            .enumerate()
+            .filter(|&(_, line)| !line.is_empty() && !line.starts_with(COMMENT));
+
+        let signature = match lines.next() {
+            Some((_, signature)) => String::from(signature),
+            None => return Err(String::from("The opening book is empty.")),
+        };
+        let size = signature
+            .split('x')
+            .next()
+            .and_then(|size| size.parse().ok())
+            .ok_or(format!("Invalid opening book header {}.", signature))?;
+        let mut book = Book {
+            signature,
+            size,
+            symmetries: solver::symmetries(size),
+            entries: BTreeMap::new(),
+        };
+
+        for (number, line) in lines {
+            let invalid = || format!("Invalid opening book line {}: {}", number + 1, line);
+            let mut fields = line.split_whitespace();
+            let cells = fields
+                .next()
+                .and_then(|position| parse_position(position, size * size))
+                .ok_or_else(invalid)?;
+
+            for field in fields {
+                let (cell, weight) = parse_weight(field, size * size).ok_or_else(invalid)?;
+                book.add(&cells, cell, weight);
+            }
+        }
+
+        Ok(book)
+    }
+
+    pub fn load(path: &PathBuf) -> Result<Book, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| format!("{} ({}).", path.display(), error))?;
+        Book::parse(&text)
+    }
+
+    pub fn save(&self, path: &PathBuf) -> Result<(), String> {
+        fs::write(path, self.to_text()).map_err(|error| format!("{} ({}).", path.display(), error))
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", self.signature);
+
+        for (cells, moves) in self.entries.iter() {
+            let weights: Vec<String> = moves
+                .iter()
+                .map(|(cell, weight)| format!("{}{}{}", cell + 1, WEIGHT_SEPARATOR, weight))
+                .collect();
+            text.push_str(&format!("{} {}\n", self.render(cells), weights.join(" ")));
+        }
+
+        text
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get_signature(&self) -> &str {
+        &self.signature
+    }
+
+    pub fn fits(&self, board: &Board) -> bool {
+        solver::is_plain(board) && solver::signature(board) == self.signature
+    }
+
+    pub fn record(&mut self, board: &Board, cell: usize, weight: u32) {
+        self.add(&solver::pack(board), cell, weight);
+    }
+
+    /// Puts a weight on every open spot of a position that has no booked
+    /// move with a weight above zero yet.
+    pub fn stock(&mut self, board: &Board, weight: u32) {
+        if self.moves(board).is_empty() {
+            for cell in board.empty_cells() {
+                self.record(board, cell, weight);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, board: &Board, cell: usize, weight: u32) {
+        let (key, stored) = self.locate(&solver::pack(board), cell);
+
+        if let Some(moves) = self.entries.get_mut(&key) {
+            if let Some(current) = moves.get_mut(&stored) {
+                *current = current.saturating_sub(weight);
+            }
+        }
+    }
+
+    /// The booked moves with a weight above zero that are open on a board.
+    pub fn moves(&self, board: &Board) -> Vec<(usize, u32)> {
+        if !self.fits(board) {
+            return Vec::new();
+        }
+
+        let (key, index) = orient(&self.symmetries, &solver::pack(board));
+        let symmetry = &self.symmetries[index];
+
+        match self.entries.get(&key) {
+            Some(moves) => moves
+                .iter()
+                .map(|(cell, weight)| (symmetry[*cell], *weight))
+                .filter(|&(cell, weight)| weight > 0 && board.is_empty_cell(cell))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn play(&mut self, board: &Board, self_play: &SelfPlay) {
+        let mut rng: StdRng = SeedableRng::from_seed(&[self_play.seed][..]);
+        let greedy = Greedy::new();
+
+        for _ in 0..self_play.games {
+            let mut position = board.clone();
+            let mut opening = Vec::new();
+
+            while !rules::is_game_over(&position) {
+                let token = position.token_to_move();
+                let choice = match opening.len() < self_play.plies {
+                    true => {
+                        let moves = position.legal_moves(&token);
+                        let choice = moves[rng.gen_range(0, moves.len())];
+                        opening.push((token, choice));
+                        choice
+                    }
+                    false => greedy.decide(&position, &token),
+                };
+                position = position.apply(choice, token);
+            }
+
+            let winner = *rules::get_winner(&position);
+            self.credit(board, &opening, winner, self_play.plies);
+        }
+    }
+
+    /// Credits the spots taken in the first plies of a game played from a
+    /// board with the points their players scored.
+    pub fn credit(&mut self, board: &Board, moves: &[(Token, Move)], winner: Token, plies: usize) {
+        let mut position = board.clone();
+
+        for &(token, choice) in moves.iter().take(plies) {
+            if let Place(cell) = choice {
+                self.record(&position, cell, points(winner, token));
+            }
+            position = position.apply(choice, token);
+        }
+    }
+
+    fn add(&mut self, cells: &[u8], cell: usize, weight: u32) {
+        let (key, stored) = self.locate(cells, cell);
+
+        *self.entries
+            .entry(key)
+            .or_default()
+            .entry(stored)
+            .or_insert(0) += weight;
+    }
+
+    // The stored copy of a position and where a spot lands on it.
+    fn locate(&self, cells: &[u8], cell: usize) -> (Vec<u8>, usize) {
+        let (key, index) = orient(&self.symmetries, cells);
+        let stored = self.symmetries[index]
+            .iter()
+            .position(|source| *source == cell)
+            .unwrap_or(cell);
+
+        (key, stored)
+    }
+
+    fn render(&self, cells: &[u8]) -> String {
+        let rows: Vec<String> = cells
+            .chunks(self.size)
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match *cell {
+                        FIRST => 'x',
+                        SECOND => 'o',
+                        BLOCKED => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        rows.join(&ROW_SEPARATOR.to_string())
+    }
+}
+
+fn points(winner: Token, token: Token) -> u32 {
+    match winner {
+        Empty => DRAW_POINTS,
+        _ if winner == token => WIN_POINTS,
+        _ => 0,
+    }
+}
+
+fn parse_position(position: &str, length: usize) -> Option<Vec<u8>> {
+    let cells = position
+        .chars()
+        .filter(|symbol| *symbol != ROW_SEPARATOR)
+        .map(|symbol| match symbol {
+            '.' => Some(OPEN),
+            'x' | 'X' => Some(FIRST),
+            'o' | 'O' => Some(SECOND),
+            '#' => Some(BLOCKED),
+            _ => None,
+        })
+        .collect::<Option<Vec<u8>>>()?;
+
+    match cells.len() == length {
+        true => Some(cells),
+        false => None,
+    }
+}
+
+fn parse_weight(field: &str, length: usize) -> Option<(usize, u32)> {
+    let mut parts = field.split(WEIGHT_SEPARATOR);
+    let spot: usize = parts.next()?.parse().ok()?;
+    let weight = parts.next()?.parse().ok()?;
+
+    match spot >= 1 && spot <= length {
+        true => Some((spot - 1, weight)),
+        false => None,
+    }
+}
+
+// The smallest copy of a position under the symmetries of the square, and
+// the symmetry that produces it.
+fn orient(symmetries: &[Vec<usize>], cells: &[u8]) -> (Vec<u8>, usize) {
+    symmetries
+        .iter()
+        .enumerate()
+        .map(|(index, symmetry)| {
+            let copy: Vec<u8> = symmetry.iter().map(|source| cells[*source]).collect();
+            (copy, index)
+        })
+        .min()
+        .unwrap_or_else(|| (cells.to_vec(), 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token::Token::{Cross, Nought};
+
+    const BOOK: &str = "4x4 k4 Flat\n\
+                        ; prefer the centre\n\
+                        ..../..../..../.... 6:12 1:0\n\
+                        x.../..../..../.... 6:5 16:1\n";
+
+    #[test]
+    fn it_parses_plain_text_and_writes_it_back() {
+        let book = Book::parse(BOOK).expect("Invalid book");
+
+        assert_eq!(2, book.len());
+        assert_eq!("4x4 k4 Flat", book.get_signature());
+        assert_eq!(
+            "4x4 k4 Flat\n..../..../..../.... 1:0 6:12\n..../..../..../...x 1:1 11:5\n",
+            book.to_text()
+        );
+        assert!(Book::parse("4x4 k4 Flat\nx... 6:5\n").is_err());
+        assert!(Book::parse("4x4 k4 Flat\n..../..../..../.... 17:5\n").is_err());
+    }
+
+    #[test]
+    fn it_finds_moves_for_symmetric_positions() {
+        let book = Book::parse(BOOK).expect("Invalid book");
+        let board = Board::gomoku(4, 4).update(15, Cross);
+
+        assert_eq!(vec![(0, 1), (10, 5)], book.moves(&board));
+        assert_eq!(vec![(5, 12)], book.moves(&Board::gomoku(4, 4)));
+        assert!(book.moves(&Board::new(3)).is_empty());
+    }
+
+    #[test]
+    fn it_records_moves_under_one_copy_of_a_position() {
+        let mut book = Book::new(&Board::new(3)).expect("Bookless board");
+        book.record(&Board::new(3).update(0, Cross), 4, 1);
+        book.record(&Board::new(3).update(8, Cross), 4, 2);
+        book.record(&Board::new(3).update(2, Cross).update(4, Nought), 0, 1);
+
+        assert_eq!(2, book.len());
+        assert_eq!(vec![(4, 3)], book.moves(&Board::new(3).update(6, Cross)));
+    }
+
+    #[test]
+    fn it_stocks_unseen_positions_and_removes_weight() {
+        let mut book = Book::new(&Board::new(3)).expect("Bookless board");
+        let board = Board::new(3).update(4, Cross);
+        book.stock(&board, 2);
+        book.stock(&board, 5);
+        book.remove(&board, 0, 3);
+
+        let moves = book.moves(&board);
+        assert_eq!(7, moves.len());
+        assert!(moves.iter().all(|&(cell, weight)| cell != 0 && weight == 2));
+    }
+
+    #[test]
+    fn it_builds_a_book_from_self_play() {
+        let board = Board::gomoku(4, 4);
+        let self_play = SelfPlay {
+            games: 20,
+            plies: 2,
+            seed: 7,
+        };
+        let mut book = Book::new(&board).expect("Bookless board");
+        book.play(&board, &self_play);
+
+        let first_moves: u32 = book.moves(&board).iter().map(|&(_, weight)| weight).sum();
+        assert!(first_moves <= 20 * WIN_POINTS);
+        assert!(book.len() > 1);
+
+        let mut again = Book::new(&board).expect("Bookless board");
+        again.play(&board, &self_play);
+        assert_eq!(book, again);
+    }
+
+    #[test]
+    fn it_credits_the_opening_of_a_recorded_game() {
+        let board = Board::new(3);
+        let moves = vec![(Cross, Place(4)), (Nought, Place(0)), (Cross, Place(8))];
+        let mut book = Book::new(&board).expect("Bookless board");
+        book.credit(&board, &moves, Nought, 2);
+
+        assert!(book.moves(&board).is_empty());
+        assert_eq!(vec![(0, WIN_POINTS)], book.moves(&board.clone().update(4, Cross)));
+        assert_eq!(2, book.len());
+    }
+
+    #[test]
+    fn it_refuses_boards_without_k_in_a_row() {
+        assert!(Book::new(&Board::wild(3)).is_err());
+        assert!(Book::new(&Board::gomoku(15, 5)).is_ok());
+    }
+}
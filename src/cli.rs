@@ -0,0 +1,731 @@
+use board::Topology;
+use book::{Book, SelfPlay};
+use clock::TimeControl;
+use history::{Query, Verdict};
+use layout::Layout;
+use matches::Format;
+use opening::Opening;
+use player::strategy::menace::{Menace, Opponent, Training};
+use rand;
+use ratings::System;
+use settings::Settings;
+use solver::Problem;
+use std::path::PathBuf;
+use std::time::Duration;
+use tablebase::Tablebase;
+use tournament::{Entrant, Pairing, Tournament};
+use variant::Variant;
+
+const VARIANT_FLAG: &str = "--variant";
+const LIMIT_FLAG: &str = "--limit";
+const BOARDS_FLAG: &str = "--boards";
+const TOPOLOGY_FLAG: &str = "--topology";
+const LAYOUT_FLAG: &str = "--layout";
+const OBSTACLES_FLAG: &str = "--obstacles";
+const SEED_FLAG: &str = "--seed";
+const OPENING_FLAG: &str = "--opening";
+const SIZE_FLAG: &str = "--size";
+const WIN_LENGTH_FLAG: &str = "--k";
+const TABLE_FLAG: &str = "--table";
+const TABLEBASE_FLAG: &str = "--tablebase";
+const OUTPUT_FLAG: &str = "--output";
+const BOOK_FLAG: &str = "--book";
+const GAMES_FLAG: &str = "--games";
+const PLIES_FLAG: &str = "--plies";
+const LEARNED_FLAG: &str = "--learned";
+const OPPONENT_FLAG: &str = "--opponent";
+const TIME_FLAG: &str = "--time";
+const MOVE_TIME_FLAG: &str = "--move-time";
+const MATCH_FLAG: &str = "--match";
+const PLAYER_FLAG: &str = "--player";
+const PAIRING_FLAG: &str = "--pairing";
+const ROUNDS_FLAG: &str = "--rounds";
+const RATINGS_FLAG: &str = "--ratings";
+const NAME_FLAG: &str = "--name";
+const SYSTEM_FLAG: &str = "--system";
+const HISTORY_FLAG: &str = "--history";
+const RESULT_FLAG: &str = "--result";
+const DATE_FLAG: &str = "--date";
+const GAME_FLAG: &str = "--game";
+const DELAY_FLAG: &str = "--delay";
+const SOLVE_COMMAND: &str = "solve";
+const TABLEBASE_COMMAND: &str = "tablebase";
+const BOOK_COMMAND: &str = "book";
+const TRAIN_COMMAND: &str = "train";
+const TOURNAMENT_COMMAND: &str = "tournament";
+const RATINGS_COMMAND: &str = "ratings";
+const HISTORY_COMMAND: &str = "history";
+const REPLAY_COMMAND: &str = "replay";
+const DEFAULT_RATINGS: &str = "ratings.txt";
+const DEFAULT_HISTORY: &str = "history.txt";
+const DEFAULT_SIZE: usize = 3;
+const DEFAULT_GAMES: usize = 100;
+const DEFAULT_PLIES: usize = 4;
+const DEFAULT_TRAINING_GAMES: usize = 1000;
+const DEFAULT_DELAY: u64 = 1000;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Play(Settings),
+    Solve(Problem),
+    Tablebase(Problem),
+    Book(Problem, SelfPlay, Option<PathBuf>),
+    Train(Problem, Training),
+    Tournament(Tournament, Option<PathBuf>, Option<PathBuf>),
+    Ratings(PathBuf, System),
+    History(PathBuf, Query),
+    Replay(PathBuf, Option<usize>, Duration),
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    match args.split_first() {
+        Some((command, rest)) if command == SOLVE_COMMAND => parse_solve(rest),
+        Some((command, rest)) if command == TABLEBASE_COMMAND => parse_tablebase(rest),
+        Some((command, rest)) if command == BOOK_COMMAND => parse_book(rest),
+        Some((command, rest)) if command == TRAIN_COMMAND => parse_train(rest),
+        Some((command, rest)) if command == TOURNAMENT_COMMAND => parse_tournament(rest),
+        Some((command, rest)) if command == RATINGS_COMMAND => parse_ratings(rest),
+        Some((command, rest)) if command == HISTORY_COMMAND => parse_history(rest),
+        Some((command, rest)) if command == REPLAY_COMMAND => parse_replay(rest),
+        _ => parse_play(args),
+    }
+}
+
+fn parse_play(args: &[String]) -> Result<Command, String> {
+    let mut name = String::from("standard");
+    let mut limit = None;
+    let mut boards = None;
+    let mut topology = Topology::Flat;
+    let mut layout = Layout::new();
+    let mut obstacles = 0;
+    let mut seed = None;
+    let mut opening = Opening::Free;
+    let mut tablebase = None;
+    let mut book = None;
+    let mut learner = None;
+    let mut time = None;
+    let mut move_time = None;
+    let mut match_format = None;
+    let mut ratings = None;
+    let mut history = None;
+    let mut names = Vec::new();
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            VARIANT_FLAG => name = value.clone(),
+            LIMIT_FLAG => limit = Some(parse_number(value)?),
+            BOARDS_FLAG => boards = Some(parse_number(value)?),
+            TOPOLOGY_FLAG => topology = parse_topology(value)?,
+            LAYOUT_FLAG => layout = Layout::parse(value)?,
+            OBSTACLES_FLAG => obstacles = parse_number(value)?,
+            SEED_FLAG => seed = Some(parse_number(value)?),
+            OPENING_FLAG => opening = parse_opening(value)?,
+            TABLEBASE_FLAG => tablebase = Some(parse_tablebase_file(value)?),
+            BOOK_FLAG => book = Some(Book::load(&PathBuf::from(value))?),
+            LEARNED_FLAG => learner = Some(Menace::load(&PathBuf::from(value))?),
+            TIME_FLAG => time = Some(TimeControl::parse(value)?),
+            MOVE_TIME_FLAG => move_time = Some(Duration::from_secs(parse_number(value)? as u64)),
+            MATCH_FLAG => match_format = Some(Format::parse(value)?),
+            RATINGS_FLAG => ratings = Some(PathBuf::from(value)),
+            HISTORY_FLAG => history = Some(PathBuf::from(value)),
+            NAME_FLAG => names.push(parse_name(value)?),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    let time_control = match move_time {
+        Some(limit) => Some(time.unwrap_or_default().with_move_limit(limit)),
+        None => time,
+    };
+
+    let layout = match obstacles {
+        0 => layout,
+        _ => layout.with_obstacles(obstacles, seed.unwrap_or_else(rand::random)),
+    };
+
+    match Variant::from_name(&name, limit, boards) {
+        Some(Variant::Notakto(_)) if topology == Topology::Torus || !layout.is_open() => {
+            Err(format!("The {} variant can only be played on a flat, open board.", name))
+        }
+        Some(variant) if opening != Opening::Free && !variant.has_openings() => {
+            Err(format!("The {} variant has no opening rules.", name))
+        }
+        Some(variant)
+            if match_format.is_some()
+                && (variant.has_roles() || variant.board().get_player_count() != 2) =>
+        {
+            Err(format!("The {} variant cannot be played as a match.", name))
+        }
+        Some(variant) if !layout.fits(&variant.board()) => {
+            Err(format!("The layout does not fit the {} board.", name))
+        }
+        Some(variant) => {
+            let settings = Settings::new(variant)
+                .with_topology(topology)
+                .with_layout(layout)
+                .with_opening(opening)
+                .with_names(names);
+            let settings = match ratings {
+                Some(ratings) => settings.with_ratings(ratings),
+                None => settings,
+            };
+            let settings = match history {
+                Some(history) => settings.with_history(history),
+                None => settings,
+            };
+            let settings = match time_control {
+                Some(time_control) => settings.with_time_control(time_control),
+                None => settings,
+            };
+            let settings = match match_format {
+                Some(match_format) => settings.with_match_format(match_format),
+                None => settings,
+            };
+            let settings = match tablebase {
+                Some(ref tablebase) if !tablebase.fits(&settings.board()) => {
+                    return Err(format!(
+                        "The tablebase for {} does not fit the {} board.",
+                        tablebase.get_signature(),
+                        name
+                    ))
+                }
+                Some(tablebase) => settings.with_tablebase(tablebase),
+                None => settings,
+            };
+            let settings = match book {
+                Some(ref book) if !book.fits(&settings.board()) => {
+                    return Err(format!(
+                        "The opening book for {} does not fit the {} board.",
+                        book.get_signature(),
+                        name
+                    ))
+                }
+                Some(book) => settings.with_book(book),
+                None => settings,
+            };
+            match learner {
+                Some(ref learner) if !learner.fits(&settings.board()) => Err(format!(
+                    "The learned table for {} does not fit the {} board.",
+                    learner.get_signature(),
+                    name
+                )),
+                Some(learner) => Ok(Command::Play(settings.with_learner(learner))),
+                None => Ok(Command::Play(settings)),
+            }
+        }
+        None => Err(format!("Unknown variant {}.", name)),
+    }
+}
+
+fn parse_solve(args: &[String]) -> Result<Command, String> {
+    let mut size = DEFAULT_SIZE;
+    let mut win_length = None;
+    let mut table = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            SIZE_FLAG => size = parse_number(value)?,
+            WIN_LENGTH_FLAG => win_length = Some(parse_number(value)?),
+            TABLE_FLAG => table = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    let problem = Problem::new(size, win_length.unwrap_or(size));
+    match table {
+        Some(path) => Ok(Command::Solve(problem.with_table(path))),
+        None => Ok(Command::Solve(problem)),
+    }
+}
+
+fn parse_tablebase(args: &[String]) -> Result<Command, String> {
+    let mut size = DEFAULT_SIZE;
+    let mut win_length = None;
+    let mut output = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            SIZE_FLAG => size = parse_number(value)?,
+            WIN_LENGTH_FLAG => win_length = Some(parse_number(value)?),
+            OUTPUT_FLAG => output = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    let win_length = win_length.unwrap_or(size);
+    let output = output
+        .unwrap_or_else(|| PathBuf::from(format!("{}x{}k{}.tb", size, size, win_length)));
+    Ok(Command::Tablebase(Problem::new(size, win_length).with_table(output)))
+}
+
+fn parse_book(args: &[String]) -> Result<Command, String> {
+    let mut size = DEFAULT_SIZE;
+    let mut win_length = None;
+    let mut output = None;
+    let mut games = None;
+    let mut plies = DEFAULT_PLIES;
+    let mut seed = None;
+    let mut history = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            SIZE_FLAG => size = parse_number(value)?,
+            WIN_LENGTH_FLAG => win_length = Some(parse_number(value)?),
+            OUTPUT_FLAG => output = Some(PathBuf::from(value)),
+            GAMES_FLAG => games = Some(parse_number(value)?),
+            PLIES_FLAG => plies = parse_number(value)?,
+            SEED_FLAG => seed = Some(parse_number(value)?),
+            HISTORY_FLAG => history = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    let win_length = win_length.unwrap_or(size);
+    let output = output
+        .unwrap_or_else(|| PathBuf::from(format!("{}x{}k{}.book", size, size, win_length)));
+    // Recorded games stand in for self-play unless games are asked for too.
+    let games = match (games, &history) {
+        (Some(games), _) => games,
+        (None, &Some(_)) => 0,
+        (None, &None) => DEFAULT_GAMES,
+    };
+    let self_play = SelfPlay {
+        games,
+        plies,
+        seed: seed.unwrap_or_else(rand::random),
+    };
+    let problem = Problem::new(size, win_length).with_table(output);
+    Ok(Command::Book(problem, self_play, history))
+}
+
+fn parse_train(args: &[String]) -> Result<Command, String> {
+    let mut size = DEFAULT_SIZE;
+    let mut win_length = None;
+    let mut output = None;
+    let mut games = DEFAULT_TRAINING_GAMES;
+    let mut opponent = Opponent::Itself;
+    let mut seed = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            SIZE_FLAG => size = parse_number(value)?,
+            WIN_LENGTH_FLAG => win_length = Some(parse_number(value)?),
+            OUTPUT_FLAG => output = Some(PathBuf::from(value)),
+            GAMES_FLAG => games = parse_number(value)?,
+            OPPONENT_FLAG => opponent = parse_opponent(value)?,
+            SEED_FLAG => seed = Some(parse_number(value)?),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    let win_length = win_length.unwrap_or(size);
+    let output = output
+        .unwrap_or_else(|| PathBuf::from(format!("{}x{}k{}.menace", size, size, win_length)));
+    let training = Training {
+        opponent,
+        games,
+        seed: seed.unwrap_or_else(rand::random),
+    };
+    Ok(Command::Train(Problem::new(size, win_length).with_table(output), training))
+}
+
+fn parse_tournament(args: &[String]) -> Result<Command, String> {
+    let mut size = DEFAULT_SIZE;
+    let mut win_length = None;
+    let mut entrants = Vec::new();
+    let mut swiss = false;
+    let mut rounds = None;
+    let mut ratings = None;
+    let mut history = None;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            SIZE_FLAG => size = parse_number(value)?,
+            WIN_LENGTH_FLAG => win_length = Some(parse_number(value)?),
+            PLAYER_FLAG => entrants.push(Entrant::parse(value)?),
+            PAIRING_FLAG => swiss = parse_pairing(value)?,
+            ROUNDS_FLAG => rounds = Some(parse_number(value)?),
+            RATINGS_FLAG => ratings = Some(PathBuf::from(value)),
+            HISTORY_FLAG => history = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    // Enough Swiss rounds by default for one entrant to stand alone on top.
+    let pairing = match (swiss, rounds) {
+        (true, Some(rounds)) => Pairing::Swiss(rounds),
+        (true, None) => {
+            Pairing::Swiss(entrants.len().next_power_of_two().trailing_zeros() as usize)
+        }
+        (false, Some(_)) => return Err(String::from("Rounds are only set for Swiss pairings.")),
+        (false, None) => Pairing::RoundRobin,
+    };
+    let board = Problem::new(size, win_length.unwrap_or(size)).board();
+    let tournament = Tournament::new(board, entrants, pairing)?;
+    Ok(Command::Tournament(tournament, ratings, history))
+}
+
+fn parse_ratings(args: &[String]) -> Result<Command, String> {
+    let mut ratings = PathBuf::from(DEFAULT_RATINGS);
+    let mut system = System::Elo;
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            RATINGS_FLAG => ratings = PathBuf::from(value),
+            SYSTEM_FLAG => {
+                system = System::from_name(value).ok_or(format!("Unknown system {}.", value))?
+            }
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    Ok(Command::Ratings(ratings, system))
+}
+
+fn parse_history(args: &[String]) -> Result<Command, String> {
+    let mut history = PathBuf::from(DEFAULT_HISTORY);
+    let mut query = Query::default();
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            HISTORY_FLAG => history = PathBuf::from(value),
+            PLAYER_FLAG => query.player = Some(parse_name(value)?),
+            RESULT_FLAG => {
+                let verdict = Verdict::from_name(value);
+                query.verdict = Some(verdict.ok_or(format!("Unknown result {}.", value))?)
+            }
+            OPENING_FLAG => query.opening = value.split_whitespace().map(String::from).collect(),
+            DATE_FLAG => query.date = Some(value.clone()),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    match query.verdict {
+        Some(Verdict::Win) | Some(Verdict::Loss) if query.player.is_none() => {
+            Err(String::from("Wins and losses are only asked for with a player."))
+        }
+        _ => Ok(Command::History(history, query)),
+    }
+}
+
+fn parse_replay(args: &[String]) -> Result<Command, String> {
+    let mut history = PathBuf::from(DEFAULT_HISTORY);
+    let mut game = None;
+    let mut delay = Duration::from_millis(DEFAULT_DELAY);
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("Missing value for {}.", flag))?;
+        match flag.as_str() {
+            HISTORY_FLAG => history = PathBuf::from(value),
+            GAME_FLAG => game = Some(parse_number(value)?),
+            DELAY_FLAG => delay = Duration::from_millis(parse_number(value)? as u64),
+            _ => return Err(format!("Unknown option {}.", flag)),
+        }
+    }
+
+    Ok(Command::Replay(history, game, delay))
+}
+
+/// Names are kept on a line of their own in the ratings file, and between
+/// bars in the history file.
+fn parse_name(value: &str) -> Result<String, String> {
+    let name = value.trim();
+    let reserved = name.starts_with(';') || name.contains('|');
+    match name.is_empty() || reserved || name.contains(char::is_control) {
+        true => Err(format!("Invalid name {}.", value)),
+        false => Ok(String::from(name)),
+    }
+}
+
+fn parse_pairing(value: &str) -> Result<bool, String> {
+    match value {
+        "round-robin" => Ok(false),
+        "swiss" => Ok(true),
+        _ => Err(format!("Unknown pairing {}.", value)),
+    }
+}
+
+fn parse_opponent(value: &str) -> Result<Opponent, String> {
+    Opponent::from_name(value).ok_or(format!("Unknown opponent {}.", value))
+}
+
+fn parse_tablebase_file(value: &str) -> Result<Tablebase, String> {
+    Tablebase::load(&PathBuf::from(value)).map_err(|error| format!("{} ({}).", value, error))
+}
+
+fn parse_topology(value: &str) -> Result<Topology, String> {
+    Topology::from_name(value).ok_or(format!("Unknown topology {}.", value))
+}
+
+fn parse_opening(value: &str) -> Result<Opening, String> {
+    Opening::from_name(value).ok_or(format!("Unknown opening {}.", value))
+}
+
+fn parse_number(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(num) if num > 0 => Ok(num),
+        Ok(_) | Err(_) => Err(format!("Invalid number {}.", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Command::*;
+    use variant::Variant::*;
+
+    fn to_args(args: Vec<&str>) -> Vec<String> {
+        args.iter().map(|arg| String::from(*arg)).collect()
+    }
+
+    #[test]
+    fn it_plays_standard_game_without_arguments() {
+        let standard = Settings::new(Standard);
+        assert_eq!(Ok(Play(standard)), parse(&[]));
+    }
+
+    #[test]
+    fn it_parses_variant_and_limit() {
+        let args = to_args(vec!["--variant", "three-marks", "--limit", "4"]);
+        assert_eq!(Ok(Play(Settings::new(ThreeMarks(4)))), parse(&args));
+    }
+
+    #[test]
+    fn it_parses_number_of_boards() {
+        let args = to_args(vec!["--variant", "notakto", "--boards", "2"]);
+        assert_eq!(Ok(Play(Settings::new(Notakto(2)))), parse(&args));
+    }
+
+    #[test]
+    fn it_parses_topology() {
+        let args = to_args(vec!["--topology", "torus", "--variant", "wild"]);
+        let expected = Settings::new(Wild).with_topology(Topology::Torus);
+        assert_eq!(Ok(Play(expected)), parse(&args));
+        assert!(parse(&to_args(vec!["--topology", "sphere"])).is_err());
+        assert!(parse(&to_args(vec!["--variant", "notakto", "--topology", "torus"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_layout_and_seeded_obstacles() {
+        let args = to_args(vec!["--layout", "x../.#./..o", "--variant", "wild"]);
+        assert!(parse(&args).is_ok());
+
+        let args = to_args(vec!["--obstacles", "2", "--seed", "9"]);
+        let layout = Layout::new().with_obstacles(2, 9);
+        assert_eq!(Ok(Play(Settings::new(Standard).with_layout(layout))), parse(&args));
+    }
+
+    #[test]
+    fn it_rejects_layouts_that_do_not_fit() {
+        assert!(parse(&to_args(vec!["--layout", "x.#"])).is_err());
+        assert!(parse(&to_args(vec!["--layout", "....z...."])).is_err());
+        assert!(parse(&to_args(vec!["--obstacles", "9"])).is_err());
+        assert!(parse(&to_args(vec!["--variant", "notakto", "--layout", "#........"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_opening_rules_for_gomoku() {
+        let args = to_args(vec!["--variant", "gomoku", "--opening", "swap2"]);
+        let expected = Settings::new(Gomoku).with_opening(Opening::Swap2);
+        assert_eq!(Ok(Play(expected)), parse(&args));
+        assert!(parse(&to_args(vec!["--variant", "gomoku", "--opening", "pro"])).is_err());
+        assert!(parse(&to_args(vec!["--variant", "pente", "--opening", "swap"])).is_ok());
+        assert!(parse(&to_args(vec!["--opening", "swap"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_time_controls() {
+        let args = to_args(vec!["--variant", "gomoku", "--time", "3+2", "--move-time", "10"]);
+        let control = TimeControl::parse("3+2")
+            .expect("Invalid time control")
+            .with_move_limit(Duration::from_secs(10));
+        assert_eq!(Ok(Play(Settings::new(Gomoku).with_time_control(control))), parse(&args));
+
+        let args = to_args(vec!["--move-time", "5"]);
+        let control = TimeControl::new().with_move_limit(Duration::from_secs(5));
+        assert_eq!(Ok(Play(Settings::new(Standard).with_time_control(control))), parse(&args));
+        assert!(parse(&to_args(vec!["--time", "3+x"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_match_formats_for_two_player_variants() {
+        let args = to_args(vec!["--match", "best-of-5"]);
+        let expected = Settings::new(Standard).with_match_format(Format::BestOf(5));
+        assert_eq!(Ok(Play(expected)), parse(&args));
+        assert!(parse(&to_args(vec!["--match", "first-to-0"])).is_err());
+
+        let args = to_args(vec!["--variant", "three-player", "--match", "first-to-2"]);
+        assert!(parse(&args).is_err());
+        let args = to_args(vec!["--variant", "order-and-chaos", "--match", "best-of-3"]);
+        assert!(parse(&args).is_err());
+    }
+
+    #[test]
+    fn it_parses_solve_command() {
+        let args = to_args(vec!["solve", "--size", "4", "--k", "3"]);
+        assert_eq!(Ok(Solve(Problem::new(4, 3))), parse(&args));
+        assert_eq!(Ok(Solve(Problem::new(3, 3))), parse(&to_args(vec!["solve"])));
+
+        let args = to_args(vec!["solve", "--table", "4x4.table", "--size", "4"]);
+        let expected = Problem::new(4, 4).with_table(PathBuf::from("4x4.table"));
+        assert_eq!(Ok(Solve(expected)), parse(&args));
+        assert!(parse(&to_args(vec!["solve", "--variant", "wild"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_tablebase_command() {
+        let args = to_args(vec!["tablebase", "--size", "4", "--k", "3", "--output", "4.tb"]);
+        let expected = Problem::new(4, 3).with_table(PathBuf::from("4.tb"));
+        assert_eq!(Ok(Tablebase(expected)), parse(&args));
+
+        let expected = Problem::new(3, 3).with_table(PathBuf::from("3x3k3.tb"));
+        assert_eq!(Ok(Tablebase(expected)), parse(&to_args(vec!["tablebase"])));
+        assert!(parse(&to_args(vec!["tablebase", "--table", "3.tb"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_book_command() {
+        let args = to_args(vec!["book", "--size", "5", "--k", "4", "--games", "50", "--seed", "3"]);
+        let expected = Problem::new(5, 4).with_table(PathBuf::from("5x5k4.book"));
+        let self_play = SelfPlay {
+            games: 50,
+            plies: DEFAULT_PLIES,
+            seed: 3,
+        };
+        assert_eq!(Ok(Book(expected, self_play, None)), parse(&args));
+        assert!(parse(&to_args(vec!["book", "--plies", "0"])).is_err());
+
+        match parse(&to_args(vec!["book", "--history", "games.txt"])) {
+            Ok(Book(_, self_play, Some(history))) => {
+                assert_eq!(0, self_play.games);
+                assert_eq!(PathBuf::from("games.txt"), history);
+            }
+            _ => panic!("Expected a book from the history"),
+        }
+        assert!(parse(&to_args(vec!["--book", "/nonexistent/3x3k3.book"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_train_command() {
+        let args = to_args(vec!["train", "--opponent", "lazy", "--games", "10", "--seed", "2"]);
+        let expected = Problem::new(3, 3).with_table(PathBuf::from("3x3k3.menace"));
+        let training = Training {
+            opponent: Opponent::Lazy,
+            games: 10,
+            seed: 2,
+        };
+        assert_eq!(Ok(Train(expected, training)), parse(&args));
+        assert!(parse(&to_args(vec!["train", "--opponent", "greedy"])).is_err());
+        assert!(parse(&to_args(vec!["--learned", "/nonexistent/3x3k3.menace"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_tournament_command() {
+        let args = to_args(vec!["tournament", "--player", "lazy", "--player", "best=unbeatable"]);
+        match parse(&args) {
+            Ok(Tournament(tournament, _, _)) => {
+                assert_eq!(Pairing::RoundRobin, tournament.get_pairing());
+                assert_eq!("best", tournament.get_entrants()[1].get_name());
+            }
+            _ => panic!("Expected a tournament"),
+        }
+
+        let args = to_args(vec![
+            "tournament", "--player", "a=lazy", "--player", "b=lazy", "--player", "c=greedy",
+            "--pairing", "swiss",
+        ]);
+        match parse(&args) {
+            Ok(Tournament(tournament, _, _)) => {
+                assert_eq!(Pairing::Swiss(2), tournament.get_pairing())
+            }
+            _ => panic!("Expected a tournament"),
+        }
+
+        assert!(parse(&to_args(vec!["tournament", "--player", "lazy"])).is_err());
+        let args = to_args(vec!["tournament", "--player", "mcts", "--player", "lazy"]);
+        assert!(parse(&args).is_err());
+        let args = to_args(vec![
+            "tournament", "--player", "lazy", "--player", "b=lazy", "--rounds", "3",
+        ]);
+        assert!(parse(&args).is_err());
+    }
+
+    #[test]
+    fn it_parses_ratings_files_and_names() {
+        let args = to_args(vec!["--ratings", "ladder.txt", "--name", "ada", "--name", "bo"]);
+        let expected = Settings::new(Variant::Standard)
+            .with_names(vec![String::from("ada"), String::from("bo")])
+            .with_ratings(PathBuf::from("ladder.txt"));
+        assert_eq!(Ok(Play(expected)), parse(&args));
+        assert!(parse(&to_args(vec!["--name", " "])).is_err());
+
+        let args = to_args(vec!["ratings", "--system", "glicko"]);
+        assert_eq!(Ok(Ratings(PathBuf::from("ratings.txt"), System::Glicko)), parse(&args));
+        assert!(parse(&to_args(vec!["ratings", "--system", "trueskill"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_history_files_and_queries() {
+        let args = to_args(vec!["--history", "games.txt", "--name", "ada"]);
+        let expected = Settings::new(Variant::Standard)
+            .with_names(vec![String::from("ada")])
+            .with_history(PathBuf::from("games.txt"));
+        assert_eq!(Ok(Play(expected)), parse(&args));
+        assert!(parse(&to_args(vec!["--name", "ada|bo"])).is_err());
+
+        let args = to_args(vec![
+            "history", "--player", "ada", "--result", "loss", "--opening", "5 1", "--date",
+            "2026-10",
+        ]);
+        let expected = Query {
+            player: Some(String::from("ada")),
+            verdict: Some(Verdict::Loss),
+            opening: vec![String::from("5"), String::from("1")],
+            date: Some(String::from("2026-10")),
+        };
+        assert_eq!(Ok(History(PathBuf::from("history.txt"), expected)), parse(&args));
+        assert!(parse(&to_args(vec!["history", "--result", "win"])).is_err());
+        assert!(parse(&to_args(vec!["history", "--result", "z"])).is_err());
+    }
+
+    #[test]
+    fn it_parses_replay_command() {
+        let args = to_args(vec!["replay", "--game", "3", "--delay", "250"]);
+        let expected = Replay(PathBuf::from("history.txt"), Some(3), Duration::from_millis(250));
+        assert_eq!(Ok(expected), parse(&args));
+        let args = to_args(vec!["replay", "--history", "games.txt"]);
+        let expected = Replay(PathBuf::from("games.txt"), None, Duration::from_millis(1000));
+        assert_eq!(Ok(expected), parse(&args));
+        assert!(parse(&to_args(vec!["replay", "--game", "0"])).is_err());
+    }
+
+    #[test]
+    fn it_rejects_missing_tablebase_file() {
+        assert!(parse(&to_args(vec!["--tablebase", "/nonexistent/3x3k3.tb"])).is_err());
+    }
+
+    #[test]
+    fn it_rejects_unknown_options() {
+        assert!(parse(&to_args(vec!["--size", "4"])).is_err());
+        assert!(parse(&to_args(vec!["--variant", "chess"])).is_err());
+        assert!(parse(&to_args(vec!["--limit", "0"])).is_err());
+        assert!(parse(&to_args(vec!["--variant"])).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use std::time::Duration;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const INCREMENT_SEPARATOR: char = '+';
+
+/// How much thinking time each player gets: a total for the whole game with
+/// an increment added after every move, a limit per move, or both.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TimeControl {
+    total: Option<Duration>,
+    increment: Duration,
+    per_move: Option<Duration>,
+}
+
+/// The time each seat has left under a time control, the time each has
+/// spent on the move it is making, and the seat that ran out of time first.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Clock {
+    control: TimeControl,
+    remaining: Vec<Duration>,
+    spent: Vec<Duration>,
+    flagged: Option<usize>,
+}
+
+impl TimeControl {
+    pub fn new() -> TimeControl {
+        TimeControl {
+            total: None,
+            increment: Duration::from_secs(0),
+            per_move: None,
+        }
+    }
+
+    /// Reads a total in minutes with an optional increment in seconds, as
+    /// in `3` or `3+2`.
+    pub fn parse(spec: &str) -> Result<TimeControl, String> {
+        let invalid = || format!("Invalid time control {}.", spec);
+        let mut parts = spec.splitn(2, INCREMENT_SEPARATOR);
+        let minutes: u64 = parts
+            .next()
+            .and_then(|minutes| minutes.trim().parse().ok())
+            .ok_or_else(invalid)?;
+        let seconds: u64 = match parts.next() {
+            Some(seconds) => seconds.trim().parse().map_err(|_| invalid())?,
+            None => 0,
+        };
+
+        Ok(TimeControl::new().with_total(
+            Duration::from_secs(minutes * SECONDS_PER_MINUTE),
+            Duration::from_secs(seconds),
+        ))
+    }
+
+    pub fn with_total(self, total: Duration, increment: Duration) -> TimeControl {
+        TimeControl {
+            total: Some(total),
+            increment,
+            ..self
+        }
+    }
+
+    pub fn with_move_limit(self, limit: Duration) -> TimeControl {
+        TimeControl {
+            per_move: Some(limit),
+            ..self
+        }
+    }
+
+    pub fn get_total(&self) -> Option<Duration> {
+        self.total
+    }
+
+    pub fn get_increment(&self) -> Duration {
+        self.increment
+    }
+
+    pub fn get_move_limit(&self) -> Option<Duration> {
+        self.per_move
+    }
+}
+
+impl Default for TimeControl {
+    fn default() -> TimeControl {
+        TimeControl::new()
+    }
+}
+
+impl Clock {
+    pub fn new(control: TimeControl, seats: usize) -> Clock {
+        Clock {
+            control,
+            remaining: vec![control.total.unwrap_or_default(); seats],
+            spent: vec![Duration::from_secs(0); seats],
+            flagged: None,
+        }
+    }
+
+    pub fn get_control(&self) -> &TimeControl {
+        &self.control
+    }
+
+    /// What is left of a seat's total, when the control has one.
+    pub fn get_remaining(&self, seat: usize) -> Option<Duration> {
+        self.control.total.map(|_| self.remaining[seat])
+    }
+
+    /// The most a seat may still spend on the move it is making before
+    /// losing on time.
+    pub fn time_left(&self, seat: usize) -> Option<Duration> {
+        let left = match (self.get_remaining(seat), self.control.per_move) {
+            (Some(remaining), Some(limit)) => Some(remaining.min(limit)),
+            (remaining, limit) => remaining.or(limit),
+        };
+        left.map(|left| left.checked_sub(self.spent[seat]).unwrap_or_default())
+    }
+
+    /// Charges a seat for time spent on its move so far, flagging it when
+    /// that used up all the time it had. Entries that do not end the move,
+    /// such as a hint, keep adding to the same move.
+    pub fn charge(&mut self, seat: usize, elapsed: Duration) {
+        if self.flagged.is_some() {
+            return;
+        }
+        if let Some(left) = self.time_left(seat) {
+            if elapsed >= left {
+                self.flagged = Some(seat);
+                return;
+            }
+        }
+        self.spent[seat] += elapsed;
+    }
+
+    /// Ends a seat's move, taking the time spent on it off its total and
+    /// adding the increment.
+    pub fn complete(&mut self, seat: usize) {
+        if self.flagged.is_some() {
+            return;
+        }
+        if self.control.total.is_some() {
+            self.remaining[seat] = self.remaining[seat] - self.spent[seat] + self.control.increment;
+        }
+        self.spent[seat] = Duration::from_secs(0);
+    }
+
+    /// Charges a seat for a whole move and ends it.
+    pub fn punch(&mut self, seat: usize, elapsed: Duration) {
+        self.charge(seat, elapsed);
+        self.complete(seat);
+    }
+
+    pub fn get_flagged(&self) -> Option<usize> {
+        self.flagged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seconds(seconds: u64) -> Duration {
+        Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn it_parses_minutes_and_an_increment() {
+        assert_eq!(
+            Ok(TimeControl::new().with_total(seconds(180), seconds(2))),
+            TimeControl::parse("3+2")
+        );
+        assert_eq!(Some(seconds(60)), TimeControl::parse("1").unwrap().get_total());
+        assert!(TimeControl::parse("3+").is_err());
+        assert!(TimeControl::parse("blitz").is_err());
+    }
+
+    #[test]
+    fn it_takes_the_elapsed_time_and_adds_the_increment() {
+        let mut clock = Clock::new(TimeControl::parse("1+2").unwrap(), 2);
+        clock.punch(0, seconds(10));
+
+        assert_eq!(Some(seconds(52)), clock.get_remaining(0));
+        assert_eq!(Some(seconds(60)), clock.get_remaining(1));
+        assert_eq!(None, clock.get_flagged());
+    }
+
+    #[test]
+    fn it_flags_the_seat_that_runs_out_of_time() {
+        let mut clock = Clock::new(TimeControl::parse("1").unwrap(), 2);
+        clock.punch(0, seconds(30));
+        clock.punch(1, seconds(61));
+        clock.punch(0, seconds(31));
+
+        assert_eq!(Some(1), clock.get_flagged());
+        assert_eq!(Some(seconds(30)), clock.get_remaining(0));
+    }
+
+    #[test]
+    fn it_adds_the_increment_once_per_completed_move() {
+        let mut clock = Clock::new(TimeControl::parse("1+2").unwrap(), 2);
+        clock.charge(0, seconds(3));
+        clock.charge(0, seconds(4));
+
+        assert_eq!(Some(seconds(60)), clock.get_remaining(0));
+        assert_eq!(Some(seconds(53)), clock.time_left(0));
+        clock.complete(0);
+        assert_eq!(Some(seconds(55)), clock.get_remaining(0));
+        assert_eq!(Some(seconds(55)), clock.time_left(0));
+    }
+
+    #[test]
+    fn it_limits_each_move_with_or_without_a_total() {
+        let per_move = TimeControl::new().with_move_limit(seconds(5));
+        let both = TimeControl::parse("1").unwrap().with_move_limit(seconds(5));
+        let mut clock = Clock::new(per_move, 2);
+
+        assert_eq!(None, clock.get_remaining(0));
+        assert_eq!(Some(seconds(5)), clock.time_left(0));
+        assert_eq!(Some(seconds(5)), Clock::new(both, 2).time_left(1));
+
+        clock.punch(0, seconds(4));
+        clock.punch(1, seconds(5));
+        assert_eq!(Some(1), clock.get_flagged());
+    }
+}
@@ -1,93 +1,420 @@
-use board::Board;
+use analysis::{self, Analysis};
+use board::{Board, Scoring};
+use clock::{Clock, TimeControl};
+use moves::Move::{self, AcceptDraw, Choose, DeclineDraw, Hint, OfferDraw, Pass, Resign};
+use opening::Phase;
 use player::Player;
-use rules;
-use token::Token;
+use rules::{self, Role};
+use std::time::Instant;
+use token::Token::{self, Empty};
+
+const REPETITION_LIMIT: usize = 3;
 
 #[derive(Debug, PartialEq)]
 pub enum GameState {
     InProgress,
     Over,
+    /// The seat that resigned.
+    Resigned(usize),
+    DrawAgreed,
+}
+
+/// A move that changed the board, with the position it was played on.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Turn {
+    pub board: Board,
+    pub token: Token,
+    pub choice: Move,
+    pub human: bool,
 }
 
 pub struct Game {
     board: Board,
-    player_one: Box<Player>,
-    player_two: Box<Player>,
+    players: Vec<Box<dyn Player>>,
     state: GameState,
+    positions: Vec<(Board, usize)>,
+    colours: Vec<Token>,
+    hint: Option<Result<Vec<Analysis>, String>>,
+    turns: Vec<Turn>,
+    clock: Option<Clock>,
+    draw_offer: Option<Token>,
+    passes: usize,
+    passes_in_a_row: usize,
 }
 
 impl Game {
-    pub fn new(board: Board, player_one: Box<Player>, player_two: Box<Player>) -> Game {
+    pub fn new(board: Board, player_one: Box<dyn Player>, player_two: Box<dyn Player>) -> Game {
+        Game::with_players(board, vec![player_one, player_two])
+    }
+
+    /// Seats the players in turn order; each moves with the token it holds.
+    pub fn with_players(board: Board, players: Vec<Box<dyn Player>>) -> Game {
         Game {
+            positions: vec![(
+                board.clone(),
+                (board.get_first_turn() + board.moves_played()) % players.len(),
+            )],
+            colours: players.iter().map(|player| *player.get_token()).collect(),
             board,
-            player_one,
-            player_two,
+            players,
             state: GameState::InProgress,
+            hint: None,
+            turns: Vec::new(),
+            clock: None,
+            draw_offer: None,
+            passes: 0,
+            passes_in_a_row: 0,
         }
     }
 
+    /// Starts a clock for every seat; a player who runs out of time loses.
+    pub fn with_clock(self, control: TimeControl) -> Game {
+        Game {
+            clock: Some(Clock::new(control, self.players.len())),
+            ..self
+        }
+    }
+
+    /// Hands each of two players the other's token, so the second seat
+    /// moves first.
+    pub fn with_sides_swapped(mut self) -> Game {
+        let tokens = self.get_seat_tokens();
+        for (player, token) in self.players.iter_mut().zip(tokens.iter().rev()) {
+            player.set_token(*token);
+        }
+
+        self
+    }
+
+    /// Names the human players in seat order; any left over stay guests.
+    pub fn with_names(mut self, names: &[String]) -> Game {
+        let humans = self.players.iter_mut().filter(|player| player.is_human());
+        for (player, name) in humans.zip(names.iter()) {
+            player.set_name(name);
+        }
+
+        self
+    }
+
+    /// The position the game started from.
+    pub fn get_start(&self) -> &Board {
+        &self.positions[0].0
+    }
+
     pub fn get_board(&self) -> &Board {
         &self.board
     }
 
+    /// The analysis of the position for the player who asked for a hint on
+    /// the last turn.
+    pub fn get_hint(&self) -> Option<&Result<Vec<Analysis>, String>> {
+        self.hint.as_ref()
+    }
+
+    pub fn get_clock(&self) -> Option<&Clock> {
+        self.clock.as_ref()
+    }
+
+    /// The token of the player who lost on time, if one did.
+    pub fn get_flagged_token(&self) -> Option<&Token> {
+        self.clock
+            .as_ref()
+            .and_then(|clock| clock.get_flagged())
+            .map(|seat| self.players[seat].get_token())
+    }
+
+    /// The token of the player who resigned, if one did.
+    pub fn get_resigned_token(&self) -> Option<&Token> {
+        match self.state {
+            GameState::Resigned(seat) => Some(self.players[seat].get_token()),
+            _ => None,
+        }
+    }
+
+    /// The token of the player whose draw offer is waiting for an answer.
+    pub fn get_draw_offer(&self) -> Option<&Token> {
+        self.draw_offer.as_ref()
+    }
+
+    /// The tokens of the seats in seating order.
+    pub fn get_seat_tokens(&self) -> Vec<Token> {
+        self.players.iter().map(|player| *player.get_token()).collect()
+    }
+
+    pub fn get_player_names(&self) -> Vec<String> {
+        self.players.iter().map(|player| player.get_name()).collect()
+    }
+
+    /// Every move that changed the board, in the order played.
+    pub fn get_turns(&self) -> &[Turn] {
+        &self.turns
+    }
+
     pub fn get_state(&self) -> &GameState {
         &self.state
     }
 
     pub fn get_winner(&self) -> &Token {
-        rules::get_winner(&self.board)
+        let conceded = match self.state {
+            GameState::Resigned(seat) => Some(seat),
+            GameState::DrawAgreed => return &Empty,
+            _ => self.clock.as_ref().and_then(|clock| clock.get_flagged()),
+        };
+        if let Some(seat) = conceded {
+            return self.winner_against(seat);
+        }
+
+        match self.board.get_scoring() {
+            Scoring::Symbol => rules::get_winner(&self.board),
+            Scoring::Mover | Scoring::Sum if rules::is_won(&self.board) => {
+                self.last_player_token()
+            }
+            Scoring::Mover | Scoring::Sum => &Empty,
+            Scoring::Role => match rules::get_winning_role(&self.board) {
+                Some(Role::Order) => self.players[0].get_token(),
+                Some(Role::Chaos) => self.players[1].get_token(),
+                None => &Empty,
+            },
+            Scoring::Quantum => rules::get_first_winner(&self.board),
+            Scoring::Misere if rules::is_game_over(&self.board) => self.next_player_token(),
+            Scoring::Misere => &Empty,
+        }
+    }
+
+    pub fn get_winning_role(&self) -> Option<Role> {
+        match self.board.get_scoring() {
+            Scoring::Role => rules::get_winning_role(&self.board),
+            _ => None,
+        }
+    }
+
+    pub fn current_player_role(&self) -> Option<Role> {
+        match (self.board.get_scoring(), self.turn_index()) {
+            (Scoring::Role, 0) => Some(Role::Order),
+            (Scoring::Role, _) => Some(Role::Chaos),
+            _ => None,
+        }
     }
 
     pub fn next_turn(&mut self) {
         let token = self.current_player_token();
+        let index = self.current_player_index();
+        if let Some(time_left) = self.clock.as_ref().and_then(|clock| clock.time_left(index)) {
+            self.players[index].set_time_left(time_left);
+        }
+
+        let started = Instant::now();
         let move_choice = self.current_player_move();
+        if let Some(ref mut clock) = self.clock {
+            clock.charge(index, started.elapsed());
+            if clock.get_flagged().is_some() {
+                self.state = GameState::Over;
+                return;
+            }
+        }
+
+        let seat_token = *self.players[index].get_token();
+        match move_choice {
+            Ok(Resign) => {
+                self.complete_move(index);
+                self.state = GameState::Resigned(index);
+                return;
+            }
+            Ok(AcceptDraw) if self.is_draw_offered_to(&seat_token) => {
+                self.complete_move(index);
+                self.state = GameState::DrawAgreed;
+                return;
+            }
+            Ok(OfferDraw) if self.draw_offer.is_none() => self.draw_offer = Some(seat_token),
+            Ok(DeclineDraw) if self.is_draw_offered_to(&seat_token) => self.draw_offer = None,
+            Ok(Pass) if self.board.allows_pass() => {
+                self.complete_move(index);
+                self.pass(&seat_token);
+            }
+            _ => {}
+        }
+
         let cells = self.board.clone();
 
+        self.hint = match move_choice {
+            Ok(Hint) => Some(analysis::analyse(&cells, &token)),
+            _ => None,
+        };
+
+        if let Ok(Choose(colour)) = move_choice {
+            if cells.opening_choices().contains(&Choose(colour)) {
+                self.assign_colour(index, colour);
+            }
+        }
+
         self.board = match move_choice {
-            Ok(num) => cells.update(num, token),
-            Err(_) => cells,
+            Ok(choice) => cells.clone().apply(choice, token),
+            Err(_) => cells.clone(),
         };
 
+        if let Ok(choice) = move_choice {
+            if self.board != cells {
+                self.complete_move(index);
+                self.passes_in_a_row = 0;
+                if self.is_draw_offered_to(&seat_token) {
+                    self.draw_offer = None;
+                }
+                self.turns.push(Turn {
+                    board: cells,
+                    token,
+                    choice,
+                    human: self.players[index].is_human(),
+                });
+            }
+        }
+
         self.update_state();
     }
 
+    /// The colour whose stone goes down next, whichever seat holds it.
     pub fn current_player_token(&self) -> Token {
-        match self.is_odd_turn() {
-            true => *self.player_one.get_token(),
-            false => *self.player_two.get_token(),
+        *self.next_player_token()
+    }
+
+    pub fn current_player_index(&self) -> usize {
+        match self.board.opening_phase() {
+            Phase::Stones(seat) | Phase::Choice(seat) => seat.index(),
+            Phase::Play => {
+                let token = self.current_player_token();
+                self.players
+                    .iter()
+                    .position(|player| *player.get_token() == token)
+                    .unwrap_or(0)
+            }
         }
     }
 
-    fn current_player_move(&mut self) -> Result<usize, String> {
-        match self.is_odd_turn() {
-            true => self.player_one.get_move(&self.board),
-            false => self.player_two.get_move(&self.board),
+    pub fn get_player_count(&self) -> usize {
+        self.players.len()
+    }
+
+    fn next_player_token(&self) -> &Token {
+        &self.colours[self.turn_index()]
+    }
+
+    // Between two players the seat that conceded loses to the other; with
+    // more seats no one wins outright.
+    fn winner_against(&self, seat: usize) -> &Token {
+        match self.players.len() {
+            2 => self.players[1 - seat].get_token(),
+            _ => &Empty,
         }
     }
 
-    fn is_odd_turn(&self) -> bool {
-        let number_empty_cells = self.board.empty_cells().len();
-        number_empty_cells % 2 != 0
+    fn last_player_token(&self) -> &Token {
+        let count = self.colours.len();
+        &self.colours[(self.turn_index() + count - 1) % count]
+    }
+
+    fn current_player_move(&mut self) -> Result<Move, String> {
+        let index = self.current_player_index();
+        self.players[index].get_move(&self.board)
+    }
+
+    fn assign_colour(&mut self, index: usize, colour: Token) {
+        let previous = *self.players[index].get_token();
+        for player in self.players.iter_mut() {
+            if *player.get_token() == colour {
+                player.set_token(previous);
+            }
+        }
+
+        self.players[index].set_token(colour);
+    }
+
+    fn turn_index(&self) -> usize {
+        let turns = self.board.get_first_turn() + self.board.moves_played() + self.passes;
+        turns % self.colours.len()
+    }
+
+    fn is_draw_offered_to(&self, token: &Token) -> bool {
+        self.draw_offer.is_some_and(|offer| offer != *token)
+    }
+
+    // Passing turns down any draw offer, as a move on the board does.
+    // Hints, draw offers and entries that cannot be read keep the clock
+    // running on the same move; only a move that ends the turn earns the
+    // increment.
+    fn complete_move(&mut self, index: usize) {
+        if let Some(ref mut clock) = self.clock {
+            clock.complete(index);
+        }
+    }
+
+    fn pass(&mut self, token: &Token) {
+        self.passes += 1;
+        self.passes_in_a_row += 1;
+        if self.is_draw_offered_to(token) {
+            self.draw_offer = None;
+        }
+    }
+
+    fn is_passed_out(&self) -> bool {
+        self.passes_in_a_row >= self.players.len()
     }
 
     fn update_state(&mut self) {
-        if rules::is_game_over(&self.board) {
+        if self.is_new_position() {
+            self.positions.push((self.board.clone(), self.turn_index()));
+        }
+
+        if rules::is_game_over(&self.board)
+            || self.is_repetition()
+            || self.is_blocked()
+            || self.is_passed_out()
+        {
             self.state = GameState::Over;
         }
     }
+
+    // Positions are told apart by the marks and the colour to move, so a
+    // pass makes a new position even though the board stays the same.
+    fn is_new_position(&self) -> bool {
+        match self.positions.last() {
+            Some(&(ref last, mover)) => {
+                last.moves_played() != self.board.moves_played() || mover != self.turn_index()
+            }
+            None => true,
+        }
+    }
+
+    fn is_blocked(&self) -> bool {
+        let token = self.current_player_token();
+        self.board.legal_moves(&token).is_empty()
+    }
+
+    fn is_repetition(&self) -> bool {
+        let mover = self.turn_index();
+        let repeats = self.positions
+            .iter()
+            .filter(|&&(ref position, to_move)| {
+                to_move == mover && position.same_marks(&self.board)
+            })
+            .count();
+
+        self.board.get_limit().is_some() && repeats >= REPETITION_LIMIT
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
     use super::GameState::{InProgress, Over};
-    use board::Board;
+    use board::{Board, Limit};
     use board::tests::*;
+    use opening::Opening;
     use player::computer::Computer;
     use player::human::Human;
     use player::strategy::lazy::Lazy;
-    use token::Token::{Cross, Nought};
+    use std::time::Duration;
+    use token::Token::{Cross, Nought, Triangle};
     use ui::input::tests::*;
+    use variant::Variant;
 
     pub fn setup_computer_vs_computer(board: Board) -> Game {
         let player_one = Box::new(Computer::new(Cross, Lazy::new()));
@@ -107,8 +434,8 @@ pub mod tests {
     fn it_creates_new_game() {
         let game = setup_human_vs_computer();
         assert_eq!(Board::new(3), game.board);
-        assert_eq!(Cross, *game.player_one.get_token());
-        assert_eq!(Nought, *game.player_two.get_token());
+        assert_eq!(Cross, *game.players[0].get_token());
+        assert_eq!(Nought, *game.players[1].get_token());
         assert_eq!(InProgress, game.state);
     }
 
@@ -146,4 +473,379 @@ pub mod tests {
 
         assert_eq!(Over, game.state);
     }
+
+    #[test]
+    fn it_alternates_turns_by_moves_played() {
+        let board = create_limited_board(3, vec![4, 0, 2, 6, 8, 1]);
+        let mut game = setup_computer_vs_computer(board);
+
+        assert_eq!(Cross, game.current_player_token());
+        game.next_turn();
+        assert_eq!(Nought, game.current_player_token());
+    }
+
+    #[test]
+    fn it_ends_limited_game_on_threefold_repetition() {
+        let board = create_limited_board(1, vec![0, 8]);
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["2", "1", "2", "1"])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["8", "9", "8", "9"])));
+        let mut game = Game::new(board, player_one, player_two);
+
+        for _ in 0..7 {
+            game.next_turn();
+            assert_eq!(InProgress, game.state);
+        }
+
+        game.next_turn();
+        assert_eq!(Over, game.state);
+        assert_eq!(&Empty, game.get_winner());
+    }
+
+    #[test]
+    fn it_counts_the_colour_to_move_after_a_pass_towards_repetition() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]).with_passing();
+        let crosses = vec!["6-3", "3-6", "6-3", "3-6"];
+        let mut game = setup_humans(board, crosses, vec!["pass", "4-5", "5-4"]);
+
+        for _ in 0..7 {
+            game.next_turn();
+            assert_eq!(InProgress, game.state);
+        }
+
+        assert_eq!(Nought, game.current_player_token());
+    }
+
+    #[test]
+    fn it_lets_the_other_side_start_after_a_cross_handicap() {
+        let board = Board::new(3).with_preset(4, Cross);
+        let mut game = setup_humans(board, vec!["1"], vec!["9"]);
+
+        assert_eq!(Nought, game.current_player_token());
+        assert_eq!(1, game.current_player_index());
+        game.next_turn();
+        assert_eq!(Nought, game.get_board().get_cells()[8]);
+        assert_eq!(Cross, game.current_player_token());
+    }
+
+    #[test]
+    fn it_hands_colours_to_the_player_who_chooses() {
+        let board = Board::gomoku(15, 5).with_opening(Opening::Swap);
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["1", "2", "3", "20"])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["x"])));
+        let mut game = Game::new(board, player_one, player_two);
+
+        for _ in 0..3 {
+            assert_eq!(0, game.current_player_index());
+            game.next_turn();
+        }
+        assert_eq!(1, game.current_player_index());
+        game.next_turn();
+
+        assert_eq!(&Cross, game.players[1].get_token());
+        assert_eq!(&Nought, game.players[0].get_token());
+        assert_eq!(Nought, game.current_player_token());
+        assert_eq!(0, game.current_player_index());
+        game.next_turn();
+        assert_eq!(Nought, game.get_board().get_cells()[19]);
+    }
+
+    #[test]
+    fn it_rotates_turns_between_three_players() {
+        let board = Board::gomoku(4, 3).with_players(3);
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(Human::new(Cross, MockInput::new(vec!["1"]))),
+            Box::new(Human::new(Nought, MockInput::new(vec!["2"]))),
+            Box::new(Human::new(Triangle, MockInput::new(vec!["3"]))),
+        ];
+        let mut game = Game::with_players(board, players);
+
+        for index in 0..3 {
+            assert_eq!(index, game.current_player_index());
+            game.next_turn();
+        }
+
+        assert_eq!(Triangle, game.get_board().get_cells()[2]);
+        assert_eq!(Cross, game.current_player_token());
+        assert_eq!(3, game.get_player_count());
+    }
+
+    #[test]
+    fn it_credits_wild_win_to_player_completing_line() {
+        let board = Board::wild(3).update(0, Nought).update(1, Nought);
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["3o"])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec![])));
+        let mut game = Game::new(board, player_one, player_two);
+
+        game.next_turn();
+        assert_eq!(Over, game.state);
+        assert_eq!(&Cross, game.get_winner());
+    }
+
+    #[test]
+    fn it_credits_order_and_chaos_win_to_role() {
+        let mut board = Board::order_and_chaos(6, 5);
+        for i in [0, 1, 2, 3] {
+            board = board.update(i, Cross);
+        }
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec![])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["5x"])));
+        let mut game = Game::new(board, player_one, player_two);
+
+        assert_eq!(Some(Role::Order), game.current_player_role());
+        game.board = game.board.update(35, Nought);
+        assert_eq!(Some(Role::Chaos), game.current_player_role());
+        game.next_turn();
+        assert_eq!(Over, game.state);
+        assert_eq!(&Cross, game.get_winner());
+        assert_eq!(Some(Role::Order), game.get_winning_role());
+    }
+
+    #[test]
+    fn it_has_no_roles_in_regular_game() {
+        let game = setup_computer_vs_computer(Board::new(3));
+        assert_eq!(None, game.current_player_role());
+        assert_eq!(None, game.get_winning_role());
+    }
+
+    #[test]
+    fn it_slides_tokens_once_all_pieces_are_placed() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["3", "1-5"])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["2-3"])));
+        let mut game = Game::new(board, player_one, player_two);
+
+        game.next_turn();
+        assert_eq!(&Cross, &game.board.get_cells()[0]);
+        assert_eq!(Cross, game.current_player_token());
+
+        game.next_turn();
+        assert_eq!(&Cross, &game.board.get_cells()[4]);
+        assert_eq!(Nought, game.current_player_token());
+    }
+
+    #[test]
+    fn it_ends_game_when_player_cannot_move() {
+        let board = Board::with_limit(3, Limit::Slide(4))
+            .update(3, Cross)
+            .update(0, Nought)
+            .update(5, Cross)
+            .update(2, Nought)
+            .update(6, Cross)
+            .update(4, Nought)
+            .update(8, Cross);
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec![])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["8"])));
+        let mut game = Game::new(board, player_one, player_two);
+
+        game.next_turn();
+        assert_eq!(Over, game.state);
+        assert_eq!(&Empty, game.get_winner());
+    }
+
+    #[test]
+    fn it_keeps_the_turn_and_analyses_the_position_on_a_hint() {
+        let board = Board::new(3).update(0, Cross).update(3, Nought).update(1, Cross);
+        let mock_input = MockInput::new(vec!["hint", "3"]);
+        let player_one = Box::new(Computer::new(Cross, Lazy::new()));
+        let player_two = Box::new(Human::new(Nought, mock_input));
+        let mut game = Game::new(board.clone(), player_one, player_two);
+
+        game.next_turn();
+        let hint = game.get_hint().expect("Missing hint").clone().expect("No analysis");
+        assert_eq!(board, game.board);
+        assert_eq!(Nought, game.current_player_token());
+        assert_eq!(Move::Place(2), hint[0].choice);
+
+        game.next_turn();
+        assert!(game.get_hint().is_none());
+        assert_eq!(Cross, game.current_player_token());
+    }
+
+    #[test]
+    fn it_records_the_moves_that_changed_the_board() {
+        let board = Board::new(3).update(0, Cross).update(3, Nought).update(1, Cross);
+        let mock_input = MockInput::new(vec!["hint", "3"]);
+        let player_one = Box::new(Computer::new(Cross, Lazy::new()));
+        let player_two = Box::new(Human::new(Nought, mock_input));
+        let mut game = Game::new(board.clone(), player_one, player_two);
+
+        game.next_turn();
+        game.next_turn();
+        game.next_turn();
+        let turns = game.get_turns();
+
+        assert_eq!(2, turns.len());
+        assert_eq!(board, turns[0].board);
+        assert_eq!(Move::Place(2), turns[0].choice);
+        assert!(turns[0].human);
+        assert_eq!(Cross, turns[1].token);
+        assert!(!turns[1].human);
+    }
+
+    #[test]
+    fn it_ends_the_game_when_a_player_runs_out_of_time() {
+        let board = Board::new(3);
+        let mock_input = MockInput::new(vec!["1"]);
+        let player_one = Box::new(Human::new(Cross, mock_input));
+        let player_two = Box::new(Computer::new(Nought, Lazy::new()));
+        let control = TimeControl::new().with_move_limit(Duration::from_secs(0));
+        let mut game = Game::new(board, player_one, player_two).with_clock(control);
+
+        game.next_turn();
+        assert_eq!(Over, game.state);
+        assert_eq!(Board::new(3), game.board);
+        assert_eq!(Some(&Cross), game.get_flagged_token());
+        assert_eq!(&Nought, game.get_winner());
+    }
+
+    #[test]
+    fn it_charges_each_move_to_the_clock() {
+        let control = TimeControl::parse("1").expect("Invalid time control");
+        let mut game = setup_computer_vs_computer(Board::new(3)).with_clock(control);
+
+        game.next_turn();
+        let clock = game.get_clock().expect("Missing clock");
+        assert!(clock.get_remaining(0) < Some(Duration::from_secs(60)));
+        assert_eq!(Some(Duration::from_secs(60)), clock.get_remaining(1));
+        assert_eq!(None, game.get_flagged_token());
+    }
+
+    #[test]
+    fn it_credits_the_increment_only_for_moves_that_end_the_turn() {
+        let control = TimeControl::parse("1+2").expect("Invalid time control");
+        let board = Board::new(3).update(0, Cross).update(4, Nought);
+        let mut game = setup_humans(board, vec!["ten", "hint", "9"], vec![]).with_clock(control);
+
+        game.next_turn();
+        game.next_turn();
+        let clock = game.get_clock().expect("Missing clock");
+        assert_eq!(Some(Duration::from_secs(60)), clock.get_remaining(0));
+        assert!(clock.time_left(0) < Some(Duration::from_secs(60)));
+
+        game.next_turn();
+        let clock = game.get_clock().expect("Missing clock");
+        let remaining = clock.get_remaining(0).expect("Missing total");
+        assert!(remaining > Duration::from_secs(61) && remaining <= Duration::from_secs(62));
+    }
+
+    #[test]
+    fn it_scores_a_resignation_from_the_seat_that_resigned() {
+        let board = Board::gomoku(4, 3).with_players(3);
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(Human::new(Cross, MockInput::new(vec!["1"]))),
+            Box::new(Human::new(Nought, MockInput::new(vec!["resign"]))),
+            Box::new(Human::new(Triangle, MockInput::new(vec![]))),
+        ];
+        let mut game = Game::with_players(board, players);
+
+        game.next_turn();
+        game.next_turn();
+        assert_eq!(GameState::Resigned(1), game.state);
+        assert_eq!(Some(&Nought), game.get_resigned_token());
+        assert_eq!(&Empty, game.get_winner());
+    }
+
+    pub fn setup_humans(
+        board: Board,
+        crosses: Vec<&'static str>,
+        noughts: Vec<&'static str>,
+    ) -> Game {
+        let player_one = Box::new(Human::new(Cross, MockInput::new(crosses)));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(noughts)));
+        Game::new(board, player_one, player_two)
+    }
+
+    #[test]
+    fn it_ends_the_game_when_a_player_resigns() {
+        let mut game = setup_humans(Board::new(3), vec!["5"], vec!["resign"]);
+
+        game.next_turn();
+        game.next_turn();
+        assert_eq!(GameState::Resigned(1), game.state);
+        assert_eq!(Some(&Nought), game.get_resigned_token());
+        assert_eq!(&Cross, game.get_winner());
+    }
+
+    #[test]
+    fn it_agrees_a_draw_the_opponent_accepts() {
+        let mut game = setup_humans(Board::new(3), vec!["draw", "5"], vec!["accept"]);
+
+        game.next_turn();
+        assert_eq!(Some(&Cross), game.get_draw_offer());
+        assert_eq!(Cross, game.current_player_token());
+
+        game.next_turn();
+        game.next_turn();
+        assert_eq!(GameState::DrawAgreed, game.state);
+        assert_eq!(&Empty, game.get_winner());
+    }
+
+    #[test]
+    fn it_drops_a_draw_offer_that_is_declined_or_answered_with_a_move() {
+        let crosses = vec!["draw", "5", "accept", "draw", "1"];
+        let mut game = setup_humans(Board::new(3), crosses, vec!["decline", "9", "3"]);
+
+        game.next_turn();
+        game.next_turn();
+        game.next_turn();
+        assert_eq!(None, game.get_draw_offer());
+        assert_eq!(Nought, game.current_player_token());
+
+        game.next_turn();
+        game.next_turn();
+        game.next_turn();
+        game.next_turn();
+        game.next_turn();
+        assert_eq!(None, game.get_draw_offer());
+        assert_eq!(InProgress, game.state);
+        assert_eq!(4, game.board.moves_played());
+    }
+
+    #[test]
+    fn it_passes_the_turn_only_where_passing_is_allowed() {
+        let mut game = setup_humans(Board::new(3), vec!["pass", "5"], vec![]);
+        game.next_turn();
+        assert_eq!(Cross, game.current_player_token());
+
+        let board = Board::with_limit(3, Limit::Slide(3)).with_passing();
+        let mut game = setup_humans(board, vec!["pass", "pass"], vec!["5", "pass"]);
+        game.next_turn();
+        assert_eq!(Nought, game.current_player_token());
+
+        game.next_turn();
+        assert_eq!(Nought, game.board.get_cells()[4]);
+        game.next_turn();
+        game.next_turn();
+        assert_eq!(Over, game.state);
+        assert_eq!(&Empty, game.get_winner());
+    }
+
+    #[test]
+    fn it_refuses_a_pass_in_three_mens_morris() {
+        let board = Variant::Morris(3).board();
+        let mut game = setup_humans(board, vec!["pass", "5"], vec![]);
+
+        game.next_turn();
+        assert_eq!(Cross, game.current_player_token());
+        assert_eq!(0, game.passes);
+        game.next_turn();
+        assert_eq!(Cross, game.board.get_cells()[4]);
+        assert_eq!(Nought, game.current_player_token());
+    }
+
+    #[test]
+    fn it_lets_the_second_seat_start_when_sides_are_swapped() {
+        let game = setup_human_vs_computer().with_sides_swapped();
+
+        assert_eq!(vec![Nought, Cross], game.get_seat_tokens());
+        assert_eq!(1, game.current_player_index());
+    }
+
+    #[test]
+    fn it_names_the_humans_in_seat_order() {
+        let game = setup_human_vs_computer().with_names(&[String::from("ada"), String::from("bo")]);
+
+        assert_eq!("ada", game.get_player_names()[0]);
+        assert!(game.get_player_names()[1].starts_with("computer:"));
+    }
 }
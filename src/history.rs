@@ -0,0 +1,519 @@
+use board::{Board, Topology};
+use game::Game;
+use layout::{self, Layout};
+use moves::Move;
+use opening::Opening;
+use player::human;
+use solver::Problem;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use token::PLAYER_TOKENS;
+use token::Token::{self, Empty};
+use variant::Variant;
+
+const FIELD_SEPARATOR: char = '\t';
+const PLAYER_SEPARATOR: char = '|';
+const MOVE_SEPARATOR: char = ':';
+const PLAIN_SEPARATOR: char = 'k';
+const COMMENT: char = ';';
+const OPEN: &str = "-";
+const DRAW: &str = "draw";
+const FIELDS: usize = 10;
+const SECONDS_PER_DAY: u64 = 86_400;
+const SECONDS_PER_HOUR: u64 = 3_600;
+const SECONDS_PER_MINUTE: u64 = 60;
+
+/// The rules a game was played under: a variant, or a plain k in a row
+/// board as used by the solver and tournaments.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Rules {
+    Variant(Variant),
+    Plain(usize, usize),
+}
+
+/// A finished game, with everything needed to play it through again.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Record {
+    pub date: String,
+    pub duration: Duration,
+    pub rules: Rules,
+    pub topology: Topology,
+    pub opening: Opening,
+    pub layout: String,
+    pub players: Vec<String>,
+    pub tokens: Vec<Token>,
+    pub winner: Token,
+    pub moves: Vec<(Token, Move)>,
+}
+
+/// Every finished game in the order played, kept in a plain-text file that
+/// only ever grows, one tab-separated line per game: the date, the
+/// duration in milliseconds, the rules, topology, opening rule and starting
+/// layout, the players and their tokens in seat order, the result and the
+/// moves as typed, each after its mover's token, as in `X:5 O:1 X:9`.
+/// Lines that cannot be read are skipped and their numbers kept.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct History {
+    records: Vec<Record>,
+    skipped: Vec<usize>,
+}
+
+/// How a game ended, for a query: a draw, a win for a token, or a win or
+/// loss for the player queried.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Verdict {
+    Draw,
+    Won(Token),
+    Win,
+    Loss,
+}
+
+/// The games to pick from a history; a query without filters picks all.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Query {
+    pub player: Option<String>,
+    pub verdict: Option<Verdict>,
+    pub opening: Vec<String>,
+    pub date: Option<String>,
+}
+
+impl Rules {
+    pub fn parse(spec: &str) -> Option<Rules> {
+        if let Some(variant) = Variant::from_spec(spec) {
+            return Some(Rules::Variant(variant));
+        }
+
+        let mut parts = spec.splitn(2, PLAIN_SEPARATOR);
+        let size = parts.next()?.split('x').next()?.parse().ok()?;
+        let win_length = parts.next()?.parse().ok()?;
+        Some(Rules::Plain(size, win_length))
+    }
+
+    /// The rules of a plain board, as in `4x4k3`.
+    pub fn plain(board: &Board) -> Rules {
+        Rules::Plain(board.get_row_size(), board.get_win_length())
+    }
+
+    pub fn to_spec(&self) -> String {
+        match *self {
+            Rules::Variant(ref variant) => variant.to_spec(),
+            Rules::Plain(size, win_length) => {
+                format!("{}x{}{}{}", size, size, PLAIN_SEPARATOR, win_length)
+            }
+        }
+    }
+
+    fn board(&self) -> Board {
+        match *self {
+            Rules::Variant(ref variant) => variant.board(),
+            Rules::Plain(size, win_length) => Problem::new(size, win_length).board(),
+        }
+    }
+}
+
+impl Record {
+    /// Records a finished game that ended at `date` after `duration`.
+    pub fn new(rules: Rules, game: &Game, date: String, duration: Duration) -> Record {
+        let start = game.get_start();
+        let layout = match start.get_cells().iter().all(|cell| *cell == Empty) {
+            true => String::from(OPEN),
+            false => layout::describe(start),
+        };
+
+        Record {
+            date,
+            duration,
+            rules,
+            topology: start.get_topology(),
+            opening: start.get_opening(),
+            layout,
+            players: game.get_player_names(),
+            tokens: game.get_seat_tokens(),
+            winner: *game.get_winner(),
+            moves: game
+                .get_turns()
+                .iter()
+                .map(|turn| (turn.token, turn.choice))
+                .collect(),
+        }
+    }
+
+    /// Reads a line, playing the moves through to check them.
+    pub fn parse(line: &str) -> Result<Record, String> {
+        let invalid = || format!("Invalid game record: {}", line);
+        let fields: Vec<&str> = line.split(FIELD_SEPARATOR).collect();
+        if fields.len() != FIELDS {
+            return Err(invalid());
+        }
+
+        let mut record = Record {
+            date: String::from(fields[0]),
+            duration: Duration::from_millis(fields[1].parse().map_err(|_| invalid())?),
+            rules: Rules::parse(fields[2]).ok_or_else(invalid)?,
+            topology: Topology::from_name(fields[3]).ok_or_else(invalid)?,
+            opening: Opening::from_name(fields[4]).ok_or_else(invalid)?,
+            layout: String::from(fields[5]),
+            players: fields[6].split(PLAYER_SEPARATOR).map(String::from).collect(),
+            tokens: fields[7]
+                .split_whitespace()
+                .map(|symbol| parse_token(symbol).ok_or_else(invalid))
+                .collect::<Result<Vec<Token>, String>>()?,
+            winner: match fields[8] {
+                DRAW => Empty,
+                symbol => parse_token(symbol).ok_or_else(invalid)?,
+            },
+            moves: Vec::new(),
+        };
+
+        let mut board = record.start()?;
+        for text in fields[9].split_whitespace() {
+            let mut parts = text.splitn(2, MOVE_SEPARATOR);
+            let token = parts.next().and_then(parse_token).ok_or_else(invalid)?;
+            let choice = human::parse_move(parts.next().unwrap_or(""), &board)
+                .map_err(|_| invalid())?;
+            board = board.apply(choice, token);
+            record.moves.push((token, choice));
+        }
+
+        Ok(record)
+    }
+
+    pub fn to_text(&self) -> String {
+        let tokens: Vec<&str> = self.tokens.iter().map(Token::to_str).collect();
+        let moves: Vec<String> = self
+            .moves
+            .iter()
+            .map(|&(token, ref choice)| {
+                format!("{}{}{}", token.to_str(), MOVE_SEPARATOR, human::notation(choice))
+            })
+            .collect();
+        let fields = [
+            self.date.clone(),
+            self.duration.as_millis().to_string(),
+            self.rules.to_spec(),
+            String::from(self.topology.to_str()),
+            String::from(self.opening.to_str()),
+            self.layout.clone(),
+            self.players.join(&PLAYER_SEPARATOR.to_string()),
+            tokens.join(" "),
+            self.result(),
+            moves.join(" "),
+        ];
+
+        fields.join(&FIELD_SEPARATOR.to_string())
+    }
+
+    /// The winning token, or `draw`.
+    pub fn result(&self) -> String {
+        match self.winner {
+            Empty => String::from(DRAW),
+            winner => String::from(winner.to_str()),
+        }
+    }
+
+    /// The position the game started from.
+    pub fn start(&self) -> Result<Board, String> {
+        let board = self.rules
+            .board()
+            .with_topology(self.topology)
+            .with_opening(self.opening);
+
+        match self.layout.as_str() {
+            OPEN => Ok(board),
+            spec => Ok(Layout::parse(spec)?.apply(board)),
+        }
+    }
+
+    /// Every position of the game from the start, one more than there are
+    /// moves.
+    pub fn positions(&self) -> Result<Vec<Board>, String> {
+        let mut positions = vec![self.start()?];
+        for &(token, choice) in self.moves.iter() {
+            let next = positions[positions.len() - 1].clone().apply(choice, token);
+            positions.push(next);
+        }
+
+        Ok(positions)
+    }
+
+    pub fn token_of(&self, player: &str) -> Option<Token> {
+        self.players
+            .iter()
+            .position(|name| name == player)
+            .and_then(|seat| self.tokens.get(seat).cloned())
+    }
+}
+
+impl History {
+    pub fn new() -> History {
+        History::default()
+    }
+
+    pub fn parse(text: &str) -> History {
+        let mut history = History::new();
+        let lines = text
+            .lines()
+            .enumerate()
+            .filter(|&(_, line)| !line.trim().is_empty() && !line.starts_with(COMMENT));
+
+        for (index, line) in lines {
+            match Record::parse(line) {
+                Ok(record) => history.records.push(record),
+                Err(_) => history.skipped.push(index + 1),
+            }
+        }
+
+        history
+    }
+
+    /// Reads the history in a file, or starts an empty one when there is no
+    /// file yet.
+    pub fn load(path: &PathBuf) -> Result<History, String> {
+        match path.exists() {
+            true => {
+                let text = fs::read_to_string(path)
+                    .map_err(|error| format!("{} ({}).", path.display(), error))?;
+                Ok(History::parse(&text))
+            }
+            false => Ok(History::new()),
+        }
+    }
+
+    /// Adds a game to the end of the file, creating it when needed.
+    pub fn append(path: &PathBuf, record: &Record) -> Result<(), String> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", record.to_text()))
+            .map_err(|error| format!("{} ({}).", path.display(), error))
+    }
+
+    pub fn get_records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// The numbers, counting from 1, of the lines that could not be read.
+    pub fn get_skipped(&self) -> &[usize] {
+        &self.skipped
+    }
+
+    /// A game by its number, counting from 1 in the order played.
+    pub fn get(&self, number: usize) -> Option<&Record> {
+        number.checked_sub(1).and_then(|index| self.records.get(index))
+    }
+
+    /// The games a query picks, each with its number.
+    pub fn select(&self, query: &Query) -> Vec<(usize, &Record)> {
+        self.records
+            .iter()
+            .enumerate()
+            .filter(|&(_, record)| query.matches(record))
+            .map(|(index, record)| (index + 1, record))
+            .collect()
+    }
+}
+
+impl Verdict {
+    pub fn from_name(name: &str) -> Option<Verdict> {
+        match name {
+            DRAW => Some(Verdict::Draw),
+            "win" => Some(Verdict::Win),
+            "loss" => Some(Verdict::Loss),
+            symbol => parse_token(&symbol.to_uppercase()).map(Verdict::Won),
+        }
+    }
+}
+
+impl Query {
+    pub fn matches(&self, record: &Record) -> bool {
+        let token = match self.player {
+            Some(ref player) => match record.token_of(player) {
+                Some(token) => Some(token),
+                None => return false,
+            },
+            None => None,
+        };
+        let verdict = match self.verdict {
+            Some(Verdict::Draw) => record.winner == Empty,
+            Some(Verdict::Won(winner)) => record.winner == winner,
+            Some(Verdict::Win) => token == Some(record.winner),
+            Some(Verdict::Loss) => record.winner != Empty && token != Some(record.winner),
+            None => true,
+        };
+        let opening = self.opening.len() <= record.moves.len()
+            && self.opening
+                .iter()
+                .zip(record.moves.iter())
+                .all(|(text, &(_, choice))| *text == human::notation(&choice));
+        let date = match self.date {
+            Some(ref date) => record.date.starts_with(date.as_str()),
+            None => true,
+        };
+
+        verdict && opening && date
+    }
+}
+
+/// The current time in UTC, as in `2026-10-19 08:15:02`.
+pub fn now() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    date_time(seconds)
+}
+
+/// Seconds since 1970 as a UTC date and time, using Howard Hinnant's
+/// days-to-civil algorithm.
+fn date_time(seconds: u64) -> String {
+    let days = (seconds / SECONDS_PER_DAY) as i64 + 719_468;
+    let time = seconds % SECONDS_PER_DAY;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / SECONDS_PER_HOUR,
+        time % SECONDS_PER_HOUR / SECONDS_PER_MINUTE,
+        time % SECONDS_PER_MINUTE
+    )
+}
+
+fn parse_token(symbol: &str) -> Option<Token> {
+    PLAYER_TOKENS.iter().find(|token| token.to_str() == symbol).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::GameState::InProgress;
+    use game::tests::*;
+    use moves::Move::{Choose, Place};
+    use token::Token::{Cross, Nought};
+
+    fn played(
+        rules: Rules,
+        board: Board,
+        crosses: Vec<&'static str>,
+        noughts: Vec<&'static str>,
+    ) -> Record {
+        let mut game = setup_humans(board, crosses, noughts);
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+        let date = String::from("2026-10-19 08:15:02");
+        Record::new(rules, &game, date, Duration::from_millis(1500))
+    }
+
+    #[test]
+    fn it_reads_back_the_line_it_writes() {
+        let record = played(
+            Rules::Variant(Variant::Standard),
+            Board::new(3),
+            vec!["1", "2", "3"],
+            vec!["4", "5"],
+        );
+        let line = record.to_text();
+
+        let expected = "2026-10-19 08:15:02\t1500\tstandard\tflat\tfree\t-\tguest|guest\tX O\tX\t\
+                        X:1 O:4 X:2 O:5 X:3";
+        assert_eq!(expected, line);
+        assert_eq!(Ok(record), Record::parse(&line));
+        let illegal = "2026-10-19\t1500\tstandard\tflat\tfree\t-\tguest\tX\tX\tX:10";
+        assert!(Record::parse(illegal).is_err());
+    }
+
+    #[test]
+    fn it_skips_lines_it_cannot_read() {
+        let line = "2026-10-19 08:15:02\t1500\tstandard\tflat\tfree\t-\tada|bo\tX O\tX\t\
+                    X:1 O:4 X:2 O:5 X:3";
+        let text = format!("{}\n; note\n{}\n\ngarbage\n{}", line, line, &line[..30]);
+        let history = History::parse(&text);
+
+        assert_eq!(2, history.get_records().len());
+        assert_eq!(&[5, 6], history.get_skipped());
+    }
+
+    #[test]
+    fn it_rebuilds_layouts_and_openings() {
+        let board = Variant::Gomoku.board().with_opening(Opening::Swap);
+        let record = played(
+            Rules::Variant(Variant::Gomoku),
+            board,
+            vec!["1", "2", "3", "resign"],
+            vec!["x"],
+        );
+        let parsed = Record::parse(&record.to_text()).expect("Invalid record");
+
+        assert_eq!(Some(&Choose(Cross)), parsed.moves.last().map(|(_, choice)| choice));
+        assert_eq!(record, parsed);
+        assert_eq!(5, parsed.positions().unwrap().len());
+
+        let board = Layout::parse("....#....").unwrap().apply(Board::new(3));
+        let record = played(Rules::plain(&board), board, vec!["1", "2", "3"], vec!["4", "6"]);
+        let line = record.to_text();
+
+        assert!(line.contains("\t3x3k3\t"));
+        assert!(line.contains("\t.../.#./...\t"));
+        assert_eq!(Ok(record), Record::parse(&line));
+        assert_eq!(Ok(Rules::Plain(4, 3)), Rules::parse("4x4k3").ok_or(()));
+    }
+
+    #[test]
+    fn it_selects_games_by_player_result_opening_and_date() {
+        let mut first = played(
+            Rules::Variant(Variant::Standard),
+            Board::new(3),
+            vec!["1", "2", "3"],
+            vec!["4", "5"],
+        );
+        first.players = vec![String::from("ada"), String::from("bo")];
+        let mut second = first.clone();
+        second.date = String::from("2026-11-02 10:00:00");
+        second.winner = Empty;
+        second.moves = vec![(Cross, Place(4))];
+        let history = History {
+            records: vec![first, second],
+            skipped: Vec::new(),
+        };
+
+        let query = |player: Option<&str>, verdict, opening: Vec<&str>, date: Option<&str>| Query {
+            player: player.map(String::from),
+            verdict,
+            opening: opening.into_iter().map(String::from).collect(),
+            date: date.map(String::from),
+        };
+        let numbers = |query: Query| -> Vec<usize> {
+            history.select(&query).iter().map(|&(number, _)| number).collect()
+        };
+
+        assert_eq!(vec![1, 2], numbers(Query::default()));
+        assert_eq!(vec![1], numbers(query(Some("ada"), Some(Verdict::Win), vec![], None)));
+        assert_eq!(vec![1], numbers(query(Some("bo"), Some(Verdict::Loss), vec![], None)));
+        assert_eq!(vec![2], numbers(query(None, Some(Verdict::Draw), vec![], None)));
+        assert_eq!(vec![1], numbers(query(None, None, vec!["1", "4"], None)));
+        assert_eq!(vec![2], numbers(query(None, None, vec![], Some("2026-11"))));
+        assert!(numbers(query(Some("cy"), None, vec![], None)).is_empty());
+        assert_eq!(Some(Verdict::Won(Nought)), Verdict::from_name("o"));
+    }
+
+    #[test]
+    fn it_writes_dates_in_utc() {
+        assert_eq!("1970-01-01 00:00:00", date_time(0));
+        assert_eq!("2000-02-29 23:59:59", date_time(951_868_799));
+        assert_eq!("2026-10-19 08:15:02", date_time(1_792_397_702));
+    }
+}
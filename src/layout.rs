@@ -0,0 +1,151 @@
+use board::Board;
+use rand::{Rng, SeedableRng, StdRng};
+use token::Token::{self, Blocked, Cross, Empty, Nought};
+
+const ROW_SEPARATOR: char = '/';
+
+/// Cells set before the first move: a spec of blocked cells and handicap
+/// stones, plus a number of obstacles scattered from a seed.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Layout {
+    preset: Vec<Token>,
+    obstacles: usize,
+    seed: usize,
+}
+
+impl Layout {
+    pub fn new() -> Layout {
+        Layout {
+            preset: Vec::new(),
+            obstacles: 0,
+            seed: 0,
+        }
+    }
+
+    pub fn parse(spec: &str) -> Result<Layout, String> {
+        let preset = spec.chars()
+            .filter(|symbol| *symbol != ROW_SEPARATOR)
+            .map(|symbol| match symbol {
+                '.' => Ok(Empty),
+                '#' => Ok(Blocked),
+                'x' | 'X' => Ok(Cross),
+                'o' | 'O' => Ok(Nought),
+                _ => Err(format!("Invalid layout symbol {}.", symbol)),
+            })
+            .collect::<Result<Vec<Token>, String>>()?;
+
+        Ok(Layout {
+            preset,
+            ..Layout::new()
+        })
+    }
+
+    pub fn with_obstacles(self, obstacles: usize, seed: usize) -> Layout {
+        Layout {
+            obstacles,
+            seed,
+            ..self
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.preset.iter().all(|cell| *cell == Empty) && self.obstacles == 0
+    }
+
+    pub fn fits(&self, board: &Board) -> bool {
+        let placed = self.preset.iter().filter(|cell| **cell != Empty).count();
+        let fits_preset = self.preset.is_empty() || self.preset.len() == board.get_length();
+
+        fits_preset && placed + self.obstacles < board.get_length()
+    }
+
+    pub fn apply(&self, board: Board) -> Board {
+        let length = board.get_length();
+        let mut board = self.preset
+            .iter()
+            .enumerate()
+            .filter(|&(cell, _)| cell < length)
+            .fold(board, |board, (cell, token)| match *token {
+                Empty => board,
+                _ => board.with_preset(cell, *token),
+            });
+
+        let mut free = board.empty_cells();
+        let mut rng: StdRng = SeedableRng::from_seed(&[self.seed][..]);
+        rng.shuffle(&mut free);
+
+        for cell in free.into_iter().take(self.obstacles) {
+            board = board.with_preset(cell, Blocked);
+        }
+
+        board
+    }
+}
+
+/// The spec of a board's blocked cells and stones, which rebuilds the board
+/// when applied to an open one of the same kind.
+pub fn describe(board: &Board) -> String {
+    let rows: Vec<String> = board
+        .get_cells()
+        .chunks(board.get_row_size())
+        .map(|row| {
+            row.iter()
+                .map(|cell| match *cell {
+                    Blocked => '#',
+                    Cross => 'x',
+                    Nought => 'o',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+
+    rows.join(&ROW_SEPARATOR.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_layout_spec() {
+        let layout = Layout::parse("x../.#./..o").expect("Invalid layout");
+        let board = layout.apply(Board::new(3));
+
+        assert_eq!(
+            &vec![Cross, Empty, Empty, Empty, Blocked, Empty, Empty, Empty, Nought],
+            board.get_cells()
+        );
+        assert!(Layout::parse("x.z").is_err());
+        assert_eq!("x../.#./..o", describe(&board));
+    }
+
+    #[test]
+    fn it_scatters_the_same_obstacles_for_a_seed() {
+        let layout = Layout::new().with_obstacles(3, 42);
+        let board = layout.apply(Board::new(4));
+        let blocked = board.get_cells().iter().filter(|cell| **cell == Blocked).count();
+
+        assert_eq!(3, blocked);
+        assert_eq!(board, layout.apply(Board::new(4)));
+        assert_eq!(13, board.empty_cells().len());
+    }
+
+    #[test]
+    fn it_keeps_obstacles_off_preset_cells() {
+        let layout = Layout::parse("xo.o.x.x.").expect("Invalid layout").with_obstacles(4, 7);
+        let board = layout.apply(Board::new(3));
+
+        assert_eq!(Cross, board.get_cells()[0]);
+        assert_eq!(Nought, board.get_cells()[1]);
+        assert_eq!(0, board.empty_cells().len());
+    }
+
+    #[test]
+    fn it_checks_layout_fits_board() {
+        assert!(Layout::parse("#........").expect("Invalid layout").fits(&Board::new(3)));
+        assert!(!Layout::parse("#...").expect("Invalid layout").fits(&Board::new(3)));
+        assert!(!Layout::new().with_obstacles(9, 1).fits(&Board::new(3)));
+        assert!(Layout::new().is_open());
+    }
+}
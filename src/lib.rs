@@ -1,52 +1,400 @@
 extern crate rand;
 
+pub mod analysis;
 pub mod board;
+pub mod book;
+pub mod cli;
+pub mod clock;
 pub mod game;
+pub mod history;
+pub mod layout;
+pub mod matches;
+pub mod moves;
+pub mod opening;
 pub mod player;
+pub mod quantum;
+pub mod ratings;
+pub mod replay;
+pub mod review;
 pub mod rules;
+pub mod settings;
 pub mod setup;
+pub mod solver;
+pub mod tablebase;
 pub mod token;
+pub mod tournament;
 pub mod ui;
+pub mod variant;
 
+use book::{Book, SelfPlay};
 use game::GameState::InProgress;
 use game::Game;
+use history::{History, Query, Record, Rules};
+use matches::{Format, Match, Side};
+use player::strategy::menace::{Menace, Training};
+use ratings::{Registry, System};
+use replay::{Action, Replay};
+use rules::Role;
+use settings::Settings;
+use solver::{Problem, Solution};
 use std::io::Write;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+use tablebase::Tablebase;
+use token::PLAYER_TOKENS;
+use token::Token::{Cross, Nought};
+use tournament::{Report, Tournament};
 use ui::*;
 use ui::color::Color;
 use ui::input::Input;
 use ui::view::View;
+use variant::Variant;
 
-pub fn run<I: Input, W: Write>(mut user_input: I, mut view: &mut View<W>, color: &Color) -> Game {
-    let mut game;
+pub fn run<I: Input, W: Write>(user_input: I, view: &mut View<W>, color: &Color) -> Game {
+    run_variant(&Variant::Standard, user_input, view, color)
+}
 
-    welcome(&mut user_input, &mut view);
+pub fn run_variant<I: Input, W: Write>(
+    variant: &Variant,
+    user_input: I,
+    view: &mut View<W>,
+    color: &Color,
+) -> Game {
+    run_with(&Settings::new(*variant), user_input, view, color)
+}
 
-    loop {
-        let mode_selection = setup::select_mode(&mut user_input, &mut view);
-        game = setup::setup_game(mode_selection);
+pub fn run_with<I: Input, W: Write>(
+    settings: &Settings,
+    mut user_input: I,
+    view: &mut View<W>,
+    color: &Color,
+) -> Game {
+    let variant = settings.get_variant();
+    let board = settings.board();
+    let mut game;
 
-        while game.get_state() == &InProgress {
-            prompt_turn(&mut game, view, color);
-            game.next_turn();
-        }
+    welcome(&mut user_input, view);
 
-        show_winner(&mut game, view, color);
+    loop {
+        game = match (board.get_player_count(), settings.get_match_format()) {
+            (2, Some(format)) => play_match(format, settings, &mut user_input, view, color),
+            (2, None) => {
+                let (mode_selection, human_role) = select_players(variant, &mut user_input, view);
+                let mut game = setup::setup_game(mode_selection, settings, human_role);
+                play_out(&mut game, settings, view, color);
+                game
+            }
+            (count, _) => {
+                let tokens = &PLAYER_TOKENS[..count];
+                let seats = setup::select_seats(&mut user_input, view, tokens);
+                let mut game = setup::setup_seats(settings, &seats);
+                play_out(&mut game, settings, view, color);
+                game
+            }
+        };
 
-        if select_exit(&mut user_input, &mut view) {
+        if select_exit(&mut user_input, view) {
             break;
         };
     }
 
-    goodbye(&mut view);
+    goodbye(view);
     game
 }
 
+fn select_players<I: Input, W: Write>(
+    variant: &Variant,
+    user_input: &mut I,
+    view: &mut View<W>,
+) -> (u32, Role) {
+    let mode_selection = setup::select_mode(user_input, view);
+    let human_role = match variant.has_roles() && setup::is_human_vs_computer(mode_selection) {
+        true => setup::select_role(user_input, view),
+        false => Role::Order,
+    };
+
+    (mode_selection, human_role)
+}
+
+/// Plays games between the same two players until the match is decided,
+/// handing the first move to the other side each game.
+fn play_match<I: Input, W: Write>(
+    format: Format,
+    settings: &Settings,
+    user_input: &mut I,
+    view: &mut View<W>,
+    color: &Color,
+) -> Game {
+    let (mode_selection, human_role) = select_players(settings.get_variant(), user_input, view);
+    let mut contest = Match::new(format);
+
+    loop {
+        let game = setup::setup_game(mode_selection, settings, human_role);
+        let mut game = match contest.starter() {
+            Side::First => game,
+            Side::Second => game.with_sides_swapped(),
+        };
+        play_out(&mut game, settings, view, color);
+        contest.record(game.get_winner(), &game.get_seat_tokens());
+        show_match_score(&contest, view);
+
+        if contest.is_over() {
+            show_match_summary(&contest, view);
+            return game;
+        }
+        next_game(user_input, view);
+    }
+}
+
+fn play_out<W: Write>(game: &mut Game, settings: &Settings, view: &mut View<W>, color: &Color) {
+    let start = Instant::now();
+    while game.get_state() == &InProgress {
+        prompt_turn(game, view, color);
+        game.next_turn();
+    }
+    let duration = start.elapsed();
+
+    show_winner(game, view, color);
+    show_review(game, view);
+
+    if let Some(path) = settings.get_ratings() {
+        if let Err(message) = rate(path, game, view) {
+            view.append_with(&message);
+        }
+    }
+
+    if let Some(path) = settings.get_history() {
+        let rules = Rules::Variant(*settings.get_variant());
+        let record = Record::new(rules, game, history::now(), duration);
+        match History::append(path, &record) {
+            Ok(()) => show_history_written(path, view),
+            Err(message) => view.append_with(&message),
+        }
+    }
+}
+
+/// Records a finished game in the ratings file, when it is between two
+/// differently named players.
+fn rate<W: Write>(path: &PathBuf, game: &Game, view: &mut View<W>) -> Result<(), String> {
+    let mut registry = Registry::load(path)?;
+    if let Some(before) = registry.record_game(game) {
+        registry.save(path)?;
+        show_rating_changes(&game.get_player_names(), &before, &registry, view);
+    }
+    Ok(())
+}
+
+pub fn solve_with<W: Write>(problem: &Problem, view: &mut View<W>) -> Result<Solution, String> {
+    let solution = solver::run(problem)?;
+    show_solution(problem, &solution, view);
+    Ok(solution)
+}
+
+/// Generates the tablebase for a problem, writes it to the problem's table
+/// file and reports it.
+pub fn generate_with<W: Write>(
+    problem: &Problem,
+    view: &mut View<W>,
+) -> Result<Tablebase, String> {
+    let tablebase = Tablebase::generate(&problem.board())?;
+    if let Some(ref path) = problem.table {
+        tablebase.save(path).map_err(|error| error.to_string())?;
+    }
+
+    show_tablebase(problem, &tablebase, view);
+    Ok(tablebase)
+}
+
+/// Adds a batch of self-play games, and the recorded games in a history
+/// file when one is given, to the opening book in the problem's table file,
+/// starting a new book when there is none yet, and reports it.
+pub fn build_book_with<W: Write>(
+    problem: &Problem,
+    self_play: &SelfPlay,
+    history: Option<&PathBuf>,
+    view: &mut View<W>,
+) -> Result<Book, String> {
+    let board = problem.board();
+    let mut book = match problem.table {
+        Some(ref path) if path.exists() => Book::load(path)?,
+        _ => Book::new(&board)?,
+    };
+    if !book.fits(&board) {
+        return Err(format!("The opening book for {} does not fit.", book.get_signature()));
+    }
+
+    let recorded = match history {
+        Some(path) => {
+            let history = History::load(path)?;
+            show_skipped_lines(path, &history, view);
+            Some(credit_history(&mut book, &history, self_play.plies))
+        }
+        None => None,
+    };
+    book.play(&board, self_play);
+    if let Some(ref path) = problem.table {
+        book.save(path)?;
+    }
+
+    show_book(problem, self_play, recorded, &book, view);
+    Ok(book)
+}
+
+/// Credits the openings of the recorded games the book fits, returning how
+/// many there were.
+fn credit_history(book: &mut Book, history: &History, plies: usize) -> usize {
+    let mut credited = 0;
+    for record in history.get_records() {
+        if let Ok(start) = record.start() {
+            if book.fits(&start) {
+                book.credit(&start, &record.moves, record.winner, plies);
+                credited += 1;
+            }
+        }
+    }
+    credited
+}
+
+/// Trains the learner in the problem's table file, starting a new one when
+/// there is none yet, saves it and reports the games played.
+pub fn train_with<W: Write>(
+    problem: &Problem,
+    training: &Training,
+    view: &mut View<W>,
+) -> Result<Menace, String> {
+    let board = problem.board();
+    let mut learner = match problem.table {
+        Some(ref path) if path.exists() => Menace::load(path)?,
+        _ => Menace::new(&board)?,
+    };
+    if !learner.fits(&board) {
+        return Err(format!("The learned table for {} does not fit.", learner.get_signature()));
+    }
+
+    let record = learner.train(&board, training);
+    if let Some(ref path) = problem.table {
+        learner.save(path)?;
+    }
+
+    show_training(problem, training, &record, &learner, view);
+    Ok(learner)
+}
+
+/// Plays every game of a tournament and reports the results, rating the
+/// games in the ratings file and adding them to the history file when
+/// those are given.
+pub fn tournament_with<W: Write>(
+    tournament: &Tournament,
+    ratings: Option<&PathBuf>,
+    history: Option<&PathBuf>,
+    view: &mut View<W>,
+) -> Result<Report, String> {
+    let registry = match ratings {
+        Some(path) => Some((path, tournament.rating_names()?, Registry::load(path)?)),
+        None => None,
+    };
+    let mut written = Ok(());
+    let report = tournament.run_with(|game: &Game, duration: Duration| {
+        if let (Some(path), true) = (history, written.is_ok()) {
+            let rules = Rules::plain(tournament.get_board());
+            written = History::append(path, &Record::new(rules, game, history::now(), duration));
+        }
+    });
+    written?;
+    show_tournament(tournament, &report, view);
+
+    if let Some((path, names, mut registry)) = registry {
+        for &(cross, nought, winner) in report.games.iter() {
+            let winner = match winner {
+                Cross => Some(0),
+                Nought => Some(1),
+                _ => None,
+            };
+            registry.record(&names[cross], &names[nought], winner);
+        }
+        registry.save(path)?;
+        show_ratings_written(path, view);
+    }
+    if let Some(path) = history {
+        show_history_written(path, view);
+    }
+
+    Ok(report)
+}
+
+/// Shows the players in a ratings file from the highest rated down.
+pub fn ratings_with<W: Write>(
+    path: &PathBuf,
+    system: System,
+    view: &mut View<W>,
+) -> Result<Registry, String> {
+    let registry = Registry::load(path)?;
+    show_leaderboard(path, system, &registry, view);
+    Ok(registry)
+}
+
+/// Lists the games in a history file that a query picks.
+pub fn history_with<W: Write>(
+    path: &PathBuf,
+    query: &Query,
+    view: &mut View<W>,
+) -> Result<History, String> {
+    let history = History::load(path)?;
+    show_skipped_lines(path, &history, view);
+    show_history(path, &history, query, view);
+    Ok(history)
+}
+
+/// Steps through a recorded game, the last one in the history file unless
+/// a number picks another, until the viewer quits.
+pub fn replay_with<I: Input, W: Write>(
+    path: &PathBuf,
+    number: Option<usize>,
+    delay: Duration,
+    mut user_input: I,
+    view: &mut View<W>,
+    color: &Color,
+) -> Result<Replay, String> {
+    let history = History::load(path)?;
+    show_skipped_lines(path, &history, view);
+    let number = number.unwrap_or_else(|| history.get_records().len());
+    let record = history
+        .get(number)
+        .ok_or(format!("{} has no game {}.", path.display(), number))?;
+    let mut replay = Replay::new(record.clone())?;
+
+    show_replay(&replay, view, color);
+    loop {
+        match select_replay_action(&mut user_input, view) {
+            Action::Quit => return Ok(replay),
+            Action::Autoplay if !replay.is_over() => {
+                while !replay.is_over() {
+                    thread::sleep(delay);
+                    replay.step(Action::Forward);
+                    show_replay(&replay, view, color);
+                }
+            }
+            action => {
+                replay.step(action);
+                show_replay(&replay, view, color);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use board::{Board, Limit, Topology};
     use game::GameState::Over;
+    use player::strategy::menace::Opponent;
+    use token::Token::Cross;
     use ui::color::Color::Dim;
     use ui::input::tests::*;
+    use std::env;
+    use std::fs;
+    use ui::script::Script::{InvalidSelection, RoleSelection};
 
     #[test]
     fn it_returns_a_completed_game() {
@@ -57,4 +405,149 @@ mod tests {
 
         assert_eq!(&Over, game.get_state());
     }
+
+    #[test]
+    fn it_returns_a_completed_order_and_chaos_game() {
+        let input = vec!["\n", "4", "n"];
+        let mock_input = MockInput::new(input);
+        let mut view = View::new(Vec::new());
+        let game = run_variant(&Variant::OrderAndChaos, mock_input, &mut view, &Dim);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert_eq!(&Over, game.get_state());
+        assert!(game.get_winning_role().is_some());
+        assert!(!output.contains(RoleSelection.to_str()));
+    }
+
+    #[test]
+    fn it_returns_a_completed_notakto_game() {
+        let input = vec!["\n", "4", "n"];
+        let mock_input = MockInput::new(input);
+        let mut view = View::new(Vec::new());
+        let game = run_variant(&Variant::Notakto(3), mock_input, &mut view, &Dim);
+
+        assert_eq!(&Over, game.get_state());
+        assert!(game.get_board().live_boards().is_empty());
+        assert_eq!(&Cross, game.get_winner());
+    }
+
+    #[test]
+    fn it_returns_a_completed_quantum_game() {
+        let input = vec!["\n", "4", "n"];
+        let mock_input = MockInput::new(input);
+        let mut view = View::new(Vec::new());
+        let game = run_variant(&Variant::Quantum, mock_input, &mut view, &Dim);
+
+        assert_eq!(&Over, game.get_state());
+        assert!(!game.get_board().must_collapse());
+    }
+
+    #[test]
+    fn it_returns_a_completed_game_on_a_torus() {
+        let input = vec!["\n", "4", "n"];
+        let mock_input = MockInput::new(input);
+        let mut view = View::new(Vec::new());
+        let settings = Settings::new(Variant::Standard).with_topology(Topology::Torus);
+        let game = run_with(&settings, mock_input, &mut view, &Dim);
+
+        assert_eq!(&Over, game.get_state());
+        assert_eq!(Topology::Torus, game.get_board().get_topology());
+    }
+
+    #[test]
+    fn it_returns_a_completed_limited_game() {
+        let input = vec!["\n", "4", "n"];
+        let mock_input = MockInput::new(input);
+        let mut view = View::new(Vec::new());
+        let game = run_variant(&Variant::ThreeMarks(3), mock_input, &mut view, &Dim);
+
+        assert_eq!(&Over, game.get_state());
+        assert_eq!(Some(Limit::Vanish(3)), game.get_board().get_limit());
+    }
+
+    #[test]
+    fn it_returns_a_completed_three_player_game() {
+        let input = vec!["\n", "3", "2", "3", "n"];
+        let mock_input = MockInput::new(input);
+        let mut view = View::new(Vec::new());
+        let game = run_variant(&Variant::ThreePlayer, mock_input, &mut view, &Dim);
+
+        assert_eq!(&Over, game.get_state());
+        assert_eq!(3, game.get_player_count());
+    }
+
+    #[test]
+    fn it_solves_and_shows_an_m_n_k_game() {
+        let mut view = View::new(Vec::new());
+        let solution = solve_with(&Problem::new(3, 3), &mut view).expect("Unsolvable problem");
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert_eq!(9, solution.best_moves.len());
+        assert!(output.contains("the game is a draw"));
+    }
+
+    #[test]
+    fn it_builds_and_shows_an_opening_book() {
+        let mut view = View::new(Vec::new());
+        let self_play = SelfPlay {
+            games: 10,
+            plies: 2,
+            seed: 1,
+        };
+        let book = build_book_with(&Problem::new(4, 3), &self_play, None, &mut view)
+            .expect("Bookless problem");
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(book.fits(&Board::gomoku(4, 3)));
+        assert!(output.contains("Self-play games: 10"));
+    }
+
+    #[test]
+    fn it_steps_through_a_recorded_game_until_the_viewer_quits() {
+        let path = env::temp_dir().join("ttt_lib_test_replay.txt");
+        let line = "2026-10-19 08:15:02\t1500\tstandard\tflat\tfree\t-\tada|bo\tX O\tX\t\
+                    X:1 O:4 X:2 O:5 X:3\n";
+        fs::write(&path, line).expect("Unwritable history");
+        let input = MockInput::new(vec!["2\n", "p\n", "next\n", "a\n", "q\n"]);
+        let mut view = View::new(Vec::new());
+        let delay = Duration::from_millis(1);
+        let replay = replay_with(&path, None, delay, input, &mut view, &Dim).expect("No replay");
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(replay.is_over());
+        assert!(output.contains("Move 2 of 5: O 4"));
+        assert!(output.contains("Move 1 of 5: X 1"));
+        assert!(output.contains(InvalidSelection.to_str()));
+        assert!(output.contains("X wins!!!"));
+        let input = MockInput::new(vec![]);
+        assert!(replay_with(&path, Some(2), delay, input, &mut view, &Dim).is_err());
+
+        fs::remove_file(path).expect("Undeletable history");
+    }
+
+    #[test]
+    fn it_trains_and_shows_a_learner() {
+        let mut view = View::new(Vec::new());
+        let training = Training {
+            opponent: Opponent::Lazy,
+            games: 20,
+            seed: 4,
+        };
+        let learner =
+            train_with(&Problem::new(3, 3), &training, &mut view).expect("Unlearnable problem");
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(learner.fits(&Board::new(3)));
+        assert!(output.contains("Training games against lazy: 20"));
+    }
+
+    #[test]
+    fn it_generates_and_shows_a_tablebase() {
+        let mut view = View::new(Vec::new());
+        let tablebase = generate_with(&Problem::new(3, 3), &mut view).expect("Unsolvable problem");
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(tablebase.fits(&Board::new(3)));
+        assert!(output.contains("Positions in the tablebase: 627"));
+    }
 }
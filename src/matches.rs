@@ -0,0 +1,262 @@
+use board::Board;
+use game::Game;
+use game::GameState::InProgress;
+use player::Player;
+use token::Token::{self, Cross, Empty, Nought};
+
+const BEST_OF: &str = "best-of-";
+const FIRST_TO: &str = "first-to-";
+const WIN_HALVES: usize = 2;
+const DRAW_HALVES: usize = 1;
+
+/// How long a match lasts: at most a number of games, won by whoever takes
+/// more than half of them, or until one side reaches a number of points.
+/// A win scores a point and a draw half a point to each side.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    BestOf(usize),
+    FirstTo(usize),
+}
+
+/// The two sides of a match. The first side starts the first game, and the
+/// start alternates from then on.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Side {
+    First,
+    Second,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GameResult {
+    pub starter: Side,
+    pub winner: Option<Side>,
+}
+
+/// The games of a match so far and the score they add up to.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Match {
+    format: Format,
+    results: Vec<GameResult>,
+}
+
+impl Format {
+    /// Reads `best-of-<games>` or `first-to-<points>`.
+    pub fn parse(spec: &str) -> Result<Format, String> {
+        let count = |prefix: &str| match spec[prefix.len()..].parse() {
+            Ok(count) if count > 0 => Ok(count),
+            _ => Err(format!("Invalid match format {}.", spec)),
+        };
+
+        match spec {
+            _ if spec.starts_with(BEST_OF) => Ok(Format::BestOf(count(BEST_OF)?)),
+            _ if spec.starts_with(FIRST_TO) => Ok(Format::FirstTo(count(FIRST_TO)?)),
+            _ => Err(format!("Unknown match format {}.", spec)),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        match *self {
+            Format::BestOf(games) => format!("best of {}", games),
+            Format::FirstTo(points) => format!("first to {}", points),
+        }
+    }
+}
+
+impl Side {
+    pub fn other(&self) -> Side {
+        match *self {
+            Side::First => Side::Second,
+            Side::Second => Side::First,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        match *self {
+            Side::First => 0,
+            Side::Second => 1,
+        }
+    }
+}
+
+impl Match {
+    pub fn new(format: Format) -> Match {
+        Match {
+            format,
+            results: Vec::new(),
+        }
+    }
+
+    pub fn get_format(&self) -> &Format {
+        &self.format
+    }
+
+    pub fn get_results(&self) -> &[GameResult] {
+        &self.results
+    }
+
+    /// The side that moves first in the next game.
+    pub fn starter(&self) -> Side {
+        match self.results.len() % 2 {
+            0 => Side::First,
+            _ => Side::Second,
+        }
+    }
+
+    /// The token a side holds in the next game; the starter plays X.
+    pub fn token_for(&self, side: Side) -> Token {
+        match side == self.starter() {
+            true => Cross,
+            false => Nought,
+        }
+    }
+
+    /// Scores a finished game, given the seat tokens in side order.
+    pub fn record(&mut self, winner: &Token, tokens: &[Token]) {
+        let winner = match *winner {
+            Empty => None,
+            _ => match tokens.iter().position(|token| token == winner) {
+                Some(0) => Some(Side::First),
+                Some(_) => Some(Side::Second),
+                None => None,
+            },
+        };
+        let starter = self.starter();
+
+        self.results.push(GameResult { starter, winner });
+    }
+
+    /// A side's score in half points.
+    pub fn get_halves(&self, side: Side) -> usize {
+        self.results
+            .iter()
+            .map(|result| match result.winner {
+                Some(winner) if winner == side => WIN_HALVES,
+                Some(_) => 0,
+                None => DRAW_HALVES,
+            })
+            .sum()
+    }
+
+    pub fn is_over(&self) -> bool {
+        let most = self.get_halves(Side::First).max(self.get_halves(Side::Second));
+
+        match self.format {
+            Format::BestOf(games) => most > games || self.results.len() >= games,
+            Format::FirstTo(points) => most >= points * WIN_HALVES,
+        }
+    }
+
+    /// The side ahead once the match is over, or none when it is level.
+    pub fn winner(&self) -> Option<Side> {
+        let first = self.get_halves(Side::First);
+        let second = self.get_halves(Side::Second);
+
+        match self.is_over() {
+            true if first > second => Some(Side::First),
+            true if second > first => Some(Side::Second),
+            _ => None,
+        }
+    }
+}
+
+/// Plays a whole match between two players made afresh for every game with
+/// the token they hold in it.
+pub fn play<F, G>(board: &Board, format: Format, first: F, second: G) -> Match
+where
+    F: Fn(Token) -> Box<dyn Player>,
+    G: Fn(Token) -> Box<dyn Player>,
+{
+    let mut contest = Match::new(format);
+
+    while !contest.is_over() {
+        let player_one = first(contest.token_for(Side::First));
+        let player_two = second(contest.token_for(Side::Second));
+        let mut game = Game::new(board.clone(), player_one, player_two);
+
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+        contest.record(game.get_winner(), &game.get_seat_tokens());
+    }
+
+    contest
+}
+
+/// Half points as a score, as in `2½`.
+pub fn points(halves: usize) -> String {
+    match halves % WIN_HALVES {
+        0 => (halves / WIN_HALVES).to_string(),
+        _ if halves < WIN_HALVES => String::from("½"),
+        _ => format!("{}½", halves / WIN_HALVES),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use player::computer::Computer;
+    use player::strategy::lazy::Lazy;
+    use player::strategy::unbeatable::Unbeatable;
+
+    #[test]
+    fn it_parses_match_formats() {
+        assert_eq!(Ok(Format::BestOf(5)), Format::parse("best-of-5"));
+        assert_eq!(Ok(Format::FirstTo(3)), Format::parse("first-to-3"));
+        assert!(Format::parse("best-of-0").is_err());
+        assert!(Format::parse("first-to-x").is_err());
+        assert!(Format::parse("round-robin").is_err());
+    }
+
+    #[test]
+    fn it_alternates_the_starter() {
+        let mut contest = Match::new(Format::BestOf(3));
+        assert_eq!(Side::First, contest.starter());
+        assert_eq!(Cross, contest.token_for(Side::First));
+
+        contest.record(&Empty, &[Cross, Nought]);
+        assert_eq!(Side::Second, contest.starter());
+        assert_eq!(Cross, contest.token_for(Side::Second));
+        assert_eq!(Nought, contest.token_for(Side::First));
+    }
+
+    #[test]
+    fn it_ends_a_best_of_match_once_it_cannot_be_caught() {
+        let mut contest = Match::new(Format::BestOf(3));
+        contest.record(&Cross, &[Cross, Nought]);
+        assert!(!contest.is_over());
+
+        contest.record(&Nought, &[Nought, Cross]);
+        assert!(contest.is_over());
+        assert_eq!(Some(Side::First), contest.winner());
+        assert_eq!(4, contest.get_halves(Side::First));
+    }
+
+    #[test]
+    fn it_counts_draws_as_half_a_point() {
+        let mut contest = Match::new(Format::FirstTo(1));
+        contest.record(&Empty, &[Cross, Nought]);
+        assert!(!contest.is_over());
+
+        contest.record(&Empty, &[Nought, Cross]);
+        assert!(contest.is_over());
+        assert_eq!(None, contest.winner());
+        assert_eq!("1", points(contest.get_halves(Side::Second)));
+        assert_eq!("½", points(1));
+        assert_eq!("2½", points(5));
+    }
+
+    #[test]
+    fn it_plays_a_match_between_bots() {
+        let board = Board::new(3).update(4, Cross).update(0, Nought);
+        let contest = play(
+            &board,
+            Format::BestOf(2),
+            |token| Box::new(Computer::new(token, Unbeatable::new(token))),
+            |token| Box::new(Computer::new(token, Lazy::new())),
+        );
+
+        assert_eq!(2, contest.get_results().len());
+        assert_eq!(Side::Second, contest.get_results()[1].starter);
+        assert_ne!(Some(Side::Second), contest.winner());
+    }
+}
@@ -0,0 +1,18 @@
+use token::Token;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Move {
+    Place(usize),
+    Slide(usize, usize),
+    Mark(usize, Token),
+    Entangle(usize, usize),
+    Collapse(usize),
+    Choose(Token),
+    Defer,
+    Hint,
+    Resign,
+    OfferDraw,
+    AcceptDraw,
+    DeclineDraw,
+    Pass,
+}
@@ -0,0 +1,107 @@
+use moves::Move::{self, Choose, Defer};
+use token::Token::{Cross, Nought};
+
+const SWAP_STONES: usize = 3;
+const SWAP2_STONES: usize = 5;
+
+/// How the first stones go down before the players settle their colours.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Opening {
+    Free,
+    Swap,
+    Swap2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Seat {
+    First,
+    Second,
+}
+
+/// Who acts next: a seat placing opening stones of either colour, a seat
+/// choosing a colour, or whoever holds the colour to move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    Stones(Seat),
+    Choice(Seat),
+    Play,
+}
+
+impl Seat {
+    pub fn index(&self) -> usize {
+        match *self {
+            Seat::First => 0,
+            Seat::Second => 1,
+        }
+    }
+}
+
+impl Opening {
+    pub fn from_name(name: &str) -> Option<Opening> {
+        match name {
+            "free" => Some(Opening::Free),
+            "swap" => Some(Opening::Swap),
+            "swap2" => Some(Opening::Swap2),
+            _ => None,
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match *self {
+            Opening::Free => "free",
+            Opening::Swap => "swap",
+            Opening::Swap2 => "swap2",
+        }
+    }
+
+    pub fn phase(&self, stones: usize, decisions: &[Move]) -> Phase {
+        match (*self, decisions) {
+            (Opening::Free, _) => Phase::Play,
+            (_, []) if stones < SWAP_STONES => Phase::Stones(Seat::First),
+            (_, []) => Phase::Choice(Seat::Second),
+            (Opening::Swap2, [Defer]) if stones < SWAP2_STONES => Phase::Stones(Seat::Second),
+            (Opening::Swap2, [Defer]) => Phase::Choice(Seat::First),
+            _ => Phase::Play,
+        }
+    }
+
+    pub fn choices(&self, decisions: &[Move]) -> Vec<Move> {
+        let mut choices = vec![Choose(Cross), Choose(Nought)];
+        if *self == Opening::Swap2 && decisions.is_empty() {
+            choices.push(Defer);
+        }
+
+        choices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Phase::*;
+    use super::Seat::*;
+
+    #[test]
+    fn it_finds_opening_by_name() {
+        assert_eq!(Some(Opening::Swap2), Opening::from_name("swap2"));
+        assert_eq!(None, Opening::from_name("pro"));
+    }
+
+    #[test]
+    fn it_hands_the_colour_choice_to_the_second_player_after_three_stones() {
+        assert_eq!(Play, Opening::Free.phase(0, &[]));
+        assert_eq!(Stones(First), Opening::Swap.phase(2, &[]));
+        assert_eq!(Choice(Second), Opening::Swap.phase(3, &[]));
+        assert_eq!(Play, Opening::Swap.phase(3, &[Choose(Nought)]));
+        assert_eq!(2, Opening::Swap.choices(&[]).len());
+    }
+
+    #[test]
+    fn it_passes_the_choice_back_after_two_more_stones_in_swap2() {
+        assert_eq!(3, Opening::Swap2.choices(&[]).len());
+        assert_eq!(Stones(Second), Opening::Swap2.phase(4, &[Defer]));
+        assert_eq!(Choice(First), Opening::Swap2.phase(5, &[Defer]));
+        assert_eq!(Play, Opening::Swap2.phase(5, &[Defer, Choose(Cross)]));
+        assert_eq!(vec![Choose(Cross), Choose(Nought)], Opening::Swap2.choices(&[Defer]));
+    }
+}
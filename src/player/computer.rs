@@ -1,17 +1,26 @@
 use board::Board;
+use moves::Move;
 use player::Player;
 use player::strategy::Strategy;
+use std::time::Duration;
 use token::Token;
 
+const COMPUTER_PREFIX: &str = "computer:";
+
 #[derive(Debug, PartialEq)]
 pub struct Computer<S> {
     token: Token,
     strategy: S,
+    time_left: Option<Duration>,
 }
 
 impl<S: Strategy> Computer<S> {
     pub fn new(token: Token, strategy: S) -> Computer<S> {
-        Computer { token, strategy }
+        Computer {
+            token,
+            strategy,
+            time_left: None,
+        }
     }
 }
 
@@ -20,8 +29,23 @@ impl<S: Strategy> Player for Computer<S> {
         &self.token
     }
 
-    fn get_move(&mut self, board: &Board) -> Result<usize, String> {
-        Ok(self.strategy.decide(board))
+    fn set_token(&mut self, token: Token) {
+        self.token = token;
+    }
+
+    fn get_name(&self) -> String {
+        format!("{}{}", COMPUTER_PREFIX, self.strategy.name())
+    }
+
+    fn get_move(&mut self, board: &Board) -> Result<Move, String> {
+        match self.time_left.take() {
+            Some(time_left) => Ok(self.strategy.decide_within(board, &self.token, time_left)),
+            None => Ok(self.strategy.decide(board, &self.token)),
+        }
+    }
+
+    fn set_time_left(&mut self, time_left: Duration) {
+        self.time_left = Some(time_left);
     }
 }
 
@@ -29,8 +53,10 @@ impl<S: Strategy> Player for Computer<S> {
 mod tests {
     use super::*;
     use board::tests::*;
-    use token::Token::Cross;
+    use moves::Move::Place;
+    use token::Token::{Cross, Nought};
     use player::strategy::lazy::Lazy;
+    use player::strategy::timed::Timed;
 
     #[test]
     fn it_creates_new_player() {
@@ -39,17 +65,30 @@ mod tests {
         assert_eq!(Lazy::new(), player.strategy);
     }
 
+    #[test]
+    fn it_is_named_after_its_strategy() {
+        let player = Computer::new(Cross, Timed::new(Lazy::new(), Duration::from_millis(50)));
+        assert_eq!("computer:lazy@50", player.get_name());
+    }
+
     #[test]
     fn it_gets_player_token() {
         let player = Computer::new(Cross, Lazy::new());
         assert_eq!(&Cross, player.get_token());
     }
 
+    #[test]
+    fn it_takes_the_token_it_is_handed() {
+        let mut player = Computer::new(Cross, Lazy::new());
+        player.set_token(Nought);
+        assert_eq!(&Nought, player.get_token());
+    }
+
     #[test]
     fn it_gets_player_move() {
         let mut player = Computer::new(Cross, Lazy::new());
         let fill_spots = vec![0, 1, 3, 4];
-        let empty_spots = vec![2, 5, 6, 7, 8];
+        let empty_spots = [Place(2), Place(5), Place(6), Place(7), Place(8)];
         let board = create_patterned_board(3, fill_spots);
         let selection = player.get_move(&board).unwrap();
 
@@ -1,20 +1,38 @@
 use board::Board;
+use moves::Move::{self, Choose, Collapse, Defer, Entangle, Hint, Mark, Place, Slide};
 use player::Player;
 use script::Script::InvalidSelection;
-use token::Token;
+use token::Token::{self, Number};
 use ui::input::Input;
 
 const TO_INDEX: usize = 1;
+const SLIDE_SEPARATOR: char = '-';
+const BOARD_SEPARATOR: char = ':';
+const NUMBER_SEPARATOR: char = '=';
+const ENTANGLE_SEPARATOR: char = '+';
+const DEFER: &str = "+";
+const HINT: &str = "hint";
+const RESIGN: &str = "resign";
+const OFFER_DRAW: &str = "draw";
+const ACCEPT_DRAW: &str = "accept";
+const DECLINE_DRAW: &str = "decline";
+const PASS: &str = "pass";
+const GUEST: &str = "guest";
 
 #[derive(Debug, PartialEq)]
 pub struct Human<I> {
     token: Token,
     input: I,
+    name: Option<String>,
 }
 
 impl<I: Input> Human<I> {
     pub fn new(token: Token, input: I) -> Human<I> {
-        Human { token, input }
+        Human {
+            token,
+            input,
+            name: None,
+        }
     }
 }
 
@@ -23,21 +41,146 @@ impl<I: Input> Player for Human<I> {
         &self.token
     }
 
-    fn get_move(&mut self, board: &Board) -> Result<usize, String> {
-        let selection = self.input.read_line();
-        let result: Result<usize, String> = match selection.trim().parse::<usize>() {
-            Ok(num) if num > 0 && num <= board.get_length() => Ok(num - TO_INDEX),
-            Ok(_) | Err(_) => Err(String::from(InvalidSelection.to_str())),
-        };
+    fn set_token(&mut self, token: Token) {
+        self.token = token;
+    }
+
+    /// Humans who were not given a name are all rated as one guest.
+    fn get_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| String::from(GUEST))
+    }
+
+    fn set_name(&mut self, name: &str) {
+        self.name = Some(String::from(name));
+    }
+
+    fn get_move(&mut self, board: &Board) -> Result<Move, String> {
+        parse_move(&self.input.read_line(), board)
+    }
+
+    fn is_human(&self) -> bool {
+        true
+    }
+}
+
+/// Reads a move as a human types it; what a number means depends on the
+/// position it is played on.
+pub fn parse_move(selection: &str, board: &Board) -> Result<Move, String> {
+    let cells: Vec<&str> = selection.trim().split(SLIDE_SEPARATOR).collect();
+
+    match cells.as_slice() {
+        [request] if *request == HINT => Ok(Hint),
+        [request] if *request == RESIGN => Ok(Move::Resign),
+        [request] if *request == OFFER_DRAW => Ok(Move::OfferDraw),
+        [request] if *request == ACCEPT_DRAW => Ok(Move::AcceptDraw),
+        [request] if *request == DECLINE_DRAW => Ok(Move::DeclineDraw),
+        [request] if *request == PASS => Ok(Move::Pass),
+        [choice] if board.must_choose() => parse_choice(choice),
+        [cell] if cell.contains(ENTANGLE_SEPARATOR) => parse_entangle(cell, board),
+        [cell] if board.must_collapse() => Ok(Collapse(parse_cell(cell, board)?)),
+        [cell] if cell.contains(BOARD_SEPARATOR) => Ok(Place(parse_board_cell(cell, board)?)),
+        [cell] if cell.contains(NUMBER_SEPARATOR) => parse_number_mark(cell, board),
+        [cell] if has_symbol(cell) => parse_mark(cell, board),
+        [cell] => Ok(Place(parse_cell(cell, board)?)),
+        [from, to] => Ok(Slide(parse_cell(from, board)?, parse_cell(to, board)?)),
+        _ => Err(String::from(InvalidSelection.to_str())),
+    }
+}
+
+/// Writes a move the way a human would type it on the position it is
+/// played on, so `parse_move` reads it back.
+pub fn notation(choice: &Move) -> String {
+    match *choice {
+        Place(cell) | Collapse(cell) => (cell + TO_INDEX).to_string(),
+        Slide(from, to) => format!("{}{}{}", from + TO_INDEX, SLIDE_SEPARATOR, to + TO_INDEX),
+        Mark(cell, Number(number)) => format!("{}{}{}", cell + TO_INDEX, NUMBER_SEPARATOR, number),
+        Mark(cell, token) => format!("{}{}", cell + TO_INDEX, token.to_str().to_lowercase()),
+        Entangle(first, second) => {
+            format!("{}{}{}", first + TO_INDEX, ENTANGLE_SEPARATOR, second + TO_INDEX)
+        }
+        Choose(token) => token.to_str().to_lowercase(),
+        Defer => String::from(DEFER),
+        Hint => String::from(HINT),
+        Move::Resign => String::from(RESIGN),
+        Move::OfferDraw => String::from(OFFER_DRAW),
+        Move::AcceptDraw => String::from(ACCEPT_DRAW),
+        Move::DeclineDraw => String::from(DECLINE_DRAW),
+        Move::Pass => String::from(PASS),
+    }
+}
+
+fn has_symbol(selection: &str) -> bool {
+    selection.chars().any(char::is_alphabetic)
+}
+
+fn parse_mark(selection: &str, board: &Board) -> Result<Move, String> {
+    let symbol_start = selection.char_indices().last().map_or(0, |(i, _)| i);
+    let (cell, symbol) = selection.split_at(symbol_start);
+
+    match Token::from_symbol(symbol) {
+        Some(token) => Ok(Mark(parse_cell(cell, board)?, token)),
+        None => Err(String::from(InvalidSelection.to_str())),
+    }
+}
+
+fn parse_choice(selection: &str) -> Result<Move, String> {
+    match Token::from_symbol(selection) {
+        Some(token) => Ok(Choose(token)),
+        None if selection == DEFER => Ok(Defer),
+        None => Err(String::from(InvalidSelection.to_str())),
+    }
+}
+
+fn parse_entangle(selection: &str, board: &Board) -> Result<Move, String> {
+    let cells: Vec<&str> = selection.split(ENTANGLE_SEPARATOR).collect();
 
-        result
+    match cells.as_slice() {
+        [first, second] => Ok(Entangle(parse_cell(first, board)?, parse_cell(second, board)?)),
+        _ => Err(String::from(InvalidSelection.to_str())),
+    }
+}
+
+fn parse_number_mark(selection: &str, board: &Board) -> Result<Move, String> {
+    let parts: Vec<&str> = selection.split(NUMBER_SEPARATOR).collect();
+
+    match parts.as_slice() {
+        [cell, number] => {
+            let number = parse_number(number, board.get_board_length())? + TO_INDEX;
+            Ok(Mark(parse_cell(cell, board)?, Number(number)))
+        }
+        _ => Err(String::from(InvalidSelection.to_str())),
+    }
+}
+
+fn parse_board_cell(selection: &str, board: &Board) -> Result<usize, String> {
+    let parts: Vec<&str> = selection.split(BOARD_SEPARATOR).collect();
+    let board_length = board.get_board_length();
+
+    match parts.as_slice() {
+        [number, cell] => {
+            let index = parse_number(number, board.get_board_count())?;
+            Ok(index * board_length + parse_number(cell, board_length)?)
+        }
+        _ => Err(String::from(InvalidSelection.to_str())),
+    }
+}
+
+fn parse_cell(selection: &str, board: &Board) -> Result<usize, String> {
+    parse_number(selection, board.get_length())
+}
+
+fn parse_number(selection: &str, max: usize) -> Result<usize, String> {
+    match selection.trim().parse::<usize>() {
+        Ok(num) if num > 0 && num <= max => Ok(num - TO_INDEX),
+        Ok(_) | Err(_) => Err(String::from(InvalidSelection.to_str())),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use token::Token::Cross;
+    use opening::Opening;
+    use token::Token::{Cross, Nought};
     use ui::input::tests::*;
 
     #[test]
@@ -47,6 +190,15 @@ mod tests {
         assert_eq!(Cross, player.token);
     }
 
+    #[test]
+    fn it_is_a_guest_until_named() {
+        let mut player = Human::new(Cross, MockInput::new(vec![]));
+        assert_eq!("guest", player.get_name());
+
+        player.set_name("ada");
+        assert_eq!("ada", player.get_name());
+    }
+
     #[test]
     fn it_gets_player_token() {
         let mock_input = MockInput::new(vec!["1"]);
@@ -61,7 +213,150 @@ mod tests {
         let board = Board::new(3);
         let selection = player.get_move(&board);
 
-        assert_eq!(Ok(0), selection);
+        assert_eq!(Ok(Place(0)), selection);
+    }
+
+    #[test]
+    fn it_gets_player_slide() {
+        let mock_input = MockInput::new(vec!["7-8"]);
+        let mut player = Human::new(Cross, mock_input);
+        let board = Board::new(3);
+        let selection = player.get_move(&board);
+
+        assert_eq!(Ok(Slide(6, 7)), selection);
+    }
+
+    #[test]
+    fn it_gets_player_mark_with_symbol() {
+        let mock_input = MockInput::new(vec!["5x", "9O"]);
+        let mut player = Human::new(Cross, mock_input);
+        let board = Board::wild(3);
+
+        assert_eq!(Ok(Mark(4, Cross)), player.get_move(&board));
+        assert_eq!(Ok(Mark(8, Nought)), player.get_move(&board));
+    }
+
+    #[test]
+    fn it_gets_spooky_mark_and_collapse_choice() {
+        let mock_input = MockInput::new(vec!["1+5", "1+5+9", "5", "9"]);
+        let mut player = Human::new(Nought, mock_input);
+        let board = Board::quantum(3);
+        let cycle = Board::quantum(3).entangle(0, 8, Cross).entangle(8, 0, Nought);
+        let expected = Err(String::from(InvalidSelection.to_str()));
+
+        assert_eq!(Ok(Entangle(0, 4)), player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(Ok(Place(4)), player.get_move(&board));
+        assert_eq!(Ok(Collapse(8)), player.get_move(&cycle));
+    }
+
+    #[test]
+    fn it_gets_colour_choice_during_opening() {
+        let mock_input = MockInput::new(vec!["o", "+", "5"]);
+        let mut player = Human::new(Nought, mock_input);
+        let board = Board::gomoku(15, 5)
+            .with_opening(Opening::Swap2)
+            .apply(Place(0), Cross)
+            .apply(Place(1), Nought)
+            .apply(Place(2), Cross);
+        let expected = Err(String::from(InvalidSelection.to_str()));
+
+        assert_eq!(Ok(Choose(Nought)), player.get_move(&board));
+        assert_eq!(Ok(Defer), player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+    }
+
+    #[test]
+    fn it_asks_for_a_hint() {
+        let mock_input = MockInput::new(vec!["hint", "hints"]);
+        let mut player = Human::new(Cross, mock_input);
+        let board = Board::new(3);
+
+        assert_eq!(Ok(Hint), player.get_move(&board));
+        assert_eq!(Err(String::from(InvalidSelection.to_str())), player.get_move(&board));
+    }
+
+    #[test]
+    fn it_takes_resign_draw_and_pass_as_commands() {
+        let commands = vec!["resign", "draw", "accept", "decline", "pass"];
+        let mut player = Human::new(Cross, MockInput::new(commands));
+        let board = Board::new(3);
+
+        assert_eq!(Ok(Move::Resign), player.get_move(&board));
+        assert_eq!(Ok(Move::OfferDraw), player.get_move(&board));
+        assert_eq!(Ok(Move::AcceptDraw), player.get_move(&board));
+        assert_eq!(Ok(Move::DeclineDraw), player.get_move(&board));
+        assert_eq!(Ok(Move::Pass), player.get_move(&board));
+    }
+
+    #[test]
+    fn it_gets_player_cell_and_number() {
+        let mock_input = MockInput::new(vec!["5=3", "1=10", "0=2", "5=3=1"]);
+        let mut player = Human::new(Cross, mock_input);
+        let board = Board::numerical(3);
+        let expected = Err(String::from(InvalidSelection.to_str()));
+
+        assert_eq!(Ok(Mark(4, Number(3))), player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+    }
+
+    #[test]
+    fn it_gets_player_move_on_numbered_board() {
+        let mock_input = MockInput::new(vec!["2:5", "1:9", "4:1", "2:10", "2"]);
+        let mut player = Human::new(Nought, mock_input);
+        let board = Board::notakto(3);
+        let expected = Err(String::from(InvalidSelection.to_str()));
+
+        assert_eq!(Ok(Place(13)), player.get_move(&board));
+        assert_eq!(Ok(Place(8)), player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(Ok(Place(1)), player.get_move(&board));
+    }
+
+    #[test]
+    fn it_reads_back_the_notation_it_writes() {
+        let board = Board::new(3);
+        let moves = vec![
+            Place(4),
+            Slide(0, 4),
+            Mark(2, Nought),
+            Mark(3, Number(7)),
+            Entangle(1, 5),
+            Move::Resign,
+            Move::Pass,
+        ];
+
+        for choice in moves {
+            assert_eq!(Ok(choice), parse_move(&notation(&choice), &board));
+        }
+        assert_eq!("3o", notation(&Mark(2, Nought)));
+    }
+
+    #[test]
+    fn it_returns_error_for_unknown_symbol() {
+        let mock_input = MockInput::new(vec!["5z", "x", "10o"]);
+        let mut player = Human::new(Cross, mock_input);
+        let board = Board::wild(3);
+        let expected = Err(String::from(InvalidSelection.to_str()));
+
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+    }
+
+    #[test]
+    fn it_returns_error_for_malformed_slide() {
+        let mock_input = MockInput::new(vec!["7-", "7-10", "1-2-3"]);
+        let mut player = Human::new(Cross, mock_input);
+        let board = Board::new(3);
+        let expected = Err(String::from(InvalidSelection.to_str()));
+
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
+        assert_eq!(expected, player.get_move(&board));
     }
 
     #[test]
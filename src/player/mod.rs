@@ -3,9 +3,25 @@ pub mod human;
 pub mod strategy;
 
 use board::Board;
+use moves::Move;
+use std::time::Duration;
 use token::Token;
 
 pub trait Player {
     fn get_token(&self) -> &Token;
-    fn get_move(&mut self, board: &Board) -> Result<usize, String>;
+    fn set_token(&mut self, token: Token);
+    fn get_move(&mut self, board: &Board) -> Result<Move, String>;
+
+    /// The name the player's games are rated under.
+    fn get_name(&self) -> String;
+
+    /// Gives a human player the name it is rated under.
+    fn set_name(&mut self, _name: &str) {}
+
+    fn is_human(&self) -> bool {
+        false
+    }
+
+    /// The time the player may spend on the move it is about to make.
+    fn set_time_left(&mut self, _time_left: Duration) {}
 }
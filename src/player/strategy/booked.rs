@@ -0,0 +1,92 @@
+use board::Board;
+use book::Book;
+use moves::Move::{self, Place};
+use player::strategy::Strategy;
+use rand::{self, Rng};
+use std::time::Duration;
+use token::Token;
+
+/// Picks a move from an opening book at random in proportion to the weights,
+/// and leaves positions the book does not cover to the inner strategy.
+#[derive(Debug, PartialEq)]
+pub struct Booked<S> {
+    book: Book,
+    inner: S,
+}
+
+impl<S: Strategy> Booked<S> {
+    pub fn new(book: Book, inner: S) -> Booked<S> {
+        Booked { book, inner }
+    }
+
+    fn booked(&self, board: &Board) -> Option<Move> {
+        let moves = self.book.moves(board);
+        let total: u32 = moves.iter().map(|&(_, weight)| weight).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut pick = rand::thread_rng().gen_range(0, total);
+        for (cell, weight) in moves {
+            if pick < weight {
+                return Some(Place(cell));
+            }
+            pick -= weight;
+        }
+
+        None
+    }
+}
+
+impl<S: Strategy> Strategy for Booked<S> {
+    fn name(&self) -> String {
+        format!("{}+book", self.inner.name())
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        self.booked(board)
+            .unwrap_or_else(|| self.inner.decide(board, token))
+    }
+
+    fn decide_within(&self, board: &Board, token: &Token, time_left: Duration) -> Move {
+        self.booked(board)
+            .unwrap_or_else(|| self.inner.decide_within(board, token, time_left))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use player::strategy::unbeatable::Unbeatable;
+    use token::Token::{Cross, Nought};
+
+    fn book() -> Book {
+        Book::parse("3x3 k3 Flat\n.../.../... 5:1\n.../.../..x 1:3 2:0\n").expect("Invalid book")
+    }
+
+    #[test]
+    fn it_plays_the_booked_move() {
+        let strategy = Booked::new(book(), Unbeatable::new(Cross));
+        assert_eq!(Place(4), strategy.decide(&Board::new(3), &Cross));
+    }
+
+    #[test]
+    fn it_only_picks_moves_with_weight() {
+        let strategy = Booked::new(book(), Unbeatable::new(Nought));
+        let board = Board::new(3).update(0, Cross);
+
+        assert_eq!(Place(8), strategy.decide(&board, &Nought));
+    }
+
+    #[test]
+    fn it_falls_back_to_the_inner_strategy_out_of_book() {
+        let strategy = Booked::new(book(), Unbeatable::new(Cross));
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(3, Nought)
+            .update(1, Cross)
+            .update(4, Nought);
+
+        assert_eq!(Place(2), strategy.decide(&board, &Cross));
+    }
+}
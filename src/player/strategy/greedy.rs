@@ -0,0 +1,200 @@
+use board::Board;
+use moves::Move::{self, Choose, Place};
+use player::strategy::Strategy;
+use rules::CAPTURES_TO_WIN;
+use token::Token::{self, Blocked, Cross, Empty, Nought};
+
+const STEP: u64 = 8;
+const WIN_BONUS: u64 = 4;
+const ATTACK: u64 = 2;
+const CAPTURE: u64 = 64;
+
+/// Five in a row on a board too big to search: every window of the winning
+/// length still open to one colour scores its empty spots, so a move builds
+/// up its own windows or cuts into the opponent's. A window is worth eight
+/// times more for every stone in it, and far more once it is complete.
+/// Where pairs can be captured, taking a pair or saving one scores too.
+#[derive(Debug, Default, PartialEq)]
+pub struct Greedy;
+
+impl Greedy {
+    pub fn new() -> Greedy {
+        Greedy {}
+    }
+
+    pub fn evaluate(&self, board: &Board, token: &Token) -> u64 {
+        board
+            .lines()
+            .iter()
+            .map(|line| stones_in(board, line, token))
+            .filter(|&(_, other, blocked)| other == 0 && !blocked)
+            .map(|(own, _, _)| weight(own, board.get_win_length()))
+            .fold(0, u64::saturating_add)
+    }
+}
+
+impl Strategy for Greedy {
+    fn name(&self) -> String {
+        String::from("greedy")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        if board.must_choose() {
+            return match self.evaluate(board, &Cross) > self.evaluate(board, &Nought) {
+                true => Choose(Cross),
+                false => Choose(Nought),
+            };
+        }
+
+        let win_length = board.get_win_length();
+        let mut scores: Vec<u64> = vec![0; board.get_length()];
+        for line in board.lines() {
+            let score = match stones_in(board, &line, token) {
+                (_, _, true) => 0,
+                (own, 0, _) => ATTACK.saturating_mul(weight(own + 1, win_length)),
+                (0, other, _) => weight(other + 1, win_length),
+                _ => 0,
+            };
+
+            for cell in line {
+                scores[cell] = scores[cell].saturating_add(score);
+            }
+        }
+
+        let opponent = match *token {
+            Cross => Nought,
+            _ => Cross,
+        };
+        let mut best = None;
+        for choice in board.legal_moves(token) {
+            let score = match choice {
+                Place(cell) => scores[cell]
+                    .saturating_add(ATTACK.saturating_mul(capture_value(board, cell, token)))
+                    .saturating_add(capture_value(board, cell, &opponent)),
+                _ => 0,
+            };
+            let improves = match best {
+                Some((value, _)) => score > value,
+                None => true,
+            };
+            if improves {
+                best = Some((score, choice));
+            }
+        }
+
+        best.expect("No legal moves").1
+    }
+}
+
+fn capture_value(board: &Board, cell: usize, token: &Token) -> u64 {
+    let pairs = board.captures_at(cell, token).len() / 2;
+
+    match pairs {
+        0 => 0,
+        _ if board.captured_pairs(token) + pairs >= CAPTURES_TO_WIN => {
+            weight(board.get_win_length(), board.get_win_length())
+        }
+        _ => CAPTURE * pairs as u64,
+    }
+}
+
+fn weight(stones: usize, win_length: usize) -> u64 {
+    match stones {
+        0 => 0,
+        _ if stones >= win_length => {
+            WIN_BONUS.saturating_mul(STEP.saturating_pow(win_length as u32 + 1))
+        }
+        _ => STEP.saturating_pow(stones as u32 - 1),
+    }
+}
+
+fn stones_in(board: &Board, line: &[usize], token: &Token) -> (usize, usize, bool) {
+    let cells: Vec<&Token> = line.iter().map(|cell| &board.get_cells()[*cell]).collect();
+    let own = cells.iter().filter(|cell| **cell == token).count();
+    let other = cells
+        .iter()
+        .filter(|cell| ***cell != Empty && ***cell != Blocked && **cell != token)
+        .count();
+    let blocked = cells.contains(&&Blocked);
+
+    (own, other, blocked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::Game;
+    use game::GameState::InProgress;
+    use opening::Opening;
+    use player::computer::Computer;
+    use player::strategy::lazy::Lazy;
+
+    fn play(board: Board, moves: Vec<(usize, Token)>) -> Board {
+        moves
+            .into_iter()
+            .fold(board, |board, (cell, token)| board.apply(Place(cell), token))
+    }
+
+    #[test]
+    fn it_completes_five_in_a_row() {
+        let board = play(
+            Board::gomoku(15, 5),
+            vec![(0, Cross), (30, Nought), (1, Cross), (31, Nought), (2, Cross),
+                 (32, Nought), (3, Cross), (33, Nought)],
+        );
+        assert_eq!(Place(4), Greedy::new().decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_blocks_an_open_four() {
+        let board = play(
+            Board::gomoku(15, 5),
+            vec![(112, Cross), (0, Nought), (113, Cross), (16, Nought), (114, Cross),
+                 (32, Nought), (115, Cross)],
+        );
+        let selection = Greedy::new().decide(&board, &Nought);
+        assert!(selection == Place(111) || selection == Place(116));
+    }
+
+    #[test]
+    fn it_chooses_the_colour_with_the_stronger_stones() {
+        let board = Board::gomoku(15, 5)
+            .with_opening(Opening::Swap)
+            .apply(Place(112), Cross)
+            .apply(Place(0), Nought)
+            .apply(Place(113), Cross);
+        assert_eq!(Choose(Cross), Greedy::new().decide(&board, &Nought));
+    }
+
+    #[test]
+    fn it_takes_a_pair_when_it_can() {
+        let board = play(
+            Board::pente(19, 5),
+            vec![(180, Cross), (181, Nought), (100, Cross), (182, Nought)],
+        );
+        assert_eq!(Place(183), Greedy::new().decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_weighs_windows_by_the_winning_length() {
+        let weights: Vec<u64> = (0..6).map(|stones| weight(stones, 5)).collect();
+        assert_eq!(vec![0, 1, 8, 64, 512, 1 << 20], weights);
+        assert_eq!(1 << 23, weight(6, 6));
+        assert_eq!(u64::MAX, weight(30, 30));
+    }
+
+    #[test]
+    fn it_beats_random_moves() {
+        let mut game = Game::new(
+            Board::gomoku(15, 5),
+            Box::new(Computer::new(Cross, Lazy::new())),
+            Box::new(Computer::new(Nought, Greedy::new())),
+        );
+
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+
+        assert_eq!(&Nought, game.get_winner());
+    }
+}
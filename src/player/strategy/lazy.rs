@@ -2,7 +2,9 @@ extern crate rand;
 
 use rand::Rng;
 use board::Board;
+use moves::Move;
 use player::strategy::Strategy;
+use token::Token;
 
 #[derive(Debug, PartialEq)]
 pub struct Lazy;
@@ -14,11 +16,15 @@ impl Lazy {
 }
 
 impl Strategy for Lazy {
-    fn decide(&self, board: &Board) -> usize {
-        let empty_cells = board.empty_cells();
-        let random_number = rand::thread_rng().gen_range(0, empty_cells.len());
+    fn name(&self) -> String {
+        String::from("lazy")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        let legal_moves = board.legal_moves(token);
+        let random_number = rand::thread_rng().gen_range(0, legal_moves.len());
 
-        empty_cells[random_number]
+        legal_moves[random_number]
     }
 }
 
@@ -26,15 +32,26 @@ impl Strategy for Lazy {
 mod tests {
     use super::*;
     use board::tests::*;
+    use moves::Move::Place;
+    use token::Token::Cross;
 
     #[test]
     fn it_picks_random_empty_cell() {
         let strategy = Lazy::new();
         let fill_spots = vec![0, 1, 3, 4];
-        let empty_spots = vec![2, 5, 6, 7, 8];
+        let empty_spots = [Place(2), Place(5), Place(6), Place(7), Place(8)];
         let board = create_patterned_board(3, fill_spots);
-        let selection = strategy.decide(&board);
+        let selection = strategy.decide(&board, &Cross);
 
         assert!(empty_spots.contains(&selection));
     }
+
+    #[test]
+    fn it_picks_random_slide_once_pieces_are_placed() {
+        let strategy = Lazy::new();
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        let selection = strategy.decide(&board, &Cross);
+
+        assert!(board.legal_moves(&Cross).contains(&selection));
+    }
 }
@@ -0,0 +1,171 @@
+use board::Board;
+use moves::Move;
+use player::strategy::Strategy;
+use rules;
+use token::PLAYER_TOKENS;
+use token::Token::{self, Blocked, Empty};
+
+const DEPTH: usize = 3;
+const GROWTH: i64 = 4;
+const WIN: i64 = 1 << 40;
+
+/// Depth-limited max^n search for any number of players. A position scores
+/// one value per player and whoever is to move picks the move that is best
+/// for their own value, so no player assumes the others gang up on them.
+#[derive(Debug, PartialEq)]
+pub struct MaxN {
+    depth: usize,
+}
+
+impl MaxN {
+    pub fn new() -> MaxN {
+        MaxN { depth: DEPTH }
+    }
+
+    fn search(&self, board: &Board, depth: usize) -> Vec<i64> {
+        if depth == 0 || rules::is_game_over(board) {
+            return evaluate(board, depth);
+        }
+
+        let token = board.token_to_move();
+        let mover = seat_of(&token);
+
+        board
+            .legal_moves(&token)
+            .into_iter()
+            .map(|choice| self.search(&board.clone().apply(choice, token), depth - 1))
+            .max_by_key(|scores| scores[mover])
+            .unwrap_or_else(|| vec![0; board.get_player_count()])
+    }
+}
+
+impl Default for MaxN {
+    fn default() -> MaxN {
+        MaxN::new()
+    }
+}
+
+impl Strategy for MaxN {
+    fn name(&self) -> String {
+        String::from("maxn")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        let mover = seat_of(token);
+
+        board
+            .legal_moves(token)
+            .into_iter()
+            .map(|choice| {
+                let scores = self.search(&board.clone().apply(choice, *token), self.depth - 1);
+                (scores[mover], choice)
+            })
+            .max_by_key(|&(score, _)| score)
+            .expect("No legal moves")
+            .1
+    }
+}
+
+// Wins found sooner, with more depth left, count for more.
+fn evaluate(board: &Board, depth: usize) -> Vec<i64> {
+    let tokens = &PLAYER_TOKENS[..board.get_player_count()];
+    let winner = rules::get_winner(board);
+
+    if *winner != Empty {
+        let reward = WIN * (depth as i64 + 1);
+        return tokens
+            .iter()
+            .map(|token| match token == winner {
+                true => reward,
+                false => -reward,
+            })
+            .collect();
+    }
+
+    let partition = board.partition();
+    let potentials: Vec<i64> = tokens
+        .iter()
+        .map(|token| partition.iter().map(|line| line_potential(line, token)).sum())
+        .collect();
+    let total: i64 = potentials.iter().sum();
+    let others = (tokens.len() - 1) as i64;
+
+    potentials
+        .iter()
+        .map(|potential| potential * others - (total - potential))
+        .collect()
+}
+
+fn line_potential(line: &[Token], token: &Token) -> i64 {
+    let open = line.iter().all(|cell| cell == token || *cell == Empty);
+    let marks = line.iter().filter(|cell| *cell == token).count();
+
+    match open && !line.contains(&Blocked) {
+        true => GROWTH.pow(marks as u32),
+        false => 0,
+    }
+}
+
+fn seat_of(token: &Token) -> usize {
+    PLAYER_TOKENS
+        .iter()
+        .position(|player| player == token)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::Game;
+    use game::GameState::InProgress;
+    use moves::Move::Place;
+    use player::Player;
+    use player::computer::Computer;
+    use token::Token::{Cross, Nought, Triangle};
+
+    fn three_player_board() -> Board {
+        Board::gomoku(4, 3).with_players(3)
+    }
+
+    #[test]
+    fn it_completes_a_line() {
+        let board = three_player_board()
+            .update(0, Cross)
+            .update(8, Nought)
+            .update(15, Triangle)
+            .update(1, Cross)
+            .update(10, Nought)
+            .update(13, Triangle);
+
+        assert_eq!(Place(2), MaxN::new().decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_blocks_the_next_player() {
+        let board = three_player_board()
+            .update(0, Cross)
+            .update(4, Nought)
+            .update(15, Triangle)
+            .update(10, Cross)
+            .update(5, Nought)
+            .update(3, Triangle);
+
+        assert_eq!(Place(6), MaxN::new().decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_plays_a_three_player_game_to_the_end() {
+        let players: Vec<Box<dyn Player>> = vec![
+            Box::new(Computer::new(Cross, MaxN::new())),
+            Box::new(Computer::new(Nought, MaxN::new())),
+            Box::new(Computer::new(Triangle, MaxN::new())),
+        ];
+        let mut game = Game::with_players(three_player_board(), players);
+
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+
+        assert!(rules::is_game_over(game.get_board()));
+    }
+}
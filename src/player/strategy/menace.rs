@@ -0,0 +1,251 @@
+use board::Board;
+use book::Book;
+use moves::Move::{self, Place};
+use player::strategy::Strategy;
+use player::strategy::lazy::Lazy;
+use player::strategy::unbeatable::Unbeatable;
+use rand::{Rng, SeedableRng, StdRng};
+use rules;
+use std::path::PathBuf;
+use token::Token::{self, Cross, Empty, Nought};
+
+const OPENING_BEADS: u32 = 4;
+const WIN_BEADS: u32 = 3;
+const DRAW_BEADS: u32 = 1;
+const LOSS_BEADS: u32 = 1;
+
+/// Who the learner plays while training.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Opponent {
+    Itself,
+    Lazy,
+    Unbeatable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Training {
+    pub opponent: Opponent,
+    pub games: usize,
+    pub seed: usize,
+}
+
+/// Games won, drawn and lost by the learner, counted for X when it plays
+/// itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Record {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+/// Michie's matchbox learner: a box of beads per position, one colour per
+/// open spot. Training draws a bead at random for each move, then adds beads
+/// for every move of a won game, one for a draw and takes one away for a
+/// loss. A box left empty is filled again. Playing takes the spot with the
+/// most beads.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Menace {
+    boxes: Book,
+}
+
+impl Opponent {
+    pub fn from_name(name: &str) -> Option<Opponent> {
+        match name {
+            "self" => Some(Opponent::Itself),
+            "lazy" => Some(Opponent::Lazy),
+            "unbeatable" => Some(Opponent::Unbeatable),
+            _ => None,
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match *self {
+            Opponent::Itself => "itself",
+            Opponent::Lazy => "lazy",
+            Opponent::Unbeatable => "unbeatable",
+        }
+    }
+}
+
+impl Menace {
+    pub fn new(board: &Board) -> Result<Menace, String> {
+        Ok(Menace {
+            boxes: Book::new(board)?,
+        })
+    }
+
+    pub fn load(path: &PathBuf) -> Result<Menace, String> {
+        Ok(Menace {
+            boxes: Book::load(path)?,
+        })
+    }
+
+    pub fn save(&self, path: &PathBuf) -> Result<(), String> {
+        self.boxes.save(path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.boxes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    pub fn get_signature(&self) -> &str {
+        self.boxes.get_signature()
+    }
+
+    pub fn fits(&self, board: &Board) -> bool {
+        self.boxes.fits(board)
+    }
+
+    pub fn train(&mut self, board: &Board, training: &Training) -> Record {
+        let mut rng: StdRng = SeedableRng::from_seed(&[training.seed][..]);
+        let mut record = Record::default();
+
+        for game in 0..training.games {
+            let learner = match game % 2 == 0 || training.opponent == Opponent::Itself {
+                true => Cross,
+                false => Nought,
+            };
+            let mut position = board.clone();
+            let mut history = Vec::new();
+
+            while !rules::is_game_over(&position) {
+                let token = position.token_to_move();
+                let cell = match training.opponent {
+                    _ if token == learner || training.opponent == Opponent::Itself => {
+                        let cell = self.draw(&position, &mut rng);
+                        history.push((position.clone(), cell, token));
+                        cell
+                    }
+                    Opponent::Unbeatable => match Unbeatable::new(token).decide(&position, &token) {
+                        Place(cell) => cell,
+                        _ => self.draw(&position, &mut rng),
+                    },
+                    _ => {
+                        let open = position.empty_cells();
+                        open[rng.gen_range(0, open.len())]
+                    }
+                };
+                position = position.apply(Place(cell), token);
+            }
+
+            let winner = *rules::get_winner(&position);
+            for (before, cell, token) in history {
+                self.reinforce(&before, cell, winner, token);
+            }
+
+            match winner {
+                Empty => record.draws += 1,
+                _ if winner == learner => record.wins += 1,
+                _ => record.losses += 1,
+            }
+        }
+
+        record
+    }
+
+    fn draw(&mut self, board: &Board, rng: &mut StdRng) -> usize {
+        let beads = OPENING_BEADS.saturating_sub(board.moves_played() as u32 / 2).max(1);
+        self.boxes.stock(board, beads);
+
+        let moves = self.boxes.moves(board);
+        let total: u32 = moves.iter().map(|&(_, weight)| weight).sum();
+        let mut pick = rng.gen_range(0, total);
+        for (cell, weight) in moves {
+            if pick < weight {
+                return cell;
+            }
+            pick -= weight;
+        }
+
+        board.empty_cells()[0]
+    }
+
+    fn reinforce(&mut self, board: &Board, cell: usize, winner: Token, token: Token) {
+        match winner {
+            Empty => self.boxes.record(board, cell, DRAW_BEADS),
+            _ if winner == token => self.boxes.record(board, cell, WIN_BEADS),
+            _ => self.boxes.remove(board, cell, LOSS_BEADS),
+        }
+    }
+}
+
+impl Strategy for Menace {
+    fn name(&self) -> String {
+        String::from("learned")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        match self.boxes.moves(board).iter().max_by_key(|&&(_, weight)| weight) {
+            Some(&(cell, _)) => Place(cell),
+            None => Lazy::new().decide(board, token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn training(opponent: Opponent, games: usize) -> Training {
+        Training {
+            opponent,
+            games,
+            seed: 11,
+        }
+    }
+
+    #[test]
+    fn it_finds_opponent_by_name() {
+        assert_eq!(Some(Opponent::Itself), Opponent::from_name("self"));
+        assert_eq!(Some(Opponent::Unbeatable), Opponent::from_name("unbeatable"));
+        assert_eq!(None, Opponent::from_name("greedy"));
+    }
+
+    #[test]
+    fn it_counts_every_training_game() {
+        let mut menace = Menace::new(&Board::new(3)).expect("Unlearnable board");
+        let record = menace.train(&Board::new(3), &training(Opponent::Lazy, 50));
+
+        assert_eq!(50, record.wins + record.draws + record.losses);
+        assert!(!menace.is_empty());
+    }
+
+    #[test]
+    fn it_trains_the_same_way_from_the_same_seed() {
+        let mut first = Menace::new(&Board::new(3)).expect("Unlearnable board");
+        let mut second = Menace::new(&Board::new(3)).expect("Unlearnable board");
+
+        assert_eq!(
+            first.train(&Board::new(3), &training(Opponent::Itself, 30)),
+            second.train(&Board::new(3), &training(Opponent::Itself, 30))
+        );
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn it_learns_to_take_a_winning_spot() {
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(3, Nought)
+            .update(1, Cross)
+            .update(4, Nought);
+        let mut menace = Menace::new(&board).expect("Unlearnable board");
+        menace.train(&board, &training(Opponent::Lazy, 200));
+
+        assert_eq!(Place(2), menace.decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_never_beats_unbeatable() {
+        let board = Board::new(3).update(4, Cross).update(0, Nought);
+        let mut menace = Menace::new(&board).expect("Unlearnable board");
+        let record = menace.train(&board, &training(Opponent::Unbeatable, 20));
+
+        assert_eq!(0, record.wins);
+        assert_eq!(20, record.draws + record.losses);
+    }
+}
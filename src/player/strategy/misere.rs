@@ -0,0 +1,250 @@
+use board::Board;
+use moves::Move;
+use player::strategy::Strategy;
+use token::Token::{self, Cross};
+
+pub const SYMMETRIES: [[usize; 9]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8],
+    [6, 3, 0, 7, 4, 1, 8, 5, 2],
+    [8, 7, 6, 5, 4, 3, 2, 1, 0],
+    [2, 5, 8, 1, 4, 7, 0, 3, 6],
+    [2, 1, 0, 5, 4, 3, 8, 7, 6],
+    [6, 7, 8, 3, 4, 5, 0, 1, 2],
+    [0, 3, 6, 1, 4, 7, 2, 5, 8],
+    [8, 5, 2, 7, 4, 1, 6, 3, 0],
+];
+const LOSING: [&str; 4] = ["a", "b2", "bc", "c2"];
+const VALUES: [(u16, &str); 46] = [
+    (0, "c"), (1, "1"), (2, "1"), (3, "ad"), (5, "b"), (10, "a"), (11, "b"), (12, "b"),
+    (13, "a"), (14, "d"), (16, "c2"), (17, "b"), (18, "b"), (19, "ab"), (21, "a"), (26, "ab"),
+    (27, "a"), (28, "a"), (29, "b"), (30, "b"), (40, "a"), (41, "d"), (42, "b"), (43, "a"),
+    (45, "b"), (68, "a"), (69, "ab"), (70, "d"), (78, "ab"), (97, "a"), (98, "1"), (99, "b"),
+    (101, "b"), (102, "a"), (106, "ab"), (108, "a"), (110, "b"), (113, "b"), (114, "b"),
+    (115, "a"), (170, "a"), (171, "b"), (173, "a"), (229, "a"), (238, "a"), (325, "a"),
+];
+
+/// An element of the Notakto misère quotient
+/// Q = <a, b, c, d | a² = 1, b³ = b, b²c = c, c³ = ac², b²d = d, cd = ad, d² = c²>,
+/// stored as the exponents of its normal form a^i b^j c^k d^l.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Quotient {
+    exponents: [u8; 4],
+}
+
+impl Quotient {
+    pub fn identity() -> Quotient {
+        Quotient { exponents: [0; 4] }
+    }
+
+    pub fn from_word(word: &str) -> Quotient {
+        let mut exponents = [0; 4];
+        let mut letters = word.chars().peekable();
+
+        while let Some(letter) = letters.next() {
+            let power = match letters.peek().and_then(|next| next.to_digit(10)) {
+                Some(digit) => {
+                    letters.next();
+                    digit as u8
+                }
+                None => 1,
+            };
+
+            if let Some(generator) = "abcd".find(letter) {
+                exponents[generator] += power;
+            }
+        }
+
+        Quotient { exponents }.normalize()
+    }
+
+    pub fn times(&self, other: &Quotient) -> Quotient {
+        let mut exponents = self.exponents;
+        for (exponent, power) in exponents.iter_mut().zip(other.exponents.iter()) {
+            *exponent += power;
+        }
+
+        Quotient { exponents }.normalize()
+    }
+
+    pub fn is_losing(&self) -> bool {
+        LOSING.iter().any(|word| Quotient::from_word(word) == *self)
+    }
+
+    fn normalize(self) -> Quotient {
+        let [mut a, mut b, mut c, mut d] = self.exponents;
+
+        loop {
+            let previous = [a, b, c, d];
+            while b >= 3 {
+                b -= 2;
+            }
+            if b == 2 && (c >= 1 || d >= 1) {
+                b = 0;
+            }
+            if c >= 3 {
+                c -= 1;
+                a += 1;
+            }
+            if c >= 1 && d >= 1 {
+                c -= 1;
+                a += 1;
+            }
+            if d >= 2 {
+                d -= 2;
+                c += 2;
+            }
+            a %= 2;
+
+            if previous == [a, b, c, d] {
+                return Quotient { exponents: [a, b, c, d] };
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Misere;
+
+impl Misere {
+    pub fn new() -> Misere {
+        Misere {}
+    }
+
+    pub fn evaluate(&self, board: &Board) -> Quotient {
+        let boards = board.boards();
+
+        board
+            .live_boards()
+            .iter()
+            .map(|index| board_value(&boards[*index]))
+            .fold(Quotient::identity(), |product, value| product.times(&value))
+    }
+}
+
+impl Strategy for Misere {
+    fn name(&self) -> String {
+        String::from("misere")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        let options: Vec<(Board, Move)> = board
+            .legal_moves(token)
+            .into_iter()
+            .map(|choice| (board.clone().apply(choice, *token), choice))
+            .collect();
+
+        let winning = options
+            .iter()
+            .find(|&(next, _)| self.evaluate(next).is_losing());
+
+        match winning {
+            Some(&(_, choice)) => choice,
+            None => {
+                options
+                    .iter()
+                    .max_by_key(|&(next, _)| next.live_boards().len())
+                    .expect("No legal moves")
+                    .1
+            }
+        }
+    }
+}
+
+fn board_value(board: &Board) -> Quotient {
+    let mask = canonical_mask(board);
+    let word = VALUES
+        .iter()
+        .find(|&&(position, _)| position == mask)
+        .map(|&(_, word)| word)
+        .expect("Unknown Notakto position");
+
+    Quotient::from_word(word)
+}
+
+fn canonical_mask(board: &Board) -> u16 {
+    let cells = board.get_cells();
+
+    SYMMETRIES
+        .iter()
+        .map(|symmetry| {
+            symmetry
+                .iter()
+                .enumerate()
+                .filter(|&(_, source)| cells[*source] == Cross)
+                .fold(0, |mask, (i, _)| mask | 1 << i)
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::Game;
+    use game::GameState::InProgress;
+    use moves::Move::Place;
+    use player::computer::Computer;
+    use player::strategy::lazy::Lazy;
+    use token::Token::Nought;
+
+    #[test]
+    fn it_reduces_words_to_normal_form() {
+        let c = Quotient::from_word("c");
+        assert_eq!(Quotient::from_word("ac2"), c.times(&c).times(&c));
+        assert_eq!(Quotient::from_word("ad"), c.times(&Quotient::from_word("d")));
+        assert_eq!(Quotient::identity(), Quotient::from_word("a2"));
+        assert_eq!(c, Quotient::from_word("b2c"));
+    }
+
+    #[test]
+    fn it_values_boards_up_to_symmetry() {
+        let corner = Board::notakto(1).update(0, Cross);
+        let other_corner = Board::notakto(1).update(8, Cross);
+        assert_eq!(Quotient::from_word("c"), board_value(&Board::notakto(1)));
+        assert_eq!(board_value(&corner), board_value(&other_corner));
+        assert!(board_value(&Board::notakto(1).update(4, Cross)).is_losing());
+    }
+
+    #[test]
+    fn it_treats_two_empty_boards_as_lost_for_the_mover() {
+        let strategy = Misere::new();
+        assert!(strategy.evaluate(&Board::notakto(2)).is_losing());
+        assert!(!strategy.evaluate(&Board::notakto(3)).is_losing());
+    }
+
+    #[test]
+    fn it_takes_the_centre_of_a_single_board() {
+        let strategy = Misere::new();
+        assert_eq!(Place(4), strategy.decide(&Board::notakto(1), &Cross));
+    }
+
+    #[test]
+    fn it_avoids_killing_the_last_board() {
+        let board = Board::notakto(2)
+            .update(0, Cross)
+            .update(1, Cross)
+            .update(2, Cross)
+            .update(9, Cross)
+            .update(10, Cross);
+        let selection = Misere::new().decide(&board, &Nought);
+
+        assert_eq!(1, board.clone().apply(selection, Nought).live_boards().len());
+    }
+
+    #[test]
+    fn it_wins_three_boards_moving_first() {
+        for _ in 0..10 {
+            let mut game = Game::new(
+                Board::notakto(3),
+                Box::new(Computer::new(Cross, Misere::new())),
+                Box::new(Computer::new(Nought, Lazy::new())),
+            );
+
+            while game.get_state() == &InProgress {
+                game.next_turn();
+            }
+
+            assert_eq!(&Cross, game.get_winner());
+        }
+    }
+}
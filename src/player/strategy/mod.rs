@@ -1,8 +1,29 @@
+pub mod booked;
+pub mod greedy;
 pub mod lazy;
+pub mod maxn;
+pub mod menace;
+pub mod misere;
+pub mod numerical;
+pub mod perfect;
+pub mod potential;
+pub mod timed;
 pub mod unbeatable;
 
 use board::Board;
+use moves::Move;
+use std::time::Duration;
+use token::Token;
 
 pub trait Strategy {
-    fn decide(&self, board: &Board) -> usize;
+    /// The configuration a computer playing the strategy is rated under.
+    fn name(&self) -> String;
+
+    fn decide(&self, board: &Board, token: &Token) -> Move;
+
+    /// Decides within the time left on the clock; strategies that cannot
+    /// trade strength for time just decide.
+    fn decide_within(&self, board: &Board, token: &Token, _time_left: Duration) -> Move {
+        self.decide(board, token)
+    }
 }
@@ -0,0 +1,211 @@
+use board::Board;
+use moves::Move::{self, Mark};
+use player::strategy::Strategy;
+use player::strategy::misere::SYMMETRIES;
+use std::collections::HashMap;
+use token::Token::{self, Blocked, Number};
+
+const WIN: i8 = 1;
+const DRAW: i8 = 0;
+const LOSS: i8 = -1;
+const BITS: usize = 4;
+const OPEN: usize = 0;
+const BLOCKED: usize = 15;
+
+/// Perfect play for numerical tic tac toe on a 3x3 board. Positions are packed
+/// four bits per cell, reduced by symmetry and solved once per decision with a
+/// win/draw/loss table.
+#[derive(Debug, Default, PartialEq)]
+pub struct Numerical;
+
+struct Solver {
+    lines: Vec<Vec<usize>>,
+    target: usize,
+    length: usize,
+    table: HashMap<u64, i8>,
+}
+
+impl Numerical {
+    pub fn new() -> Numerical {
+        Numerical {}
+    }
+}
+
+impl Strategy for Numerical {
+    fn name(&self) -> String {
+        String::from("numerical")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        let mut solver = Solver::new(board);
+        let mut cells = pack(board);
+        let mut best = None;
+
+        for choice in board.legal_moves(token) {
+            let outcome = match choice {
+                Mark(cell, Number(number)) => {
+                    cells[cell] = number;
+                    let outcome = -solver.solve(&mut cells, cell);
+                    cells[cell] = OPEN;
+                    outcome
+                }
+                _ => LOSS,
+            };
+
+            let improves = match best {
+                Some((value, _)) => outcome > value,
+                None => true,
+            };
+            if improves {
+                best = Some((outcome, choice));
+            }
+            if outcome == WIN {
+                break;
+            }
+        }
+
+        best.expect("No legal moves").1
+    }
+}
+
+impl Solver {
+    fn new(board: &Board) -> Solver {
+        Solver {
+            lines: board.lines(),
+            target: board.get_target(),
+            length: board.get_length(),
+            table: HashMap::new(),
+        }
+    }
+
+    // Scores the position for the player about to move, given the last cell played.
+    fn solve(&mut self, cells: &mut Vec<usize>, last: usize) -> i8 {
+        if self.completes_line(cells, last) {
+            return LOSS;
+        }
+
+        let key = canonical_key(cells);
+        if let Some(value) = self.table.get(&key) {
+            return *value;
+        }
+
+        let placed = cells.iter().filter(|cell| is_number(**cell)).count();
+        let parity = (placed + 1) % 2;
+        let numbers: Vec<usize> = (1..self.length + 1)
+            .filter(|number| number % 2 == parity && !cells.contains(number))
+            .collect();
+        let mut best = match cells.contains(&OPEN) && !numbers.is_empty() {
+            true => LOSS,
+            false => DRAW,
+        };
+
+        'search: for cell in 0..self.length {
+            if cells[cell] != OPEN {
+                continue;
+            }
+
+            for number in numbers.iter() {
+                cells[cell] = *number;
+                let value = -self.solve(cells, cell);
+                cells[cell] = OPEN;
+
+                if value > best {
+                    best = value;
+                }
+                if best == WIN {
+                    break 'search;
+                }
+            }
+        }
+
+        self.table.insert(key, best);
+        best
+    }
+
+    fn completes_line(&self, cells: &[usize], last: usize) -> bool {
+        self.lines
+            .iter()
+            .filter(|line| line.contains(&last))
+            .any(|line| {
+                line.iter().all(|cell| is_number(cells[*cell]))
+                    && line.iter().map(|cell| cells[*cell]).sum::<usize>() == self.target
+            })
+    }
+}
+
+fn pack(board: &Board) -> Vec<usize> {
+    board
+        .get_cells()
+        .iter()
+        .map(|cell| match *cell {
+            Blocked => BLOCKED,
+            _ => cell.get_number().unwrap_or(OPEN),
+        })
+        .collect()
+}
+
+fn is_number(cell: usize) -> bool {
+    cell != OPEN && cell != BLOCKED
+}
+
+fn canonical_key(cells: &[usize]) -> u64 {
+    SYMMETRIES
+        .iter()
+        .map(|symmetry| {
+            symmetry
+                .iter()
+                .fold(0, |key, source| key << BITS | cells[*source] as u64)
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::Game;
+    use game::GameState::InProgress;
+    use player::computer::Computer;
+    use player::strategy::lazy::Lazy;
+    use token::Token::{Cross, Nought};
+
+    #[test]
+    fn it_completes_a_line_summing_to_fifteen() {
+        let board = Board::numerical(3)
+            .apply(Mark(0, Number(9)), Cross)
+            .apply(Mark(4, Number(2)), Nought)
+            .apply(Mark(1, Number(1)), Cross)
+            .apply(Mark(8, Number(6)), Nought);
+
+        assert_eq!(Mark(2, Number(5)), Numerical::new().decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_plays_around_blocked_cells() {
+        let board = Board::numerical(3)
+            .with_preset(2, Blocked)
+            .apply(Mark(0, Number(9)), Cross)
+            .apply(Mark(4, Number(2)), Nought)
+            .apply(Mark(1, Number(1)), Cross)
+            .apply(Mark(8, Number(6)), Nought);
+        let selection = Numerical::new().decide(&board, &Cross);
+
+        assert!(board.legal_moves(&Cross).contains(&selection));
+        assert_ne!(Mark(2, Number(5)), selection);
+    }
+
+    #[test]
+    fn it_never_loses_moving_second() {
+        let mut game = Game::new(
+            Board::numerical(3),
+            Box::new(Computer::new(Cross, Lazy::new())),
+            Box::new(Computer::new(Nought, Numerical::new())),
+        );
+
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+
+        assert_ne!(&Cross, game.get_winner());
+    }
+}
@@ -0,0 +1,84 @@
+use board::Board;
+use moves::Move::{self, Place};
+use player::strategy::Strategy;
+use player::strategy::unbeatable::Unbeatable;
+use tablebase::Tablebase;
+use token::Token;
+
+/// Plays the first best move a tablebase holds for the position, and only
+/// searches for positions the tablebase does not cover.
+#[derive(Debug, PartialEq)]
+pub struct Perfect {
+    tablebase: Tablebase,
+}
+
+impl Perfect {
+    pub fn new(tablebase: Tablebase) -> Perfect {
+        Perfect { tablebase }
+    }
+}
+
+impl Strategy for Perfect {
+    fn name(&self) -> String {
+        String::from("tablebase")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        match self.tablebase.lookup(board) {
+            Some((_, ref moves)) if !moves.is_empty() => Place(moves[0]),
+            _ => Unbeatable::new(*token).decide(board, token),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::Game;
+    use game::GameState::InProgress;
+    use player::computer::Computer;
+    use token::Token::{Cross, Empty, Nought};
+
+    fn tablebase() -> Tablebase {
+        Tablebase::generate(&Board::new(3)).expect("Unsolvable board")
+    }
+
+    #[test]
+    fn it_takes_the_win_over_the_block() {
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(3, Nought)
+            .update(1, Cross)
+            .update(4, Nought);
+
+        assert_eq!(Place(2), Perfect::new(tablebase()).decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_searches_boards_the_tablebase_does_not_cover() {
+        let board = Board::new(4)
+            .update(0, Cross)
+            .update(4, Nought)
+            .update(1, Cross)
+            .update(5, Nought)
+            .update(2, Cross)
+            .update(6, Nought);
+
+        assert_eq!(Place(3), Perfect::new(tablebase()).decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_draws_against_itself() {
+        let mut game = Game::new(
+            Board::new(3),
+            Box::new(Computer::new(Cross, Perfect::new(tablebase()))),
+            Box::new(Computer::new(Nought, Perfect::new(tablebase()))),
+        );
+
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+
+        assert_eq!(&Empty, game.get_winner());
+    }
+}
@@ -0,0 +1,115 @@
+use board::Board;
+use moves::Move;
+use player::strategy::Strategy;
+use rules::{self, Role};
+use token::Token::{self, Blocked, Empty};
+
+const GROWTH: u64 = 4;
+const WIN: u64 = 1 << 40;
+
+#[derive(Debug, PartialEq)]
+pub struct Potential {
+    role: Role,
+}
+
+impl Potential {
+    pub fn new(role: Role) -> Potential {
+        Potential { role }
+    }
+
+    fn evaluate(&self, board: &Board) -> u64 {
+        if rules::is_won(board) {
+            return WIN;
+        }
+
+        board
+            .partition()
+            .iter()
+            .map(|line| line_potential(line))
+            .sum()
+    }
+}
+
+impl Strategy for Potential {
+    fn name(&self) -> String {
+        String::from("potential")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        let options = board.legal_moves(token).into_iter().map(|choice| {
+            let potential = self.evaluate(&board.clone().apply(choice, *token));
+            (potential, choice)
+        });
+
+        let best = match self.role {
+            Role::Order => options.max_by_key(|&(potential, _)| potential),
+            Role::Chaos => options.min_by_key(|&(potential, _)| potential),
+        };
+
+        best.expect("No legal moves").1
+    }
+}
+
+fn line_potential(line: &[Token]) -> u64 {
+    let symbols: Vec<&Token> = line.iter().filter(|cell| **cell != Empty).collect();
+
+    match symbols.first() {
+        _ if symbols.contains(&&Blocked) => 0,
+        Some(first) if symbols.iter().any(|symbol| symbol != first) => 0,
+        _ => GROWTH.pow(symbols.len() as u32),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moves::Move::Mark;
+    use token::Token::{Cross, Nought};
+
+    fn create_order_and_chaos_board(crosses: Vec<usize>, noughts: Vec<usize>) -> Board {
+        let mut board = Board::order_and_chaos(6, 5);
+        for i in crosses {
+            board = board.update(i, Cross);
+        }
+        for i in noughts {
+            board = board.update(i, Nought);
+        }
+
+        board
+    }
+
+    #[test]
+    fn it_scores_lines_by_matching_symbols() {
+        assert_eq!(1, line_potential(&[Empty, Empty, Empty]));
+        assert_eq!(16, line_potential(&[Cross, Empty, Cross]));
+        assert_eq!(0, line_potential(&[Cross, Nought, Empty]));
+        assert_eq!(0, line_potential(&[Blocked, Empty, Blocked]));
+    }
+
+    #[test]
+    fn it_completes_five_in_a_row_as_order() {
+        let board = create_order_and_chaos_board(vec![], vec![6, 7, 8, 9]);
+        let strategy = Potential::new(Role::Order);
+        let selection = strategy.decide(&board, &Cross);
+
+        assert!(selection == Mark(10, Nought) || selection == Mark(11, Nought));
+    }
+
+    #[test]
+    fn it_blocks_open_four_as_chaos() {
+        let board = create_order_and_chaos_board(vec![7, 8, 9, 10], vec![]);
+        let strategy = Potential::new(Role::Chaos);
+        let selection = strategy.decide(&board, &Nought);
+
+        assert!(selection == Mark(6, Nought) || selection == Mark(11, Nought));
+    }
+
+    #[test]
+    fn it_avoids_completing_a_line_as_chaos() {
+        let board = create_order_and_chaos_board(vec![0, 1, 2, 3], vec![5]);
+        let strategy = Potential::new(Role::Chaos);
+        let selection = strategy.decide(&board, &Nought);
+
+        assert_ne!(Mark(4, Cross), selection);
+    }
+}
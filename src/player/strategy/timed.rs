@@ -0,0 +1,57 @@
+use board::Board;
+use moves::Move;
+use player::strategy::Strategy;
+use std::time::Duration;
+use token::Token;
+
+/// Gives the inner strategy the same thinking time for every move, whether
+/// or not a clock is running.
+#[derive(Debug, PartialEq)]
+pub struct Timed<S> {
+    inner: S,
+    budget: Duration,
+}
+
+impl<S: Strategy> Timed<S> {
+    pub fn new(inner: S, budget: Duration) -> Timed<S> {
+        Timed { inner, budget }
+    }
+}
+
+impl<S: Strategy> Strategy for Timed<S> {
+    fn name(&self) -> String {
+        format!("{}@{}", self.inner.name(), self.budget.as_millis())
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        self.inner.decide_within(board, token, self.budget)
+    }
+
+    fn decide_within(&self, board: &Board, token: &Token, time_left: Duration) -> Move {
+        self.inner.decide_within(board, token, time_left.min(self.budget))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use player::strategy::unbeatable::Unbeatable;
+    use token::Token::{Cross, Nought};
+
+    #[test]
+    fn it_decides_within_its_budget() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought).update(8, Cross);
+        let strategy = Timed::new(Unbeatable::new(Nought), Duration::from_secs(60));
+
+        let expected = Unbeatable::new(Nought).decide(&board, &Nought);
+        assert_eq!(expected, strategy.decide(&board, &Nought));
+    }
+
+    #[test]
+    fn it_still_moves_without_a_budget() {
+        let board = Board::new(3).update(0, Cross);
+        let strategy = Timed::new(Unbeatable::new(Nought), Duration::from_secs(0));
+
+        assert!(board.legal_moves(&Nought).contains(&strategy.decide(&board, &Nought)));
+    }
+}
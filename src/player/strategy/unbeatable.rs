@@ -1,13 +1,18 @@
-use board::Board;
+use board::{Board, Scoring};
+use moves::Move;
 use player::strategy::Strategy;
 use rules;
-use std::i32::{MAX, MIN};
+use std::time::{Duration, Instant};
 use token::Token::{self, Cross, Nought};
 
+const HORIZON: i32 = 9;
+pub const WIN_SCORE: i32 = 10;
+
 #[derive(Debug, PartialEq)]
 pub struct Unbeatable {
     max: Token,
     min: Token,
+    deadline: Option<Instant>,
 }
 
 impl Unbeatable {
@@ -15,13 +20,26 @@ impl Unbeatable {
         Unbeatable {
             max: token,
             min: get_min_token(token),
+            deadline: None,
         }
     }
 
+    fn is_out_of_time(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
     fn score(&self, depth: i32, board: &Board) -> i32 {
         match *rules::get_winner(board) {
-            winner if winner == self.max => depth + 10,
-            winner if winner == self.min => -depth - 10,
+            winner if winner == self.max => depth + WIN_SCORE,
+            winner if winner == self.min => -depth - WIN_SCORE,
+            _ => 0,
+        }
+    }
+
+    fn wild_score(&self, depth: i32, board: &Board, is_max: bool) -> i32 {
+        match (rules::is_won(board), is_max) {
+            (true, true) => -depth - WIN_SCORE,
+            (true, false) => depth + WIN_SCORE,
             _ => 0,
         }
     }
@@ -33,39 +51,83 @@ impl Unbeatable {
         }
     }
 
+    /// Every legal move with the score the search gives it: positive when the
+    /// max token wins, more so the sooner, and negative when it loses.
+    pub fn score_moves(&self, board: &Board) -> Vec<(Move, i32)> {
+        let depth = horizon(board);
+
+        board
+            .legal_moves(&self.max)
+            .into_iter()
+            .map(|choice| {
+                let after = board.clone().apply(choice, self.max);
+                (choice, self.get_best_option(depth - 1, i32::MIN, i32::MAX, &after, false).0)
+            })
+            .collect()
+    }
+
     fn get_best_option(
+        &self,
+        depth: i32,
+        alpha: i32,
+        beta: i32,
+        board: &Board,
+        is_max: bool,
+    ) -> (i32, Option<Move>) {
+        self.search(depth, alpha, beta, board, is_max, &mut Vec::new())
+    }
+
+    fn search(
         &self,
         depth: i32,
         mut alpha: i32,
         mut beta: i32,
         board: &Board,
         is_max: bool,
-    ) -> (i32, usize) {
+        path: &mut Vec<Board>,
+    ) -> (i32, Option<Move>) {
         let mut best_score;
-        let mut best_move = 10;
+        let mut best_move = None;
+        let token = self.current_token(is_max);
+        let legal_moves = board.legal_moves(&token);
+
+        if self.is_out_of_time() {
+            return (0, best_move);
+        }
 
         if rules::is_game_over(board) {
-            best_score = self.score(depth, board);
+            best_score = match board.get_scoring() {
+                Scoring::Symbol => self.score(depth, board),
+                _ => self.wild_score(depth, board, is_max),
+            };
             return (best_score, best_move);
         };
 
-        for i in &board.empty_cells() {
-            let token = self.current_token(is_max);
-            let mock_board = board.clone().update(*i, token);
-            best_score = self.get_best_option(depth - 1, alpha, beta, &mock_board, !is_max)
+        if legal_moves.is_empty()
+            || depth <= 0
+            || board.get_limit().is_some() && is_cut_off(depth, board, path)
+        {
+            return (0, best_move);
+        };
+
+        path.push(board.clone());
+
+        for choice in legal_moves {
+            let mock_board = board.clone().apply(choice, token);
+            best_score = self.search(depth - 1, alpha, beta, &mock_board, !is_max, path)
                 .0;
 
             if is_max {
                 if alpha < best_score {
                     alpha = best_score;
-                    best_move = *i;
+                    best_move = Some(choice);
                 }
             }
 
             if !is_max {
                 if beta > best_score {
                     beta = best_score;
-                    best_move = *i;
+                    best_move = Some(choice);
                 }
             }
 
@@ -74,6 +136,8 @@ impl Unbeatable {
             };
         }
 
+        path.pop();
+
         best_score = match is_max {
             true => alpha,
             false => beta,
@@ -84,12 +148,61 @@ impl Unbeatable {
 }
 
 impl Strategy for Unbeatable {
-    fn decide(&self, board: &Board) -> usize {
-        let depth = board.empty_cells().len() as i32;
-        self.get_best_option(depth, MIN, MAX, &board, true).1
+    fn name(&self) -> String {
+        String::from("unbeatable")
+    }
+
+    fn decide(&self, board: &Board, token: &Token) -> Move {
+        if *token != self.max {
+            return Unbeatable::new(*token).decide(board, token);
+        }
+
+        self.get_best_option(horizon(board), i32::MIN, i32::MAX, board, true)
+            .1
+            .expect("No legal moves")
+    }
+
+    /// Searches one ply deeper at a time until its share of the time left
+    /// runs out, keeping the move of the deepest search that finished.
+    fn decide_within(&self, board: &Board, token: &Token, time_left: Duration) -> Move {
+        if *token != self.max {
+            return Unbeatable::new(*token).decide_within(board, token, time_left);
+        }
+
+        let moves_left = (board.empty_cells().len() as u32).div_ceil(2).max(1);
+        let timed = Unbeatable {
+            deadline: Some(Instant::now() + time_left / moves_left),
+            ..*self
+        };
+        let mut choice = None;
+
+        for depth in 1..=horizon(board) {
+            let best = timed.get_best_option(depth, i32::MIN, i32::MAX, board, true).1;
+            if timed.is_out_of_time() {
+                break;
+            }
+            choice = best;
+        }
+
+        choice
+            .or_else(|| board.legal_moves(&self.max).first().cloned())
+            .expect("No legal moves")
     }
 }
 
+/// How many plies the search looks ahead: to the end of the game, or a
+/// fixed horizon when marks vanish and games can go on for ever.
+pub fn horizon(board: &Board) -> i32 {
+    match board.get_limit() {
+        Some(_) => HORIZON,
+        None => board.empty_cells().len() as i32,
+    }
+}
+
+fn is_cut_off(depth: i32, board: &Board, path: &[Board]) -> bool {
+    depth <= 0 || path.iter().any(|position| position.repeats(board))
+}
+
 fn get_min_token(token: Token) -> Token {
     match token == Cross {
         true => Nought,
@@ -100,8 +213,10 @@ fn get_min_token(token: Token) -> Token {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use board::Topology;
     use board::tests::*;
-    use token::Token::Empty;
+    use moves::Move::{Mark, Place, Slide};
+    use token::Token::{Blocked, Empty};
 
     #[test]
     fn it_creates_strategy_with_cross_max() {
@@ -149,8 +264,8 @@ mod tests {
         let mut board = create_tied_board(3);
         let unbeatable = Unbeatable::new(Cross);
         assert_eq!(
-            (0, 10),
-            unbeatable.get_best_option(0, MIN, MAX, &mut board, true)
+            (0, None),
+            unbeatable.get_best_option(0, i32::MIN, i32::MAX, &mut board, true)
         );
     }
 
@@ -162,8 +277,8 @@ mod tests {
         let mut board = create_board_from_cells(win);
         let unbeatable = Unbeatable::new(Nought);
         assert_eq!(
-            (-14, 10),
-            unbeatable.get_best_option(4, MIN, MAX, &mut board, true)
+            (-14, None),
+            unbeatable.get_best_option(4, i32::MIN, i32::MAX, &mut board, true)
         );
     }
 
@@ -175,8 +290,8 @@ mod tests {
         let mut board = create_board_from_cells(one_spot);
         let unbeatable = Unbeatable::new(Nought);
         assert_eq!(
-            (0, 7),
-            unbeatable.get_best_option(1, MIN, MAX, &mut board, false)
+            (0, Some(Place(7))),
+            unbeatable.get_best_option(1, i32::MIN, i32::MAX, &mut board, false)
         );
     }
 
@@ -185,11 +300,62 @@ mod tests {
         let mut board = Board::new(3);
         let unbeatable = Unbeatable::new(Nought);
         assert_eq!(
-            (0, 0),
-            unbeatable.get_best_option(1, MIN, MAX, &mut board, true)
+            (0, Some(Place(0))),
+            unbeatable.get_best_option(1, i32::MIN, i32::MAX, &mut board, true)
+        );
+    }
+
+    #[test]
+    fn it_treats_repeated_position_as_draw() {
+        let board = create_limited_board(3, vec![4, 0, 2, 6, 8, 1]);
+        let unbeatable = Unbeatable::new(Cross);
+        let path = vec![board.clone()];
+        assert!(is_cut_off(5, &board, &path));
+        assert!(!is_cut_off(5, &board, &[]));
+        assert!(is_cut_off(0, &board, &[]));
+        assert_eq!(
+            (0, None),
+            unbeatable.search(5, i32::MIN, i32::MAX, &board, true, &mut path.clone())
         );
     }
 
+    #[test]
+    fn it_wins_limited_game_when_possible() {
+        let board = create_limited_board(3, vec![6, 3, 0, 4, 1, 8]);
+        let unbeatable = Unbeatable::new(Cross);
+        assert_eq!(Place(2), unbeatable.decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_picks_open_cell_in_limited_game() {
+        let board = create_limited_board(3, vec![4, 0, 2, 6, 8, 1]);
+        let unbeatable = Unbeatable::new(Cross);
+        assert!(board.legal_moves(&Cross).contains(&unbeatable.decide(&board, &Cross)));
+    }
+
+    #[test]
+    fn it_scores_wild_win_for_player_who_moved_last() {
+        let board = Board::wild(3).update(0, Nought).update(1, Nought).update(2, Nought);
+        let unbeatable = Unbeatable::new(Cross);
+        assert_eq!(-13, unbeatable.wild_score(3, &board, true));
+        assert_eq!(13, unbeatable.wild_score(3, &board, false));
+        assert_eq!(0, unbeatable.wild_score(3, &Board::wild(3), true));
+    }
+
+    #[test]
+    fn it_completes_line_of_either_symbol_in_wild_game() {
+        let board = Board::wild(3).update(0, Nought).update(1, Nought).update(4, Cross);
+        let unbeatable = Unbeatable::new(Nought);
+        assert_eq!(Mark(2, Nought), unbeatable.decide(&board, &Nought));
+    }
+
+    #[test]
+    fn it_slides_into_winning_line() {
+        let board = create_sliding_board(vec![0, 3, 1, 4, 5, 8]);
+        let unbeatable = Unbeatable::new(Cross);
+        assert_eq!(Slide(5, 2), unbeatable.decide(&board, &Cross));
+    }
+
     #[test]
     fn it_blocks_min_player_win() {
         let block = vec![
@@ -198,8 +364,78 @@ mod tests {
         let mut board = create_board_from_cells(block);
         let unbeatable = Unbeatable::new(Nought);
         assert_eq!(
-            (0, 7),
-            unbeatable.get_best_option(6, MIN, MAX, &mut board, true)
+            (0, Some(Place(7))),
+            unbeatable.get_best_option(6, i32::MIN, i32::MAX, &mut board, true)
+        );
+    }
+
+    #[test]
+    fn it_completes_broken_diagonal_on_torus() {
+        let board = Board::new(3)
+            .with_topology(Topology::Torus)
+            .update(1, Cross)
+            .update(0, Nought)
+            .update(5, Cross)
+            .update(8, Nought);
+        let unbeatable = Unbeatable::new(Cross);
+
+        assert_eq!(Place(6), unbeatable.decide(&board, &Cross));
+    }
+
+    #[test]
+    fn it_blocks_broken_diagonal_on_torus() {
+        let board = Board::new(3)
+            .with_topology(Topology::Torus)
+            .update(1, Cross)
+            .update(4, Nought)
+            .update(5, Cross);
+        let unbeatable = Unbeatable::new(Nought);
+
+        assert_eq!(Place(6), unbeatable.decide(&board, &Nought));
+    }
+
+    #[test]
+    fn it_plays_for_the_token_it_is_handed() {
+        let board = Board::new(3).update(0, Cross).update(3, Nought).update(1, Cross);
+        let unbeatable = Unbeatable::new(Cross);
+
+        assert_eq!(Place(2), unbeatable.decide(&board, &Nought));
+    }
+
+    #[test]
+    fn it_plays_around_blocked_cells() {
+        let mut board = Board::new(3).with_preset(4, Blocked);
+        let players = [Unbeatable::new(Cross), Unbeatable::new(Nought)];
+        let mut turn = 0;
+
+        while !rules::is_game_over(&board) {
+            let token = players[turn % 2].max;
+            let choice = players[turn % 2].decide(&board, &token);
+            board = board.apply(choice, token);
+            turn += 1;
+        }
+
+        assert_eq!(Blocked, board.get_cells()[4]);
+        assert_eq!(8, turn);
+        assert_eq!(&Empty, rules::get_winner(&board));
+    }
+
+    #[test]
+    fn it_finds_the_same_move_when_time_allows() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought).update(8, Cross);
+        let unbeatable = Unbeatable::new(Nought);
+
+        assert_eq!(
+            unbeatable.decide(&board, &Nought),
+            unbeatable.decide_within(&board, &Nought, Duration::from_secs(60))
         );
     }
+
+    #[test]
+    fn it_still_moves_without_time_to_think() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought);
+        let choice = Unbeatable::new(Cross).decide_within(&board, &Cross, Duration::from_secs(0));
+
+        assert!(board.legal_moves(&Cross).contains(&choice));
+    }
 }
@@ -0,0 +1,101 @@
+use token::Token;
+
+/// A mark in superposition across two cells, labelled with the turn it was made on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spooky {
+    pub token: Token,
+    pub turn: usize,
+    pub cells: (usize, usize),
+}
+
+impl Spooky {
+    pub fn new(token: Token, turn: usize, cells: (usize, usize)) -> Spooky {
+        Spooky { token, turn, cells }
+    }
+
+    pub fn touches(&self, cell: usize) -> bool {
+        self.cells.0 == cell || self.cells.1 == cell
+    }
+
+    fn other_cell(&self, cell: usize) -> usize {
+        match self.cells.0 == cell {
+            true => self.cells.1,
+            false => self.cells.0,
+        }
+    }
+}
+
+pub fn has_cycle(marks: &[Spooky], length: usize) -> bool {
+    let mut roots: Vec<usize> = (0..length).collect();
+
+    for mark in marks {
+        let (first, second) = (find_root(&roots, mark.cells.0), find_root(&roots, mark.cells.1));
+        if first == second {
+            return true;
+        }
+        roots[first] = second;
+    }
+
+    false
+}
+
+/// Resolves `mark` into `cell` and every mark sharing a resolved cell into its
+/// other cell, returning the marks left in superposition and the classical marks.
+pub fn collapse(marks: &[Spooky], mark: Spooky, cell: usize) -> (Vec<Spooky>, Vec<(usize, Spooky)>) {
+    let mut remaining: Vec<Spooky> = marks.iter().filter(|other| **other != mark).cloned().collect();
+    let mut resolved = Vec::new();
+    let mut pending = vec![(cell, mark)];
+
+    while let Some((cell, mark)) = pending.pop() {
+        resolved.push((cell, mark));
+
+        let (forced, kept): (Vec<Spooky>, Vec<Spooky>) =
+            remaining.into_iter().partition(|other| other.touches(cell));
+        remaining = kept;
+        pending.extend(forced.into_iter().map(|other| (other.other_cell(cell), other)));
+    }
+
+    (remaining, resolved)
+}
+
+fn find_root(roots: &[usize], cell: usize) -> usize {
+    let mut root = cell;
+    while roots[root] != root {
+        root = roots[root];
+    }
+
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token::Token::{Cross, Nought};
+
+    #[test]
+    fn it_detects_cycles_of_entanglement() {
+        let marks = vec![
+            Spooky::new(Cross, 1, (0, 4)),
+            Spooky::new(Nought, 2, (4, 8)),
+        ];
+        assert!(!has_cycle(&marks, 9));
+
+        let closed = [&marks[..], &[Spooky::new(Cross, 3, (8, 0))]].concat();
+        assert!(has_cycle(&closed, 9));
+        assert!(has_cycle(&[Spooky::new(Cross, 1, (0, 4)), Spooky::new(Nought, 2, (4, 0))], 9));
+    }
+
+    #[test]
+    fn it_collapses_entangled_marks_in_cascade() {
+        let marks = vec![
+            Spooky::new(Cross, 1, (0, 4)),
+            Spooky::new(Nought, 2, (4, 8)),
+            Spooky::new(Cross, 3, (8, 0)),
+            Spooky::new(Nought, 4, (1, 2)),
+        ];
+        let (remaining, resolved) = collapse(&marks, marks[2], 0);
+
+        assert_eq!(vec![marks[3]], remaining);
+        assert_eq!(vec![(0, marks[2]), (4, marks[0]), (8, marks[1])], resolved);
+    }
+}
@@ -0,0 +1,444 @@
+use game::Game;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+use std::fs;
+use std::path::PathBuf;
+use token::Token::Empty;
+
+const FIELD_SEPARATOR: char = '\t';
+const COMMENT: char = ';';
+const HEADER: &str = "; name\tgames\twon\tdrawn\tlost\telo\tglicko\tdeviation\tvolatility";
+const INITIAL_RATING: f64 = 1500.0;
+const ELO_K: f64 = 32.0;
+const ELO_SCALE: f64 = 400.0;
+const INITIAL_DEVIATION: f64 = 350.0;
+const INITIAL_VOLATILITY: f64 = 0.06;
+const GLICKO_SCALE: f64 = 173.7178;
+const GLICKO_TAU: f64 = 0.5;
+const GLICKO_EPSILON: f64 = 0.000_001;
+const WIN: f64 = 1.0;
+const DRAW: f64 = 0.5;
+const LOSS: f64 = 0.0;
+
+/// The rating systems a leaderboard can be ranked by.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum System {
+    Elo,
+    Glicko,
+}
+
+/// A Glicko-2 rating on the familiar Elo-like scale: the rating, how
+/// uncertain it is and how erratic the player's results have been.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Glicko {
+    pub rating: f64,
+    pub deviation: f64,
+    pub volatility: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rating {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub elo: f64,
+    pub glicko: Glicko,
+}
+
+/// Every rated player by name, written as plain text with one line per
+/// player: the name, games, wins, draws and losses, the Elo rating and the
+/// Glicko-2 rating, deviation and volatility, separated by tabs.
+///
+/// Each game is its own Glicko-2 rating period, so a rating moves after
+/// every game rather than in batches.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Registry {
+    players: BTreeMap<String, Rating>,
+}
+
+impl System {
+    pub fn from_name(name: &str) -> Option<System> {
+        match name {
+            "elo" => Some(System::Elo),
+            "glicko" => Some(System::Glicko),
+            _ => None,
+        }
+    }
+
+    pub fn to_str(&self) -> &str {
+        match *self {
+            System::Elo => "Elo",
+            System::Glicko => "Glicko-2",
+        }
+    }
+}
+
+impl Glicko {
+    pub fn new() -> Glicko {
+        Glicko {
+            rating: INITIAL_RATING,
+            deviation: INITIAL_DEVIATION,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+
+    /// The rating after one rating period with the given opponents and
+    /// scores, following Glickman's Glicko-2 algorithm.
+    pub fn update(&self, results: &[(Glicko, f64)]) -> Glicko {
+        let mu = (self.rating - INITIAL_RATING) / GLICKO_SCALE;
+        let phi = self.deviation / GLICKO_SCALE;
+        if results.is_empty() {
+            let deviation = (phi.powi(2) + self.volatility.powi(2)).sqrt() * GLICKO_SCALE;
+            return Glicko { deviation, ..*self };
+        }
+
+        let scaled: Vec<(f64, f64, f64)> = results
+            .iter()
+            .map(|&(ref opponent, score)| {
+                let opponent_mu = (opponent.rating - INITIAL_RATING) / GLICKO_SCALE;
+                let opponent_phi = opponent.deviation / GLICKO_SCALE;
+                let weight = 1.0 / (1.0 + 3.0 * opponent_phi.powi(2) / PI.powi(2)).sqrt();
+                let expected = 1.0 / (1.0 + (-weight * (mu - opponent_mu)).exp());
+                (weight, expected, score)
+            })
+            .collect();
+        let variance = 1.0 / scaled
+            .iter()
+            .map(|&(weight, expected, _)| weight.powi(2) * expected * (1.0 - expected))
+            .sum::<f64>();
+        let improvement: f64 = scaled
+            .iter()
+            .map(|&(weight, expected, score)| weight * (score - expected))
+            .sum();
+        let delta = variance * improvement;
+
+        let volatility = new_volatility(self.volatility, phi, variance, delta);
+        let pre_period = (phi.powi(2) + volatility.powi(2)).sqrt();
+        let new_phi = 1.0 / (1.0 / pre_period.powi(2) + 1.0 / variance).sqrt();
+        let new_mu = mu + new_phi.powi(2) * improvement;
+
+        Glicko {
+            rating: new_mu * GLICKO_SCALE + INITIAL_RATING,
+            deviation: new_phi * GLICKO_SCALE,
+            volatility,
+        }
+    }
+}
+
+impl Default for Glicko {
+    fn default() -> Glicko {
+        Glicko::new()
+    }
+}
+
+impl Rating {
+    pub fn new() -> Rating {
+        Rating {
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            elo: INITIAL_RATING,
+            glicko: Glicko::new(),
+        }
+    }
+
+    pub fn get_games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    pub fn get(&self, system: System) -> f64 {
+        match system {
+            System::Elo => self.elo,
+            System::Glicko => self.glicko.rating,
+        }
+    }
+
+    fn score(&mut self, score: f64) {
+        match score {
+            _ if score == WIN => self.wins += 1,
+            _ if score == LOSS => self.losses += 1,
+            _ => self.draws += 1,
+        }
+    }
+}
+
+impl Default for Rating {
+    fn default() -> Rating {
+        Rating::new()
+    }
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn parse(text: &str) -> Result<Registry, String> {
+        let mut registry = Registry::new();
+        let lines = text
+            .lines()
+            .enumerate()
+            .filter(|&(_, line)| !line.trim().is_empty() && !line.starts_with(COMMENT));
+
+        for (number, line) in lines {
+            let invalid = || format!("Invalid ratings line {}: {}", number + 1, line);
+            let fields: Vec<&str> = line.split(FIELD_SEPARATOR).collect();
+            if fields.len() != 9 || fields[0].is_empty() {
+                return Err(invalid());
+            }
+            let count = |index: usize| fields[index].parse::<usize>().map_err(|_| invalid());
+            let value = |index: usize| fields[index].parse::<f64>().map_err(|_| invalid());
+            let rating = Rating {
+                wins: count(2)?,
+                draws: count(3)?,
+                losses: count(4)?,
+                elo: value(5)?,
+                glicko: Glicko {
+                    rating: value(6)?,
+                    deviation: value(7)?,
+                    volatility: value(8)?,
+                },
+            };
+            if rating.get_games() != count(1)? {
+                return Err(invalid());
+            }
+
+            registry.players.insert(String::from(fields[0]), rating);
+        }
+
+        Ok(registry)
+    }
+
+    /// Reads the registry in a file, or starts an empty one when there is
+    /// no file yet.
+    pub fn load(path: &PathBuf) -> Result<Registry, String> {
+        match path.exists() {
+            true => {
+                let text = fs::read_to_string(path)
+                    .map_err(|error| format!("{} ({}).", path.display(), error))?;
+                Registry::parse(&text)
+            }
+            false => Ok(Registry::new()),
+        }
+    }
+
+    pub fn save(&self, path: &PathBuf) -> Result<(), String> {
+        fs::write(path, self.to_text()).map_err(|error| format!("{} ({}).", path.display(), error))
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", HEADER);
+
+        for (name, rating) in self.players.iter() {
+            text.push_str(&format!(
+                "{1}{0}{2}{0}{3}{0}{4}{0}{5}{0}{6:.2}{0}{7:.2}{0}{8:.2}{0}{9:.6}\n",
+                FIELD_SEPARATOR,
+                name,
+                rating.get_games(),
+                rating.wins,
+                rating.draws,
+                rating.losses,
+                rating.elo,
+                rating.glicko.rating,
+                rating.glicko.deviation,
+                rating.glicko.volatility
+            ));
+        }
+
+        text
+    }
+
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Rating> {
+        self.players.get(name)
+    }
+
+    /// Rates a game between two differently named players, the winner
+    /// given by seat, and returns both players' ratings from before it.
+    pub fn record(
+        &mut self,
+        first: &str,
+        second: &str,
+        winner: Option<usize>,
+    ) -> Option<(Rating, Rating)> {
+        if first == second {
+            return None;
+        }
+
+        let before = (self.rating(first), self.rating(second));
+        let score = match winner {
+            Some(0) => WIN,
+            Some(_) => LOSS,
+            None => DRAW,
+        };
+        let (one, two) = before;
+        let expected = 1.0 / (1.0 + 10f64.powf((two.elo - one.elo) / ELO_SCALE));
+
+        let player = self.players.entry(String::from(first)).or_default();
+        player.score(score);
+        player.elo += ELO_K * (score - expected);
+        player.glicko = one.glicko.update(&[(two.glicko, score)]);
+
+        let opponent = self.players.entry(String::from(second)).or_default();
+        opponent.score(WIN - score);
+        opponent.elo -= ELO_K * (score - expected);
+        opponent.glicko = two.glicko.update(&[(one.glicko, WIN - score)]);
+
+        Some(before)
+    }
+
+    /// Rates a finished two-player game by the names of its players.
+    pub fn record_game(&mut self, game: &Game) -> Option<(Rating, Rating)> {
+        let names = game.get_player_names();
+        let tokens = game.get_seat_tokens();
+        if names.len() != 2 {
+            return None;
+        }
+
+        let winner = match *game.get_winner() {
+            Empty => None,
+            ref winner => tokens.iter().position(|token| token == winner),
+        };
+        self.record(&names[0], &names[1], winner)
+    }
+
+    /// Every player from the highest rating to the lowest.
+    pub fn leaderboard(&self, system: System) -> Vec<(&str, &Rating)> {
+        let mut players: Vec<(&str, &Rating)> = self
+            .players
+            .iter()
+            .map(|(name, rating)| (name.as_str(), rating))
+            .collect();
+        players.sort_by(|&(_, first), &(_, second)| {
+            second
+                .get(system)
+                .partial_cmp(&first.get(system))
+                .unwrap_or(Ordering::Equal)
+        });
+        players
+    }
+
+    fn rating(&self, name: &str) -> Rating {
+        self.players.get(name).cloned().unwrap_or_default()
+    }
+}
+
+/// Finds the new volatility with the Illinois variant of regula falsi.
+fn new_volatility(volatility: f64, phi: f64, variance: f64, delta: f64) -> f64 {
+    let a = volatility.powi(2).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        ex * (delta.powi(2) - phi.powi(2) - variance - ex)
+            / (2.0 * (phi.powi(2) + variance + ex).powi(2))
+            - (x - a) / GLICKO_TAU.powi(2)
+    };
+
+    let mut lower = a;
+    let mut upper = match delta.powi(2) > phi.powi(2) + variance {
+        true => (delta.powi(2) - phi.powi(2) - variance).ln(),
+        false => {
+            let mut k = 1.0;
+            while f(a - k * GLICKO_TAU) < 0.0 {
+                k += 1.0;
+            }
+            a - k * GLICKO_TAU
+        }
+    };
+    let mut f_lower = f(lower);
+    let mut f_upper = f(upper);
+
+    while (upper - lower).abs() > GLICKO_EPSILON {
+        let next = lower + (lower - upper) * f_lower / (f_upper - f_lower);
+        let f_next = f(next);
+        if f_next * f_upper <= 0.0 {
+            lower = upper;
+            f_lower = f_upper;
+        } else {
+            f_lower /= 2.0;
+        }
+        upper = next;
+        f_upper = f_next;
+    }
+
+    (lower / 2.0).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glicko(rating: f64, deviation: f64) -> Glicko {
+        Glicko {
+            rating,
+            deviation,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+
+    #[test]
+    fn it_follows_the_glicko_2_worked_example() {
+        let player = glicko(1500.0, 200.0);
+        let results = [
+            (glicko(1400.0, 30.0), WIN),
+            (glicko(1550.0, 100.0), LOSS),
+            (glicko(1700.0, 300.0), LOSS),
+        ];
+        let updated = player.update(&results);
+
+        assert!((updated.rating - 1464.06).abs() < 0.01);
+        assert!((updated.deviation - 151.52).abs() < 0.01);
+        assert!((updated.volatility - 0.05999).abs() < 0.00001);
+    }
+
+    #[test]
+    fn it_moves_elo_by_half_k_between_new_players() {
+        let mut registry = Registry::new();
+        registry.record("ada", "computer:lazy", Some(0));
+
+        assert_eq!(1516.0, registry.get("ada").unwrap().elo);
+        assert_eq!(1484.0, registry.get("computer:lazy").unwrap().elo);
+        assert_eq!(1, registry.get("computer:lazy").unwrap().losses);
+        assert!(registry.get("ada").unwrap().glicko.rating > INITIAL_RATING);
+    }
+
+    #[test]
+    fn it_does_not_rate_a_player_against_itself() {
+        let mut registry = Registry::new();
+        assert_eq!(None, registry.record("guest", "guest", None));
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn it_ranks_by_the_chosen_system() {
+        let mut registry = Registry::new();
+        registry.record("ada", "bo", Some(0));
+        registry.record("cy", "bo", None);
+
+        let names: Vec<&str> = registry
+            .leaderboard(System::Elo)
+            .iter()
+            .map(|&(name, _)| name)
+            .collect();
+        assert_eq!(vec!["ada", "cy", "bo"], names);
+    }
+
+    #[test]
+    fn it_reads_back_what_it_writes() {
+        let mut registry = Registry::new();
+        registry.record("ada lovelace", "computer:unbeatable@50", None);
+        let parsed = Registry::parse(&registry.to_text()).expect("Invalid ratings");
+
+        assert_eq!(2, parsed.len());
+        assert_eq!(1, parsed.get("ada lovelace").unwrap().draws);
+        assert_eq!(registry.to_text(), parsed.to_text());
+        assert!(Registry::parse("ada\t2\t1\t0\t0\t1500\t1500\t350\t0.06").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use board::Board;
+use history::Record;
+use moves::Move;
+use token::Token;
+
+const FORWARD: &str = "n";
+const BACK: &str = "p";
+const AUTOPLAY: &str = "a";
+const QUIT: &str = "q";
+
+/// What the viewer asked the replay to do next.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Action {
+    Forward,
+    Back,
+    Jump(usize),
+    Autoplay,
+    Quit,
+}
+
+/// A recorded game being stepped through, one position at a time, from the
+/// start (ply 0) to the position after the last move.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Replay {
+    record: Record,
+    positions: Vec<Board>,
+    ply: usize,
+}
+
+impl Action {
+    /// Reads a line as typed: enter or `n` steps forward, `p` steps back, a
+    /// number jumps to the position after that move, `a` plays the rest
+    /// automatically and `q` quits. A line without even a newline means the
+    /// input has run out, which quits too.
+    pub fn parse(line: &str) -> Option<Action> {
+        if line.is_empty() {
+            return Some(Action::Quit);
+        }
+
+        match line.trim().to_lowercase().as_str() {
+            "" | FORWARD => Some(Action::Forward),
+            BACK => Some(Action::Back),
+            AUTOPLAY => Some(Action::Autoplay),
+            QUIT => Some(Action::Quit),
+            number => number.parse().ok().map(Action::Jump),
+        }
+    }
+}
+
+impl Replay {
+    pub fn new(record: Record) -> Result<Replay, String> {
+        let positions = record.positions()?;
+
+        Ok(Replay {
+            record,
+            positions,
+            ply: 0,
+        })
+    }
+
+    pub fn get_record(&self) -> &Record {
+        &self.record
+    }
+
+    pub fn get_board(&self) -> &Board {
+        &self.positions[self.ply]
+    }
+
+    /// How many moves have been played on the position shown.
+    pub fn get_ply(&self) -> usize {
+        self.ply
+    }
+
+    pub fn get_length(&self) -> usize {
+        self.record.moves.len()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.ply == self.get_length()
+    }
+
+    /// The move that led to the position shown.
+    pub fn last_move(&self) -> Option<(Token, Move)> {
+        self.ply
+            .checked_sub(1)
+            .map(|index| self.record.moves[index])
+    }
+
+    /// The token the record moves next from the position shown.
+    pub fn to_move(&self) -> Option<Token> {
+        self.record.moves.get(self.ply).map(|&(token, _)| token)
+    }
+
+    /// Applies an action, returning whether the position shown changed.
+    pub fn step(&mut self, action: Action) -> bool {
+        let ply = match action {
+            Action::Forward if !self.is_over() => self.ply + 1,
+            Action::Back if self.ply > 0 => self.ply - 1,
+            Action::Jump(ply) if ply <= self.get_length() => ply,
+            _ => return false,
+        };
+
+        let changed = ply != self.ply;
+        self.ply = ply;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use history::History;
+    use moves::Move::Place;
+    use token::Token::{Cross, Empty, Nought};
+
+    const LINE: &str = "2026-10-19 08:15:02\t1500\tstandard\tflat\tfree\t-\tada|bo\tX O\tX\t\
+                        X:1 O:4 X:2 O:5 X:3";
+
+    fn replay() -> Replay {
+        let history = History::parse(LINE);
+        Replay::new(history.get_records()[0].clone()).expect("Unplayable record")
+    }
+
+    #[test]
+    fn it_reads_the_viewer_actions() {
+        assert_eq!(Some(Action::Forward), Action::parse("\n"));
+        assert_eq!(Some(Action::Forward), Action::parse("n\n"));
+        assert_eq!(Some(Action::Back), Action::parse("P\n"));
+        assert_eq!(Some(Action::Jump(4)), Action::parse("4\n"));
+        assert_eq!(Some(Action::Autoplay), Action::parse("a\n"));
+        assert_eq!(Some(Action::Quit), Action::parse("q\n"));
+        assert_eq!(Some(Action::Quit), Action::parse(""));
+        assert_eq!(None, Action::parse("forward\n"));
+    }
+
+    #[test]
+    fn it_steps_forward_and_back_through_the_positions() {
+        let mut replay = replay();
+
+        assert_eq!(None, replay.last_move());
+        assert_eq!(Some(Cross), replay.to_move());
+        assert!(!replay.step(Action::Back));
+        assert!(replay.step(Action::Forward));
+        assert_eq!(Some((Cross, Place(0))), replay.last_move());
+        assert_eq!(Cross, replay.get_board().get_cells()[0]);
+        assert!(replay.step(Action::Back));
+        assert_eq!(Empty, replay.get_board().get_cells()[0]);
+    }
+
+    #[test]
+    fn it_jumps_to_a_move_within_the_game() {
+        let mut replay = replay();
+
+        assert!(replay.step(Action::Jump(4)));
+        assert_eq!(Some((Nought, Place(4))), replay.last_move());
+        assert!(!replay.step(Action::Jump(6)));
+        assert!(replay.step(Action::Jump(5)));
+        assert!(replay.is_over());
+        assert_eq!(None, replay.to_move());
+        assert!(!replay.step(Action::Forward));
+        assert!(replay.step(Action::Jump(0)));
+        assert_eq!(0, replay.get_ply());
+    }
+}
@@ -0,0 +1,95 @@
+use analysis::{self, Analysis, Value};
+use game::Turn;
+use moves::Move;
+use token::Token;
+
+/// A move as played next to the best move on the same position, both valued
+/// with perfect play from there on.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Review {
+    pub ply: usize,
+    pub token: Token,
+    pub choice: Move,
+    pub value: Value,
+    pub best: Analysis,
+}
+
+impl Review {
+    /// A blunder gives away the outcome the best move keeps, such as a draw
+    /// that becomes a loss. Taking longer to win is not one.
+    pub fn is_blunder(&self) -> bool {
+        self.value.outcome() < self.best.value.outcome()
+    }
+}
+
+/// Reviews every turn the full search can finish, skipping the rest.
+pub fn review(turns: &[Turn]) -> Vec<Review> {
+    turns
+        .iter()
+        .filter_map(|turn| {
+            let analyses = analysis::analyse(&turn.board, &turn.token).ok()?;
+            let played = analyses.iter().find(|analysis| analysis.choice == turn.choice)?;
+
+            Some(Review {
+                ply: turn.board.moves_played() + 1,
+                token: turn.token,
+                choice: turn.choice,
+                value: played.value,
+                best: analyses[0].clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use board::Board;
+    use moves::Move::Place;
+    use token::Token::{Cross, Nought};
+
+    fn turn(board: Board, token: Token, cell: usize) -> Turn {
+        Turn {
+            board,
+            token,
+            choice: Place(cell),
+            human: true,
+        }
+    }
+
+    #[test]
+    fn it_flags_a_move_that_turns_a_draw_into_a_loss() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought).update(8, Cross);
+        let reviews = review(&[turn(board, Nought, 2)]);
+
+        assert_eq!(1, reviews.len());
+        assert_eq!(4, reviews[0].ply);
+        assert_eq!(Value::Loss(4), reviews[0].value);
+        assert_eq!(Value::Draw, reviews[0].best.value);
+        assert_eq!(Place(1), reviews[0].best.choice);
+        assert!(reviews[0].is_blunder());
+    }
+
+    #[test]
+    fn it_passes_moves_that_keep_the_outcome() {
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(1, Nought)
+            .update(4, Cross)
+            .update(8, Nought);
+        let drawn = Board::new(3).update(0, Cross).update(4, Nought);
+        let reviews = review(&[turn(board, Cross, 6), turn(drawn, Cross, 8)]);
+
+        assert_eq!(Value::Win(3), reviews[0].value);
+        assert!(!reviews[0].is_blunder());
+        assert_eq!(Value::Draw, reviews[1].value);
+        assert!(!reviews[1].is_blunder());
+    }
+
+    #[test]
+    fn it_skips_turns_it_cannot_search() {
+        let reviews = review(&[turn(Board::new(4), Cross, 0), turn(Board::wild(3), Cross, 0)]);
+
+        assert!(reviews.is_empty());
+    }
+}
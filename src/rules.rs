@@ -1,22 +1,70 @@
 use board::Board;
+use token::PLAYER_TOKENS;
 use token::Token::{self, Cross, Empty, Nought};
 
+const FULL_POINT: f32 = 1.0;
+const HALF_POINT: f32 = 0.5;
+pub const CAPTURES_TO_WIN: usize = 5;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Role {
+    Order,
+    Chaos,
+}
+
+impl Role {
+    pub fn to_str(&self) -> &str {
+        match *self {
+            Role::Order => "Order",
+            Role::Chaos => "Chaos",
+        }
+    }
+}
+
 pub fn is_game_over(board: &Board) -> bool {
-    is_won(board) || is_draw(board)
+    match board.is_misere() {
+        true => board.live_boards().is_empty(),
+        false => is_won(board) || is_draw(board),
+    }
 }
 
 pub fn get_winner(board: &Board) -> &Token {
-    if win_for(&Cross, board) {
-        return &Cross;
-    } else if win_for(&Nought, board) {
-        return &Nought;
+    let tokens: &'static [Token] = &PLAYER_TOKENS;
+    tokens.iter().find(|token| win_for(token, board)).unwrap_or(&Empty)
+}
+
+pub fn get_first_winner(board: &Board) -> &Token {
+    match (first_line_turn(&Cross, board), first_line_turn(&Nought, board)) {
+        (Some(cross), Some(nought)) if nought < cross => &Nought,
+        (Some(_), _) => &Cross,
+        (None, Some(_)) => &Nought,
+        (None, None) => &Empty,
+    }
+}
+
+pub fn get_points(token: &Token, board: &Board) -> f32 {
+    match (first_line_turn(token, board), get_first_winner(board)) {
+        (Some(_), winner) if winner == token => FULL_POINT,
+        (Some(_), _) => HALF_POINT,
+        (None, _) => 0.0,
+    }
+}
+
+pub fn get_winning_role(board: &Board) -> Option<Role> {
+    if is_won(board) {
+        Some(Role::Order)
+    } else if board.empty_cells().is_empty() {
+        Some(Role::Chaos)
     } else {
-        return &Empty;
+        None
     }
 }
 
-fn is_won(board: &Board) -> bool {
-    win_for(&Cross, board) || win_for(&Nought, board)
+pub fn is_won(board: &Board) -> bool {
+    match board.is_numerical() {
+        true => board.lines().iter().any(|line| sums_to_target(line, board)),
+        false => PLAYER_TOKENS.iter().any(|token| win_for(token, board)),
+    }
 }
 
 fn is_draw(board: &Board) -> bool {
@@ -24,10 +72,29 @@ fn is_draw(board: &Board) -> bool {
 }
 
 fn win_for(token: &Token, board: &Board) -> bool {
-    board
+    let has_line = board
         .partition()
         .iter()
-        .any(|line| each_token_matches(line, token))
+        .any(|line| each_token_matches(line, token));
+
+    has_line || board.captured_pairs(token) >= CAPTURES_TO_WIN
+}
+
+fn first_line_turn(token: &Token, board: &Board) -> Option<usize> {
+    board
+        .lines()
+        .iter()
+        .filter(|line| line.iter().all(|cell| board.get_cells()[*cell] == *token))
+        .map(|line| line.iter().map(|cell| board.get_age(*cell)).max().unwrap_or(0))
+        .min()
+}
+
+fn sums_to_target(line: &[usize], board: &Board) -> bool {
+    let numbers: Vec<usize> = line.iter()
+        .filter_map(|cell| board.get_cells()[*cell].get_number())
+        .collect();
+
+    numbers.len() == line.len() && numbers.iter().sum::<usize>() == board.get_target()
 }
 
 fn each_token_matches(line: &Vec<Token>, token: &Token) -> bool {
@@ -37,6 +104,7 @@ fn each_token_matches(line: &Vec<Token>, token: &Token) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use board::Topology;
     use board::tests::*;
 
     #[test]
@@ -65,6 +133,14 @@ mod tests {
         assert!(is_won(&board));
     }
 
+    #[test]
+    fn it_detects_wins_along_broken_diagonals_on_a_torus() {
+        let flat = Board::new(3).update(1, Cross).update(5, Cross).update(6, Cross);
+        let torus = flat.clone().with_topology(Topology::Torus);
+        assert!(!is_won(&flat));
+        assert!(is_won(&torus));
+    }
+
     #[test]
     fn it_specifies_winner() {
         let board = create_patterned_board(3, (0..9).collect());
@@ -77,6 +153,64 @@ mod tests {
         assert_eq!(&Empty, get_winner(&board));
     }
 
+    #[test]
+    fn it_credits_order_with_five_in_a_row_of_either_symbol() {
+        let mut board = Board::order_and_chaos(6, 5);
+        for i in [7, 14, 21, 28] {
+            board = board.update(i, Nought);
+        }
+        assert_eq!(None, get_winning_role(&board));
+        board = board.update(35, Nought);
+        assert_eq!(Some(Role::Order), get_winning_role(&board));
+    }
+
+    #[test]
+    fn it_credits_chaos_with_full_board_without_line() {
+        let mut board = Board::order_and_chaos(6, 5);
+        for i in 0..36 {
+            let token = match (i % 6 / 2 + i / 6) % 2 {
+                0 => Cross,
+                _ => Nought,
+            };
+            board = board.update(i, token);
+        }
+        assert_eq!(Some(Role::Chaos), get_winning_role(&board));
+    }
+
+    #[test]
+    fn it_informs_if_a_full_line_sums_to_fifteen() {
+        let board = Board::numerical(3)
+            .update(0, Token::Number(8))
+            .update(1, Token::Number(1));
+        assert!(!is_won(&board.clone().update(2, Token::Number(5))));
+        assert!(is_won(&board.clone().update(2, Token::Number(6))));
+        assert!(is_won(&board.update(4, Token::Number(3)).update(8, Token::Number(4))));
+    }
+
+    #[test]
+    fn it_scores_simultaneous_lines_by_lowest_subscript() {
+        let board = Board::quantum(3)
+            .entangle(0, 1, Cross)
+            .entangle(1, 3, Nought)
+            .entangle(3, 4, Cross)
+            .entangle(4, 6, Nought)
+            .entangle(6, 7, Cross)
+            .entangle(7, 0, Nought);
+        assert!(!is_won(&board));
+
+        let board = board.collapse(7);
+        assert!(is_won(&board));
+        assert_eq!(&Cross, get_first_winner(&board));
+        assert_eq!(1.0, get_points(&Cross, &board));
+        assert_eq!(0.5, get_points(&Nought, &board));
+    }
+
+    #[test]
+    fn it_gets_string_for_role() {
+        assert_eq!("Order", Role::Order.to_str());
+        assert_eq!("Chaos", Role::Chaos.to_str());
+    }
+
     #[test]
     fn it_informs_if_tokens_match() {
         let mixed_row = vec![Cross, Cross, Empty];
@@ -98,4 +232,32 @@ mod tests {
         let board = create_tied_board(3);
         assert!(is_draw(&board));
     }
+
+    #[test]
+    fn it_wins_with_five_captured_pairs() {
+        let mut board = Board::pente(19, 5);
+        for pair in 0..5 {
+            let row = pair * 2 * 19;
+            board = board
+                .update(row, Nought)
+                .update(row + 1, Cross)
+                .update(row + 2, Cross)
+                .update(row + 3, Nought);
+            assert_eq!(pair == 4, is_won(&board));
+        }
+
+        assert_eq!(&Nought, get_winner(&board));
+    }
+
+    #[test]
+    fn it_finds_a_third_player_winning() {
+        let board = Board::gomoku(4, 3)
+            .with_players(3)
+            .update(1, Token::Triangle)
+            .update(6, Token::Triangle)
+            .update(11, Token::Triangle);
+
+        assert!(is_won(&board));
+        assert_eq!(&Token::Triangle, get_winner(&board));
+    }
 }
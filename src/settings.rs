@@ -0,0 +1,197 @@
+use board::{Board, Topology};
+use book::Book;
+use clock::TimeControl;
+use layout::Layout;
+use matches::Format;
+use opening::Opening;
+use player::strategy::menace::Menace;
+use std::path::PathBuf;
+use tablebase::Tablebase;
+use variant::Variant;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Settings {
+    variant: Variant,
+    topology: Topology,
+    layout: Layout,
+    opening: Opening,
+    tablebase: Option<Box<Tablebase>>,
+    book: Option<Box<Book>>,
+    learner: Option<Box<Menace>>,
+    time_control: Option<TimeControl>,
+    match_format: Option<Format>,
+    ratings: Option<PathBuf>,
+    history: Option<PathBuf>,
+    names: Vec<String>,
+}
+
+impl Settings {
+    pub fn new(variant: Variant) -> Settings {
+        Settings {
+            variant,
+            topology: Topology::Flat,
+            layout: Layout::new(),
+            opening: Opening::Free,
+            tablebase: None,
+            book: None,
+            learner: None,
+            time_control: None,
+            match_format: None,
+            ratings: None,
+            history: None,
+            names: Vec::new(),
+        }
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Settings {
+        Settings { topology, ..self }
+    }
+
+    pub fn with_layout(self, layout: Layout) -> Settings {
+        Settings { layout, ..self }
+    }
+
+    pub fn with_opening(self, opening: Opening) -> Settings {
+        Settings { opening, ..self }
+    }
+
+    pub fn with_tablebase(self, tablebase: Tablebase) -> Settings {
+        Settings {
+            tablebase: Some(Box::new(tablebase)),
+            ..self
+        }
+    }
+
+    pub fn with_book(self, book: Book) -> Settings {
+        Settings {
+            book: Some(Box::new(book)),
+            ..self
+        }
+    }
+
+    pub fn with_learner(self, learner: Menace) -> Settings {
+        Settings {
+            learner: Some(Box::new(learner)),
+            ..self
+        }
+    }
+
+    pub fn with_time_control(self, time_control: TimeControl) -> Settings {
+        Settings {
+            time_control: Some(time_control),
+            ..self
+        }
+    }
+
+    pub fn with_match_format(self, match_format: Format) -> Settings {
+        Settings {
+            match_format: Some(match_format),
+            ..self
+        }
+    }
+
+    pub fn with_ratings(self, ratings: PathBuf) -> Settings {
+        Settings {
+            ratings: Some(ratings),
+            ..self
+        }
+    }
+
+    pub fn with_history(self, history: PathBuf) -> Settings {
+        Settings {
+            history: Some(history),
+            ..self
+        }
+    }
+
+    pub fn with_names(self, names: Vec<String>) -> Settings {
+        Settings { names, ..self }
+    }
+
+    pub fn get_layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    pub fn get_variant(&self) -> &Variant {
+        &self.variant
+    }
+
+    pub fn get_topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn get_time_control(&self) -> Option<TimeControl> {
+        self.time_control
+    }
+
+    pub fn get_match_format(&self) -> Option<Format> {
+        self.match_format
+    }
+
+    /// The file completed games are rated in, when they are rated at all.
+    pub fn get_ratings(&self) -> Option<&PathBuf> {
+        self.ratings.as_ref()
+    }
+
+    /// The file completed games are appended to, when they are kept at all.
+    pub fn get_history(&self) -> Option<&PathBuf> {
+        self.history.as_ref()
+    }
+
+    /// The names of the human players in seat order.
+    pub fn get_names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn get_tablebase(&self) -> Option<&Tablebase> {
+        self.tablebase.as_deref()
+    }
+
+    pub fn get_book(&self) -> Option<&Book> {
+        self.book.as_deref()
+    }
+
+    pub fn get_learner(&self) -> Option<&Menace> {
+        self.learner.as_deref()
+    }
+
+    pub fn board(&self) -> Board {
+        let board = self.variant
+            .board()
+            .with_topology(self.topology)
+            .with_opening(self.opening);
+        self.layout.apply(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_builds_flat_board_for_variant_by_default() {
+        let settings = Settings::new(Variant::Standard);
+        assert_eq!(Board::new(3), settings.board());
+        assert_eq!(&Variant::Standard, settings.get_variant());
+    }
+
+    #[test]
+    fn it_applies_topology_to_variant_board() {
+        let settings = Settings::new(Variant::Wild).with_topology(Topology::Torus);
+        assert_eq!(Topology::Torus, settings.board().get_topology());
+        assert!(settings.board().is_wild());
+    }
+
+    #[test]
+    fn it_applies_layout_to_variant_board() {
+        let layout = Layout::parse("....#....").expect("Invalid layout");
+        let settings = Settings::new(Variant::Standard).with_layout(layout);
+        assert_eq!(8, settings.board().empty_cells().len());
+    }
+
+    #[test]
+    fn it_applies_opening_to_variant_board() {
+        let settings = Settings::new(Variant::Gomoku).with_opening(Opening::Swap2);
+        assert_eq!(Opening::Swap2, settings.board().get_opening());
+    }
+}
@@ -1,18 +1,31 @@
-use board::Board;
 use game::Game;
 use player::*;
 use player::computer::Computer;
 use player::human::Human;
+use player::strategy::booked::Booked;
+use player::strategy::greedy::Greedy;
 use player::strategy::lazy::Lazy;
+use player::strategy::maxn::MaxN;
+use player::strategy::Strategy;
+use player::strategy::misere::Misere;
+use player::strategy::numerical::Numerical;
+use player::strategy::perfect::Perfect;
+use player::strategy::potential::Potential;
 use player::strategy::unbeatable::Unbeatable;
-use token::Token::{Cross, Nought};
-use script::Script::ModeSelection;
+use rules::Role::{self, Chaos, Order};
+use token::PLAYER_TOKENS;
+use token::Token::{self, Cross, Nought};
+use script::Script::{ModeSelection, RoleSelection, SeatOptions, SeatSelection};
 use std::io::Write;
 use ui::input::Input;
 use ui::view::View;
 use ui::input::UserInput;
+use settings::Settings;
+use variant::Variant;
 
 const MODE_OPTIONS: [u32; 4] = [1, 2, 3, 4];
+const HUMAN_VS_COMPUTER_MODES: [u32; 2] = [2, 3];
+const SEAT_OPTIONS: [u32; 3] = [1, 2, 3];
 
 pub fn select_mode<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>) -> u32 {
     view.update_with(ModeSelection.to_str());
@@ -22,13 +35,90 @@ pub fn select_mode<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>) -
     }
 }
 
-pub fn setup_game(mode_selection: u32) -> Game {
-    let board = Board::new(3);
-    let (player_one, player_two) = setup_players(mode_selection);
-    Game::new(board, player_one, player_two)
+pub fn select_role<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>) -> Role {
+    view.update_with(RoleSelection.to_str());
+    match user_input.read_line().trim() {
+        "1" => Order,
+        "2" => Chaos,
+        _ => select_role(user_input, view),
+    }
+}
+
+pub fn select_seats<I: Input, W: Write>(
+    user_input: &mut I,
+    view: &mut View<W>,
+    tokens: &[Token],
+) -> Vec<u32> {
+    tokens
+        .iter()
+        .map(|token| select_seat(user_input, view, token))
+        .collect()
+}
+
+fn select_seat<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>, token: &Token) -> u32 {
+    view.update_with(&format!(
+        "{}{}{}",
+        SeatSelection.to_str(),
+        token.to_str(),
+        SeatOptions.to_str()
+    ));
+    match user_input.read_line().trim().parse() {
+        Ok(num) if SEAT_OPTIONS.contains(&num) => num,
+        Ok(_) | Err(_) => select_seat(user_input, view, token),
+    }
+}
+
+pub fn is_human_vs_computer(mode_selection: u32) -> bool {
+    HUMAN_VS_COMPUTER_MODES.contains(&mode_selection)
 }
 
-fn setup_players(mode_selection: u32) -> (Box<Player>, Box<Player>) {
+pub fn setup_game(mode_selection: u32, settings: &Settings, human_role: Role) -> Game {
+    let board = settings.board();
+    let variant = settings.get_variant();
+    let (player_one, player_two) = match *variant {
+        Variant::Notakto(_) => setup_solver_players(mode_selection, Misere::new),
+        Variant::Numerical => setup_solver_players(mode_selection, Numerical::new),
+        Variant::Quantum => setup_solver_players(mode_selection, Lazy::new),
+        Variant::Gomoku | Variant::Pente => match settings.get_book() {
+            Some(book) => {
+                setup_solver_players(mode_selection, || Booked::new(book.clone(), Greedy::new()))
+            }
+            None => setup_solver_players(mode_selection, Greedy::new),
+        },
+        _ if variant.has_roles() => setup_role_players(mode_selection, human_role),
+        _ => setup_players(mode_selection, settings),
+    };
+    let game = Game::new(board, player_one, player_two).with_names(settings.get_names());
+    start_clock(game, settings)
+}
+
+/// Seats one player per token in turn order, each a human, an easy computer
+/// or a hard computer as selected.
+pub fn setup_seats(settings: &Settings, seats: &[u32]) -> Game {
+    let players = seats
+        .iter()
+        .zip(PLAYER_TOKENS.iter())
+        .map(|(seat, token)| -> Box<dyn Player> {
+            match *seat {
+                1 => Box::new(Human::new(*token, UserInput::new())),
+                2 => Box::new(Computer::new(*token, Lazy::new())),
+                _ => Box::new(Computer::new(*token, MaxN::new())),
+            }
+        })
+        .collect();
+
+    let game = Game::with_players(settings.board(), players).with_names(settings.get_names());
+    start_clock(game, settings)
+}
+
+fn start_clock(game: Game, settings: &Settings) -> Game {
+    match settings.get_time_control() {
+        Some(time_control) => game.with_clock(time_control),
+        None => game,
+    }
+}
+
+fn setup_players(mode_selection: u32, settings: &Settings) -> (Box<dyn Player>, Box<dyn Player>) {
     match mode_selection {
         1 => (
             Box::new(Human::new(Cross, UserInput::new())),
@@ -40,11 +130,88 @@ fn setup_players(mode_selection: u32) -> (Box<Player>, Box<Player>) {
         ),
         3 => (
             Box::new(Human::new(Cross, UserInput::new())),
-            Box::new(Computer::new(Nought, Unbeatable::new(Nought))),
+            hard_player(Nought, settings),
+        ),
+        _ => (hard_player(Cross, settings), hard_player(Nought, settings)),
+    }
+}
+
+/// The strongest computer the settings allow: a learned table when one is
+/// given, otherwise perfect play from a tablebase or a full search, either
+/// of them after the moves of an opening book.
+fn hard_player(token: Token, settings: &Settings) -> Box<dyn Player> {
+    if let Some(learner) = settings.get_learner() {
+        return Box::new(Computer::new(token, learner.clone()));
+    }
+
+    match (settings.get_book(), settings.get_tablebase()) {
+        (Some(book), Some(tablebase)) => Box::new(Computer::new(
+            token,
+            Booked::new(book.clone(), Perfect::new(tablebase.clone())),
+        )),
+        (Some(book), None) => Box::new(Computer::new(
+            token,
+            Booked::new(book.clone(), Unbeatable::new(token)),
+        )),
+        (None, Some(tablebase)) => Box::new(Computer::new(token, Perfect::new(tablebase.clone()))),
+        (None, None) => Box::new(Computer::new(token, Unbeatable::new(token))),
+    }
+}
+
+fn setup_solver_players<S, F>(
+    mode_selection: u32,
+    solver: F,
+) -> (Box<dyn Player>, Box<dyn Player>)
+where
+    S: Strategy + 'static,
+    F: Fn() -> S,
+{
+    match mode_selection {
+        1 => (
+            Box::new(Human::new(Cross, UserInput::new())),
+            Box::new(Human::new(Nought, UserInput::new())),
         ),
+        2 => (
+            Box::new(Human::new(Cross, UserInput::new())),
+            Box::new(Computer::new(Nought, Lazy::new())),
+        ),
+        3 => (
+            Box::new(Human::new(Cross, UserInput::new())),
+            Box::new(Computer::new(Nought, solver())),
+        ),
+        _ => (
+            Box::new(Computer::new(Cross, solver())),
+            Box::new(Computer::new(Nought, solver())),
+        ),
+    }
+}
+
+fn setup_role_players(mode_selection: u32, human_role: Role) -> (Box<dyn Player>, Box<dyn Player>) {
+    let (human_token, computer_token, computer_role) = match human_role {
+        Order => (Cross, Nought, Chaos),
+        Chaos => (Nought, Cross, Order),
+    };
+
+    match mode_selection {
+        1 => (
+            Box::new(Human::new(Cross, UserInput::new())),
+            Box::new(Human::new(Nought, UserInput::new())),
+        ),
+        2 | 3 => {
+            let human: Box<dyn Player> = Box::new(Human::new(human_token, UserInput::new()));
+            let computer: Box<dyn Player> = match mode_selection {
+                2 => Box::new(Computer::new(computer_token, Lazy::new())),
+                _ => Box::new(Computer::new(computer_token, Potential::new(computer_role))),
+            };
+
+            match human_role {
+                Order => (human, computer),
+                Chaos => (computer, human),
+            }
+        }
         _ => (
-            Box::new(Computer::new(Cross, Unbeatable::new(Cross))),
-            Box::new(Computer::new(Nought, Unbeatable::new(Nought))),
+            Box::new(Computer::new(Cross, Potential::new(Order))),
+            Box::new(Computer::new(Nought, Potential::new(Chaos))),
         ),
     }
 }
@@ -52,6 +219,7 @@ fn setup_players(mode_selection: u32) -> (Box<Player>, Box<Player>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use clock::TimeControl;
     use ui::input::tests::*;
 
     #[test]
@@ -62,4 +230,57 @@ mod tests {
         assert_eq!(1, selection);
         assert_eq!(7, mock_input.times_called());
     }
+
+    #[test]
+    fn it_keeps_prompting_for_valid_role() {
+        let mut mock_input = MockInput::new(vec!["3", "order", "2"]);
+        let mut view = View::new(Vec::new());
+        let role = select_role(&mut mock_input, &mut view);
+        assert_eq!(Chaos, role);
+        assert_eq!(3, mock_input.times_called());
+    }
+
+    #[test]
+    fn it_selects_a_player_for_each_seat() {
+        let mut mock_input = MockInput::new(vec!["1", "4", "3", "2"]);
+        let mut view = View::new(Vec::new());
+        let seats = select_seats(&mut mock_input, &mut view, &PLAYER_TOKENS);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert_eq!(vec![1, 3, 2], seats);
+        assert!(output.contains("Who plays Δ?"));
+    }
+
+    #[test]
+    fn it_seats_three_computers() {
+        let game = setup_seats(&Settings::new(Variant::ThreePlayer), &[3, 2, 3]);
+        assert_eq!(3, game.get_player_count());
+        assert_eq!(Cross, game.current_player_token());
+    }
+
+    #[test]
+    fn it_informs_if_mode_is_human_vs_computer() {
+        assert!(!is_human_vs_computer(1));
+        assert!(is_human_vs_computer(2));
+        assert!(is_human_vs_computer(3));
+        assert!(!is_human_vs_computer(4));
+    }
+
+    #[test]
+    fn it_seats_order_first() {
+        let game = setup_game(4, &Settings::new(Variant::OrderAndChaos), Chaos);
+        assert_eq!(Some(Order), game.current_player_role());
+        assert_eq!(36, game.get_board().get_length());
+    }
+
+    #[test]
+    fn it_starts_the_clock_from_the_settings() {
+        let control = TimeControl::parse("3+2").expect("Invalid time control");
+        let settings = Settings::new(Variant::Gomoku).with_time_control(control);
+
+        let game = setup_game(4, &settings, Order);
+        assert_eq!(Some(&control), game.get_clock().map(|clock| clock.get_control()));
+        assert!(setup_game(4, &Settings::new(Variant::Gomoku), Order).get_clock().is_none());
+        assert!(setup_seats(&settings, &[3, 2, 3]).get_clock().is_some());
+    }
 }
@@ -0,0 +1,435 @@
+use board::{Board, Scoring};
+use opening::Opening;
+use rules;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use token::Token::{Blocked, Cross, Nought};
+
+const WIN: i8 = 1;
+const DRAW: i8 = 0;
+const LOSS: i8 = -1;
+pub const OPEN: u8 = 0;
+pub const FIRST: u8 = 1;
+pub const SECOND: u8 = 2;
+pub const BLOCKED: u8 = 3;
+const BITS: u32 = 2;
+const MAX_CELLS: usize = 64;
+const ENTRY_BYTES: usize = 17;
+const SYMMETRY_COUNT: usize = 8;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Outcome {
+    FirstPlayerWin,
+    Draw,
+    SecondPlayerWin,
+}
+
+impl Outcome {
+    pub fn to_str(&self) -> &str {
+        match *self {
+            Outcome::FirstPlayerWin => "the first player wins",
+            Outcome::Draw => "the game is a draw",
+            Outcome::SecondPlayerWin => "the second player wins",
+        }
+    }
+}
+
+/// An m,n,k game to solve: a square board, the length of a winning line and
+/// an optional file keeping the transposition table between runs.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Problem {
+    pub size: usize,
+    pub win_length: usize,
+    pub table: Option<PathBuf>,
+}
+
+/// What a search found, with the positions it visited to find it; those
+/// already in a table loaded beforehand cost one visit each.
+#[derive(Debug, PartialEq)]
+pub struct Solution {
+    pub outcome: Outcome,
+    pub positions: usize,
+    pub best_moves: Vec<usize>,
+}
+
+/// Win/draw/loss search over every position reachable from a board, with
+/// positions reduced by the symmetries of the square before they are stored.
+pub struct Solver {
+    signature: String,
+    lines_through: Vec<Vec<Vec<usize>>>,
+    symmetries: Vec<Vec<usize>>,
+    table: HashMap<u128, i8>,
+    visited: usize,
+}
+
+impl Problem {
+    pub fn new(size: usize, win_length: usize) -> Problem {
+        Problem {
+            size,
+            win_length,
+            table: None,
+        }
+    }
+
+    pub fn with_table(self, table: PathBuf) -> Problem {
+        Problem {
+            table: Some(table),
+            ..self
+        }
+    }
+
+    pub fn board(&self) -> Board {
+        Board::gomoku(self.size, self.win_length)
+    }
+}
+
+pub fn run(problem: &Problem) -> Result<Solution, String> {
+    let board = problem.board();
+    let mut solver = Solver::new(&board)?;
+
+    if let Some(ref path) = problem.table {
+        if path.exists() {
+            solver.load(path).map_err(|error| error.to_string())?;
+        }
+    }
+
+    let solution = solver.solve(&board);
+
+    if let Some(ref path) = problem.table {
+        solver.save(path).map_err(|error| error.to_string())?;
+    }
+
+    Ok(solution)
+}
+
+impl Solver {
+    pub fn new(board: &Board) -> Result<Solver, String> {
+        check(board)?;
+
+        Ok(Solver {
+            signature: signature(board),
+            lines_through: lines_through(board),
+            symmetries: symmetries(board.get_row_size()),
+            table: HashMap::new(),
+            visited: 0,
+        })
+    }
+
+    pub fn positions(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn solve(&mut self, board: &Board) -> Solution {
+        let mut cells = pack(board);
+        let mover = next_mover(&cells);
+        let mut values = Vec::new();
+        self.visited = 0;
+
+        if !rules::is_won(board) {
+            for cell in open_cells(&cells) {
+                cells[cell] = mover;
+                values.push((cell, -self.value(&mut cells, cell)));
+                cells[cell] = OPEN;
+            }
+        }
+
+        let best = values.iter().map(|&(_, value)| value).max();
+        let first_value = match (best, rules::get_winner(board)) {
+            (Some(value), _) if mover == FIRST => value,
+            (Some(value), _) => -value,
+            (None, &Cross) => WIN,
+            (None, &Nought) => LOSS,
+            (None, _) => DRAW,
+        };
+        let outcome = match first_value {
+            WIN => Outcome::FirstPlayerWin,
+            LOSS => Outcome::SecondPlayerWin,
+            _ => Outcome::Draw,
+        };
+
+        Solution {
+            outcome,
+            positions: self.visited,
+            best_moves: values
+                .into_iter()
+                .filter(|&(_, value)| Some(value) == best)
+                .map(|(cell, _)| cell)
+                .collect(),
+        }
+    }
+
+    pub fn load(&mut self, path: &PathBuf) -> io::Result<usize> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut signature = String::new();
+        reader.read_line(&mut signature)?;
+
+        if signature.trim() != self.signature {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The table in {} belongs to another board.", path.display()),
+            ));
+        }
+
+        let mut entries = Vec::new();
+        reader.read_to_end(&mut entries)?;
+        for entry in entries.chunks(ENTRY_BYTES).filter(|entry| entry.len() == ENTRY_BYTES) {
+            let key = entry[..ENTRY_BYTES - 1]
+                .iter()
+                .rev()
+                .fold(0, |key, byte| key << 8 | u128::from(*byte));
+            self.table.insert(key, entry[ENTRY_BYTES - 1] as i8);
+        }
+
+        Ok(self.table.len())
+    }
+
+    pub fn save(&self, path: &PathBuf) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{}", self.signature)?;
+
+        for (key, value) in self.table.iter() {
+            writer.write_all(&key.to_le_bytes())?;
+            writer.write_all(&[*value as u8])?;
+        }
+
+        writer.flush()
+    }
+
+    // Scores the position for the player about to move, given the last cell played.
+    fn value(&mut self, cells: &mut Vec<u8>, last: usize) -> i8 {
+        self.visited += 1;
+        if completes_line(&self.lines_through, cells, last) {
+            return LOSS;
+        }
+
+        let key = self.canonical_key(cells);
+        if let Some(value) = self.table.get(&key) {
+            return *value;
+        }
+
+        let mover = next_mover(cells);
+        let open = open_cells(cells);
+        let mut best = match open.is_empty() {
+            true => DRAW,
+            false => LOSS,
+        };
+
+        for cell in open {
+            cells[cell] = mover;
+            let value = -self.value(cells, cell);
+            cells[cell] = OPEN;
+
+            if value > best {
+                best = value;
+            }
+            if best == WIN {
+                break;
+            }
+        }
+
+        self.table.insert(key, best);
+        best
+    }
+
+    fn canonical_key(&self, cells: &[u8]) -> u128 {
+        canonical(&self.symmetries, cells).0
+    }
+}
+
+/// Fails for boards the solver cannot search: anything but two players
+/// placing their own symbol on one open board of at most 64 spots.
+pub fn check(board: &Board) -> Result<(), String> {
+    if !is_plain(board) {
+        return Err(String::from("Only open two-player k in a row boards can be solved."));
+    }
+    if board.get_length() > MAX_CELLS {
+        return Err(format!("Boards over {} spots are too large to solve.", MAX_CELLS));
+    }
+
+    Ok(())
+}
+
+/// Two players taking turns from an open board, so the stones on it tell
+/// whose move it is: blocked cells and handicap stones would not.
+pub fn is_plain(board: &Board) -> bool {
+    board.get_scoring() == Scoring::Symbol && board.get_board_count() == 1
+        && board.get_player_count() == 2 && board.get_limit().is_none()
+        && !board.is_capturing() && board.get_opening() == Opening::Free
+        && !board.has_preset()
+}
+
+pub fn signature(board: &Board) -> String {
+    format!(
+        "{}x{} k{} {:?}",
+        board.get_row_size(),
+        board.get_row_size(),
+        board.get_win_length(),
+        board.get_topology()
+    )
+}
+
+/// Every winning line through each spot, indexed by spot.
+pub fn lines_through(board: &Board) -> Vec<Vec<Vec<usize>>> {
+    let lines = board.lines();
+
+    (0..board.get_length())
+        .map(|cell| {
+            lines
+                .iter()
+                .filter(|line| line.contains(&cell))
+                .cloned()
+                .collect()
+        })
+        .collect()
+}
+
+pub fn completes_line(lines_through: &[Vec<Vec<usize>>], cells: &[u8], last: usize) -> bool {
+    lines_through[last]
+        .iter()
+        .any(|line| line.iter().all(|cell| cells[*cell] == cells[last]))
+}
+
+/// The smallest key among the symmetric copies of a position, and the
+/// symmetry that produces it.
+pub fn canonical(symmetries: &[Vec<usize>], cells: &[u8]) -> (u128, usize) {
+    symmetries
+        .iter()
+        .enumerate()
+        .map(|(index, symmetry)| {
+            let key = symmetry
+                .iter()
+                .fold(0, |key, source| key << BITS | u128::from(cells[*source]));
+            (key, index)
+        })
+        .min()
+        .unwrap_or((0, 0))
+}
+
+pub fn pack(board: &Board) -> Vec<u8> {
+    board
+        .get_cells()
+        .iter()
+        .map(|cell| match *cell {
+            Cross => FIRST,
+            Nought => SECOND,
+            Blocked => BLOCKED,
+            _ => OPEN,
+        })
+        .collect()
+}
+
+pub fn next_mover(cells: &[u8]) -> u8 {
+    let first = cells.iter().filter(|cell| **cell == FIRST).count();
+    let second = cells.iter().filter(|cell| **cell == SECOND).count();
+
+    match first > second {
+        true => SECOND,
+        false => FIRST,
+    }
+}
+
+pub fn open_cells(cells: &[u8]) -> Vec<usize> {
+    (0..cells.len()).filter(|cell| cells[*cell] == OPEN).collect()
+}
+
+/// The eight rotations and reflections of a square, each listing for every
+/// spot the spot it is taken from.
+pub fn symmetries(size: usize) -> Vec<Vec<usize>> {
+    (0..SYMMETRY_COUNT)
+        .map(|symmetry| {
+            (0..size * size)
+                .map(|cell| {
+                    let (row, column) = transform(symmetry, cell / size, cell % size, size - 1);
+                    row * size + column
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn transform(symmetry: usize, row: usize, column: usize, end: usize) -> (usize, usize) {
+    match symmetry {
+        0 => (row, column),
+        1 => (end - column, row),
+        2 => (end - row, end - column),
+        3 => (column, end - row),
+        4 => (row, end - column),
+        5 => (end - row, column),
+        6 => (column, row),
+        _ => (end - column, end - row),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    fn solve(size: usize, win_length: usize) -> Solution {
+        run(&Problem::new(size, win_length)).expect("Unsolvable problem")
+    }
+
+    #[test]
+    fn it_proves_classic_tic_tac_toe_is_a_draw() {
+        let solution = solve(3, 3);
+
+        assert_eq!(Outcome::Draw, solution.outcome);
+        assert_eq!((0..9).collect::<Vec<usize>>(), solution.best_moves);
+        assert!(solution.positions > 0);
+    }
+
+    #[test]
+    fn it_proves_three_in_a_row_on_4x4_is_a_first_player_win() {
+        let solution = solve(4, 3);
+
+        assert_eq!(Outcome::FirstPlayerWin, solution.outcome);
+        assert_eq!(16, solution.best_moves.len());
+    }
+
+    #[test]
+    fn it_solves_positions_with_moves_played() {
+        let board = Board::new(3).update(0, Cross).update(1, Nought);
+        let solution = Solver::new(&board).expect("Unsolvable board").solve(&board);
+
+        assert_eq!(Outcome::FirstPlayerWin, solution.outcome);
+        assert!(solution.best_moves.contains(&4));
+    }
+
+    #[test]
+    fn it_reduces_positions_by_symmetry() {
+        let solver = Solver::new(&Board::new(3)).expect("Unsolvable board");
+        let corner = [FIRST, OPEN, OPEN, OPEN, OPEN, OPEN, OPEN, OPEN, OPEN];
+        let other_corner = [OPEN, OPEN, OPEN, OPEN, OPEN, OPEN, OPEN, OPEN, FIRST];
+
+        assert_eq!(solver.canonical_key(&corner), solver.canonical_key(&other_corner));
+        assert_eq!(8, solver.symmetries.len());
+    }
+
+    #[test]
+    fn it_keeps_the_table_between_runs() {
+        let path = env::temp_dir().join("ttt_solver_test_3x3_k3.table");
+        let problem = Problem::new(3, 3).with_table(path.clone());
+        let first = run(&problem).expect("Unsolvable problem");
+
+        let mut solver = Solver::new(&problem.board()).expect("Unsolvable board");
+        assert!(solver.load(&path).expect("Unreadable table") > 0);
+        let second = solver.solve(&problem.board());
+        assert_eq!((first.outcome, &first.best_moves), (second.outcome, &second.best_moves));
+        assert_eq!(9, second.positions);
+
+        let mut other = Solver::new(&Board::new(4)).expect("Unsolvable board");
+        assert!(other.load(&path).is_err());
+        fs::remove_file(path).expect("Undeletable table");
+    }
+
+    #[test]
+    fn it_refuses_boards_it_cannot_solve() {
+        assert!(Solver::new(&Board::wild(3)).is_err());
+        assert!(Solver::new(&Board::new(3).with_preset(4, Cross)).is_err());
+        assert!(Solver::new(&Board::new(3).with_preset(0, Blocked)).is_err());
+        assert!(Solver::new(&Board::new(9)).is_err());
+    }
+}
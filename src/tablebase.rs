@@ -0,0 +1,278 @@
+use board::Board;
+use solver::{self, OPEN};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+const SCALE: i8 = 100;
+const CELLS_PER_KEY_BYTE: usize = 4;
+const CELLS_PER_MASK_BYTE: usize = 8;
+
+/// What a position is worth to the player about to move and which spots
+/// keep that worth, with the spots numbered as on the position's stored copy.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Entry {
+    value: i8,
+    best: u64,
+}
+
+/// Every position reachable from a board, stored once per rotation or
+/// reflection with its value and best moves, so perfect play is a lookup.
+/// Values count plies: a win scores more the sooner it comes, a loss less
+/// the later it comes, and a draw scores zero.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tablebase {
+    signature: String,
+    length: usize,
+    symmetries: Vec<Vec<usize>>,
+    entries: HashMap<u128, Entry>,
+}
+
+struct Generator {
+    lines_through: Vec<Vec<Vec<usize>>>,
+    symmetries: Vec<Vec<usize>>,
+    entries: HashMap<u128, Entry>,
+}
+
+impl Entry {
+    pub fn get_value(&self) -> i8 {
+        self.value
+    }
+}
+
+impl Tablebase {
+    pub fn generate(board: &Board) -> Result<Tablebase, String> {
+        solver::check(board)?;
+
+        let symmetries = solver::symmetries(board.get_row_size());
+        let mut generator = Generator {
+            lines_through: solver::lines_through(board),
+            symmetries: symmetries.clone(),
+            entries: HashMap::new(),
+        };
+        generator.value(&solver::pack(board));
+
+        Ok(Tablebase {
+            signature: solver::signature(board),
+            length: board.get_length(),
+            symmetries,
+            entries: generator.entries,
+        })
+    }
+
+    pub fn load(path: &PathBuf) -> io::Result<Tablebase> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a tablebase.", path.display()),
+            )
+        };
+        let (size, signature) = parse_header(header.trim()).ok_or_else(invalid)?;
+        let length = size * size;
+        let (key_bytes, mask_bytes) = entry_bytes(length);
+
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        if bytes.len() % (key_bytes + 1 + mask_bytes) != 0 {
+            return Err(invalid());
+        }
+
+        let entries = bytes
+            .chunks(key_bytes + 1 + mask_bytes)
+            .map(|chunk| {
+                let entry = Entry {
+                    value: chunk[key_bytes] as i8,
+                    best: from_bytes(&chunk[key_bytes + 1..]) as u64,
+                };
+                (from_bytes(&chunk[..key_bytes]), entry)
+            })
+            .collect();
+
+        Ok(Tablebase {
+            signature,
+            length,
+            symmetries: solver::symmetries(size),
+            entries,
+        })
+    }
+
+    pub fn save(&self, path: &PathBuf) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let (key_bytes, mask_bytes) = entry_bytes(self.length);
+        writeln!(writer, "{}", self.signature)?;
+
+        for (key, entry) in self.entries.iter() {
+            writer.write_all(&key.to_le_bytes()[..key_bytes])?;
+            writer.write_all(&[entry.value as u8])?;
+            writer.write_all(&entry.best.to_le_bytes()[..mask_bytes])?;
+        }
+
+        writer.flush()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get_signature(&self) -> &str {
+        &self.signature
+    }
+
+    pub fn fits(&self, board: &Board) -> bool {
+        solver::check(board).is_ok() && solver::signature(board) == self.signature
+    }
+
+    /// The entry for a board and its best moves as spots on that board.
+    pub fn lookup(&self, board: &Board) -> Option<(Entry, Vec<usize>)> {
+        if !self.fits(board) {
+            return None;
+        }
+
+        let (key, index) = solver::canonical(&self.symmetries, &solver::pack(board));
+        let symmetry = &self.symmetries[index];
+
+        self.entries.get(&key).map(|entry| {
+            let moves = (0..self.length)
+                .filter(|cell| entry.best & 1 << cell != 0)
+                .map(|cell| symmetry[cell])
+                .collect();
+            (*entry, moves)
+        })
+    }
+}
+
+impl Generator {
+    // Scores a position that is still in play for the player about to move.
+    fn value(&mut self, cells: &[u8]) -> i8 {
+        let (key, index) = solver::canonical(&self.symmetries, cells);
+        if let Some(entry) = self.entries.get(&key) {
+            return entry.value;
+        }
+
+        let mut stored: Vec<u8> = self.symmetries[index]
+            .iter()
+            .map(|source| cells[*source])
+            .collect();
+        let mover = solver::next_mover(&stored);
+        let played = stored.iter().filter(|cell| **cell != OPEN).count() as i8;
+        let mut scores = Vec::new();
+
+        for cell in solver::open_cells(&stored) {
+            stored[cell] = mover;
+            let score = if solver::completes_line(&self.lines_through, &stored, cell) {
+                SCALE - played - 1
+            } else if solver::open_cells(&stored).is_empty() {
+                0
+            } else {
+                -self.value(&stored)
+            };
+            stored[cell] = OPEN;
+            scores.push((cell, score));
+        }
+
+        let value = scores.iter().map(|&(_, score)| score).max().unwrap_or(0);
+        let best = scores
+            .iter()
+            .filter(|&&(_, score)| score == value)
+            .fold(0, |best, &(cell, _)| best | 1 << cell);
+
+        self.entries.insert(key, Entry { value, best });
+        value
+    }
+}
+
+fn parse_header(header: &str) -> Option<(usize, String)> {
+    let size = header.split('x').next()?.parse().ok()?;
+
+    match size * size {
+        1..=64 => Some((size, String::from(header))),
+        _ => None,
+    }
+}
+
+fn entry_bytes(length: usize) -> (usize, usize) {
+    (
+        length.div_ceil(CELLS_PER_KEY_BYTE),
+        length.div_ceil(CELLS_PER_MASK_BYTE),
+    )
+}
+
+fn from_bytes(bytes: &[u8]) -> u128 {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |number, byte| number << 8 | u128::from(*byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use token::Token::{Cross, Nought};
+
+    #[test]
+    fn it_stores_every_reachable_3x3_position_once_per_symmetry() {
+        let tablebase = Tablebase::generate(&Board::new(3)).expect("Unsolvable board");
+
+        assert_eq!(627, tablebase.len());
+        assert_eq!("3x3 k3 Flat", tablebase.get_signature());
+    }
+
+    #[test]
+    fn it_finds_the_fastest_win() {
+        let tablebase = Tablebase::generate(&Board::new(3)).expect("Unsolvable board");
+        let board = Board::new(3)
+            .update(0, Cross)
+            .update(3, Nought)
+            .update(1, Cross)
+            .update(4, Nought)
+            .update(8, Cross)
+            .update(7, Nought);
+        let (entry, moves) = tablebase.lookup(&board).expect("Missing position");
+
+        assert_eq!(SCALE - 7, entry.get_value());
+        assert_eq!(vec![2], moves);
+    }
+
+    #[test]
+    fn it_maps_best_moves_back_through_the_symmetry() {
+        let tablebase = Tablebase::generate(&Board::new(3)).expect("Unsolvable board");
+        let corner = Board::new(3).update(8, Cross);
+        let (entry, moves) = tablebase.lookup(&corner).expect("Missing position");
+
+        assert_eq!(0, entry.get_value());
+        assert_eq!(vec![4], moves);
+    }
+
+    #[test]
+    fn it_saves_and_loads_a_compact_file() {
+        let path = env::temp_dir().join("ttt_tablebase_test_3x3_k3.tb");
+        let tablebase = Tablebase::generate(&Board::new(3)).expect("Unsolvable board");
+        tablebase.save(&path).expect("Unwritable tablebase");
+
+        let size = fs::metadata(&path).expect("Missing tablebase").len() as usize;
+        assert_eq!("3x3 k3 Flat\n".len() + tablebase.len() * 6, size);
+        assert_eq!(tablebase, Tablebase::load(&path).expect("Unreadable tablebase"));
+        fs::remove_file(path).expect("Undeletable tablebase");
+    }
+
+    #[test]
+    fn it_only_fits_boards_of_the_same_configuration() {
+        let tablebase = Tablebase::generate(&Board::new(3)).expect("Unsolvable board");
+
+        assert!(tablebase.fits(&Board::new(3)));
+        assert!(!tablebase.fits(&Board::new(4)));
+        assert!(!tablebase.fits(&Board::wild(3)));
+        assert!(tablebase.lookup(&Board::new(4)).is_none());
+    }
+}
@@ -1,8 +1,14 @@
+const NUMERALS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+pub const PLAYER_TOKENS: [Token; 3] = [Token::Cross, Token::Nought, Token::Triangle];
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Token {
     Empty,
     Cross,
     Nought,
+    Triangle,
+    Number(usize),
+    Blocked,
 }
 
 impl Token {
@@ -11,18 +17,47 @@ impl Token {
             Token::Empty => " ",
             Token::Cross => "X",
             Token::Nought => "O",
+            Token::Triangle => "Δ",
+            Token::Number(number) => NUMERALS[number],
+            Token::Blocked => "#",
+        }
+    }
+
+    pub fn get_number(&self) -> Option<usize> {
+        match *self {
+            Token::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    pub fn from_symbol(symbol: &str) -> Option<Token> {
+        match symbol.to_uppercase().as_str() {
+            "X" => Some(Token::Cross),
+            "O" => Some(Token::Nought),
+            _ => None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Token::*;
+    use super::Token::{self, *};
 
     #[test]
     fn it_gets_string_for_token() {
         assert_eq!(" ", Empty.to_str());
         assert_eq!("X", Cross.to_str());
         assert_eq!("O", Nought.to_str());
+        assert_eq!("Δ", Triangle.to_str());
+        assert_eq!("7", Number(7).to_str());
+        assert_eq!("#", Blocked.to_str());
+    }
+
+    #[test]
+    fn it_gets_token_for_symbol() {
+        assert_eq!(Some(Cross), Token::from_symbol("x"));
+        assert_eq!(Some(Nought), Token::from_symbol("O"));
+        assert_eq!(None, Token::from_symbol(" "));
+        assert_eq!(None, Token::from_symbol("z"));
     }
 }
@@ -0,0 +1,706 @@
+use board::Board;
+use game::Game;
+use game::GameState::InProgress;
+use player::Player;
+use player::computer::Computer;
+use player::strategy::Strategy;
+use player::strategy::greedy::Greedy;
+use player::strategy::lazy::Lazy;
+use player::strategy::menace::Menace;
+use player::strategy::perfect::Perfect;
+use player::strategy::timed::Timed;
+use player::strategy::unbeatable::Unbeatable;
+use std::cmp::Reverse;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tablebase::Tablebase;
+use token::Token::{self, Cross, Nought};
+
+const NAME_SEPARATOR: char = '=';
+const BUDGET_SEPARATOR: char = '@';
+const FILE_SEPARATOR: char = ':';
+const WIN_HALVES: usize = 2;
+const DRAW_HALVES: usize = 1;
+const GAMES_PER_PAIRING: usize = 2;
+const UNBUDGETED_SPOTS: usize = 9;
+
+/// The strategies an entrant may play with.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Engine {
+    Lazy,
+    Greedy,
+    Unbeatable,
+    Learned(Box<Menace>),
+    Perfect(Box<Tablebase>),
+}
+
+/// A named player configuration: a strategy and, optionally, the thinking
+/// time it gets for every move.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Entrant {
+    name: String,
+    engine: Engine,
+    budget: Option<Duration>,
+}
+
+/// How entrants meet: everyone once, or a number of rounds in which
+/// entrants on similar scores meet and nobody meets the same opponent twice
+/// while a fresh one is left.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Pairing {
+    RoundRobin,
+    Swiss(usize),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tournament {
+    board: Board,
+    entrants: Vec<Entrant>,
+    pairing: Pairing,
+}
+
+/// An entrant's games. A bye in a Swiss round scores as a drawn pairing.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Score {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub byes: usize,
+}
+
+/// Two entrants playing one game with each colour, the first with X first.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PairingStats {
+    pub round: usize,
+    pub first: usize,
+    pub second: usize,
+    pub first_wins: usize,
+    pub second_wins: usize,
+    pub draws: usize,
+    pub cross_wins: usize,
+    pub nought_wins: usize,
+    pub plies: usize,
+}
+
+/// Everything a tournament produced: the half points each entrant took off
+/// every other, the scores, every pairing and every game as the entrants
+/// playing X and O and the winning token, with entrants in entry order.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Report {
+    pub names: Vec<String>,
+    pub crosstable: Vec<Vec<Option<usize>>>,
+    pub scores: Vec<Score>,
+    pub pairings: Vec<PairingStats>,
+    pub games: Vec<(usize, usize, Token)>,
+}
+
+impl Entrant {
+    /// Reads `[<name>=]<strategy>[@<milliseconds>]`, where the strategy is
+    /// `lazy`, `greedy`, `unbeatable`, `learned:<file>` or `tablebase:<file>`.
+    pub fn parse(spec: &str) -> Result<Entrant, String> {
+        let (name, rest) = match spec.find(NAME_SEPARATOR) {
+            Some(index) => (&spec[..index], &spec[index + 1..]),
+            None => (spec, spec),
+        };
+        let (strategy, budget) = match rest.find(BUDGET_SEPARATOR) {
+            Some(index) => {
+                let milliseconds = rest[index + 1..]
+                    .parse()
+                    .map_err(|_| format!("Invalid budget in {}.", spec))?;
+                (&rest[..index], Some(Duration::from_millis(milliseconds)))
+            }
+            None => (rest, None),
+        };
+        let (kind, file) = match strategy.find(FILE_SEPARATOR) {
+            Some(index) => (&strategy[..index], Some(PathBuf::from(&strategy[index + 1..]))),
+            None => (strategy, None),
+        };
+
+        let engine = match (kind, file) {
+            ("lazy", None) => Engine::Lazy,
+            ("greedy", None) => Engine::Greedy,
+            ("unbeatable", None) => Engine::Unbeatable,
+            ("learned", Some(path)) => Engine::Learned(Box::new(Menace::load(&path)?)),
+            ("tablebase", Some(path)) => Engine::Perfect(Box::new(
+                Tablebase::load(&path).map_err(|error| error.to_string())?,
+            )),
+            _ => return Err(format!("Unknown player {}.", spec)),
+        };
+
+        match name.is_empty() {
+            true => Err(format!("Missing name in {}.", spec)),
+            false => Ok(Entrant::new(name, engine, budget)),
+        }
+    }
+
+    pub fn new(name: &str, engine: Engine, budget: Option<Duration>) -> Entrant {
+        Entrant {
+            name: String::from(name),
+            engine,
+            budget,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name the entrant's games are rated under, which follows its
+    /// configuration rather than the name it was entered with.
+    pub fn get_rating_name(&self) -> String {
+        self.player(Cross).get_name()
+    }
+
+    pub fn fits(&self, board: &Board) -> bool {
+        match self.engine {
+            Engine::Learned(ref learner) => learner.fits(board),
+            Engine::Perfect(ref tablebase) => tablebase.fits(board),
+            _ => true,
+        }
+    }
+
+    /// Whether the entrant's games end in reasonable time: a full search
+    /// without a budget only does on the smallest boards.
+    pub fn finishes_on(&self, board: &Board) -> bool {
+        match (&self.engine, self.budget) {
+            (&Engine::Unbeatable, None) => board.get_length() <= UNBUDGETED_SPOTS,
+            _ => true,
+        }
+    }
+
+    pub fn player(&self, token: Token) -> Box<dyn Player> {
+        match self.engine {
+            Engine::Lazy => self.seat(token, Lazy::new()),
+            Engine::Greedy => self.seat(token, Greedy::new()),
+            Engine::Unbeatable => self.seat(token, Unbeatable::new(token)),
+            Engine::Learned(ref learner) => self.seat(token, (**learner).clone()),
+            Engine::Perfect(ref tablebase) => {
+                self.seat(token, Perfect::new((**tablebase).clone()))
+            }
+        }
+    }
+
+    fn seat<S: Strategy + 'static>(&self, token: Token, strategy: S) -> Box<dyn Player> {
+        match self.budget {
+            Some(budget) => Box::new(Computer::new(token, Timed::new(strategy, budget))),
+            None => Box::new(Computer::new(token, strategy)),
+        }
+    }
+}
+
+impl Pairing {
+    pub fn to_text(&self) -> String {
+        match *self {
+            Pairing::RoundRobin => String::from("round robin"),
+            Pairing::Swiss(rounds) => format!("Swiss, {} rounds", rounds),
+        }
+    }
+}
+
+impl Score {
+    pub fn get_halves(&self) -> usize {
+        self.wins * WIN_HALVES + (self.draws + self.byes * GAMES_PER_PAIRING) * DRAW_HALVES
+    }
+
+    pub fn get_games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+}
+
+impl Tournament {
+    pub fn new(board: Board, entrants: Vec<Entrant>, pairing: Pairing) -> Result<Tournament, String> {
+        if entrants.len() < 2 {
+            return Err(String::from("A tournament needs at least two players."));
+        }
+        if board.get_player_count() != 2 {
+            return Err(String::from("Tournaments are played on two-player boards."));
+        }
+        for (index, entrant) in entrants.iter().enumerate() {
+            if entrants[..index].iter().any(|other| other.name == entrant.name) {
+                return Err(format!("Two players are named {}.", entrant.name));
+            }
+            if !entrant.fits(&board) {
+                return Err(format!("The player {} does not fit the board.", entrant.name));
+            }
+            if !entrant.finishes_on(&board) {
+                return Err(format!(
+                    "The player {} needs a budget, as in unbeatable@100, on boards over {} spots.",
+                    entrant.name, UNBUDGETED_SPOTS
+                ));
+            }
+        }
+
+        Ok(Tournament {
+            board,
+            entrants,
+            pairing,
+        })
+    }
+
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn get_entrants(&self) -> &[Entrant] {
+        &self.entrants
+    }
+
+    pub fn get_pairing(&self) -> Pairing {
+        self.pairing
+    }
+
+    /// The names the entrants' games are rated under, in entry order. Two
+    /// entrants with the same configuration would be rated as one player
+    /// whose games against itself do not count, so they are refused.
+    pub fn rating_names(&self) -> Result<Vec<String>, String> {
+        let names: Vec<String> = self.entrants.iter().map(Entrant::get_rating_name).collect();
+
+        for (index, name) in names.iter().enumerate() {
+            if let Some(other) = names[..index].iter().position(|other| other == name) {
+                return Err(format!(
+                    "The players {} and {} are both rated as {}.",
+                    self.entrants[other].name, self.entrants[index].name, name
+                ));
+            }
+        }
+
+        Ok(names)
+    }
+
+    pub fn run(&self) -> Report {
+        self.run_with(|_, _| {})
+    }
+
+    /// Runs the tournament, handing every finished game and the time it
+    /// took to `played`.
+    pub fn run_with<F: FnMut(&Game, Duration)>(&self, mut played: F) -> Report {
+        let count = self.entrants.len();
+        let mut report = Report {
+            names: self.entrants.iter().map(|entrant| entrant.name.clone()).collect(),
+            crosstable: vec![vec![None; count]; count],
+            scores: vec![Score::default(); count],
+            pairings: Vec::new(),
+            games: Vec::new(),
+        };
+
+        match self.pairing {
+            Pairing::RoundRobin => {
+                for (round, pairs) in round_robin(count).into_iter().enumerate() {
+                    for (first, second) in pairs {
+                        self.play_pairing(round + 1, first, second, &mut report, &mut played);
+                    }
+                }
+            }
+            Pairing::Swiss(rounds) => {
+                for round in 1..=rounds {
+                    let (pairs, bye) = swiss_round(&report);
+                    if let Some(bye) = bye {
+                        report.scores[bye].byes += 1;
+                    }
+                    for (first, second) in pairs {
+                        self.play_pairing(round, first, second, &mut report, &mut played);
+                    }
+                }
+            }
+        }
+
+        report
+    }
+
+    fn play_pairing<F: FnMut(&Game, Duration)>(
+        &self,
+        round: usize,
+        first: usize,
+        second: usize,
+        report: &mut Report,
+        played: &mut F,
+    ) {
+        let mut stats = PairingStats {
+            round,
+            first,
+            second,
+            first_wins: 0,
+            second_wins: 0,
+            draws: 0,
+            cross_wins: 0,
+            nought_wins: 0,
+            plies: 0,
+        };
+
+        for &(cross, nought) in [(first, second), (second, first)].iter() {
+            let (winner, plies) = self.play_game(cross, nought, played);
+            stats.plies += plies;
+            report.games.push((cross, nought, winner));
+
+            let result = match winner {
+                Cross => Some((cross, nought)),
+                Nought => Some((nought, cross)),
+                _ => None,
+            };
+            match winner {
+                Cross => stats.cross_wins += 1,
+                Nought => stats.nought_wins += 1,
+                _ => {}
+            }
+            match result {
+                Some((won, lost)) => {
+                    report.scores[won].wins += 1;
+                    report.scores[lost].losses += 1;
+                    add_halves(report, won, lost, WIN_HALVES);
+                    add_halves(report, lost, won, 0);
+                    match won == first {
+                        true => stats.first_wins += 1,
+                        false => stats.second_wins += 1,
+                    }
+                }
+                None => {
+                    report.scores[cross].draws += 1;
+                    report.scores[nought].draws += 1;
+                    add_halves(report, cross, nought, DRAW_HALVES);
+                    add_halves(report, nought, cross, DRAW_HALVES);
+                    stats.draws += 1;
+                }
+            }
+        }
+
+        report.pairings.push(stats);
+    }
+
+    fn play_game<F: FnMut(&Game, Duration)>(
+        &self,
+        cross: usize,
+        nought: usize,
+        played: &mut F,
+    ) -> (Token, usize) {
+        let player_one = self.entrants[cross].player(Cross);
+        let player_two = self.entrants[nought].player(Nought);
+        let mut game = Game::new(self.board.clone(), player_one, player_two);
+        let start = Instant::now();
+
+        while game.get_state() == &InProgress {
+            game.next_turn();
+        }
+        played(&game, start.elapsed());
+
+        (*game.get_winner(), game.get_turns().len())
+    }
+}
+
+impl Report {
+    pub fn get_halves(&self, entrant: usize) -> usize {
+        self.scores[entrant].get_halves()
+    }
+
+    /// Entrants from first place to last: by points, then by the points of
+    /// the opponents they took points off (Sonneborn-Berger), then by wins.
+    pub fn standings(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.names.len()).collect();
+        let rank = |entrant: usize| {
+            (
+                self.get_halves(entrant),
+                self.tiebreak(entrant),
+                self.scores[entrant].wins,
+            )
+        };
+        order.sort_by_key(|&entrant| Reverse(rank(entrant)));
+        order
+    }
+
+    /// Each opponent's points weighted by the half points taken off them.
+    pub fn tiebreak(&self, entrant: usize) -> usize {
+        self.crosstable[entrant]
+            .iter()
+            .enumerate()
+            .filter_map(|(opponent, halves)| halves.map(|halves| halves * self.get_halves(opponent)))
+            .sum()
+    }
+
+    /// The average game length of a pairing in plies.
+    pub fn average_plies(stats: &PairingStats) -> f64 {
+        stats.plies as f64 / GAMES_PER_PAIRING as f64
+    }
+}
+
+fn add_halves(report: &mut Report, entrant: usize, opponent: usize, halves: usize) {
+    let cell = &mut report.crosstable[entrant][opponent];
+    *cell = Some(cell.unwrap_or(0) + halves);
+}
+
+/// Every entrant meets every other once, spread over rounds with the circle
+/// method; with an odd count one entrant sits out each round.
+fn round_robin(count: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut seats: Vec<Option<usize>> = (0..count).map(Some).collect();
+    if count % 2 == 1 {
+        seats.push(None);
+    }
+    let size = seats.len();
+    let mut rounds = Vec::new();
+
+    for _ in 1..size {
+        let pairs = (0..size / 2)
+            .filter_map(|seat| match (seats[seat], seats[size - 1 - seat]) {
+                (Some(first), Some(second)) => Some((first, second)),
+                _ => None,
+            })
+            .collect();
+        rounds.push(pairs);
+        seats[1..].rotate_right(1);
+    }
+
+    rounds
+}
+
+/// Pairs entrants from the top of the standings down without rematches
+/// where that can be done, and in standing order where it cannot, after
+/// giving the bye to the lowest entrant without one when the count is odd.
+fn swiss_round(report: &Report) -> (Vec<(usize, usize)>, Option<usize>) {
+    let mut unpaired = report.standings();
+    let bye = match unpaired.len() % 2 {
+        0 => None,
+        _ => {
+            let position = unpaired
+                .iter()
+                .rposition(|&entrant| report.scores[entrant].byes == 0)
+                .unwrap_or(unpaired.len() - 1);
+            Some(unpaired.remove(position))
+        }
+    };
+    let pairs = match fresh_pairs(report, &unpaired) {
+        Some(pairs) => pairs,
+        None => unpaired.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+    };
+
+    (pairs, bye)
+}
+
+/// Pairs each entrant, from the top down, with the highest placed one it
+/// has not met that still leaves the rest a way to pair without rematches,
+/// or gives up when every way needs a rematch.
+fn fresh_pairs(report: &Report, unpaired: &[usize]) -> Option<Vec<(usize, usize)>> {
+    let (&first, rest) = match unpaired.split_first() {
+        Some(split) => split,
+        None => return Some(Vec::new()),
+    };
+
+    for (position, &second) in rest.iter().enumerate() {
+        if report.crosstable[first][second].is_some() {
+            continue;
+        }
+        let mut others = rest.to_vec();
+        others.remove(position);
+        if let Some(mut pairs) = fresh_pairs(report, &others) {
+            pairs.insert(0, (first, second));
+            return Some(pairs);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entrants(specs: &[&str]) -> Vec<Entrant> {
+        specs
+            .iter()
+            .map(|spec| Entrant::parse(spec).expect("Invalid player"))
+            .collect()
+    }
+
+    #[test]
+    fn it_parses_named_players_with_budgets() {
+        let entrant = Entrant::parse("quick=unbeatable@50").expect("Invalid player");
+        assert_eq!(
+            Entrant::new("quick", Engine::Unbeatable, Some(Duration::from_millis(50))),
+            entrant
+        );
+        assert_eq!("greedy", Entrant::parse("greedy").expect("Invalid player").get_name());
+        assert_eq!("computer:unbeatable@50", entrant.get_rating_name());
+        assert!(Entrant::parse("mcts").is_err());
+        assert!(Entrant::parse("lazy@soon").is_err());
+        assert!(Entrant::parse("=lazy").is_err());
+        assert!(Entrant::parse("learned:/no/such/file").is_err());
+    }
+
+    #[test]
+    fn it_refuses_too_few_or_twice_named_players() {
+        let board = Board::new(3);
+        assert!(Tournament::new(board.clone(), entrants(&["lazy"]), Pairing::RoundRobin).is_err());
+        assert!(
+            Tournament::new(board, entrants(&["lazy", "lazy"]), Pairing::RoundRobin).is_err()
+        );
+    }
+
+    #[test]
+    fn it_refuses_a_full_search_without_a_budget_on_larger_boards() {
+        let board = Board::gomoku(4, 3);
+        let players = entrants(&["unbeatable", "lazy"]);
+        let expected = "The player unbeatable needs a budget, as in unbeatable@100, on boards \
+                        over 9 spots.";
+
+        assert_eq!(
+            Err(String::from(expected)),
+            Tournament::new(board.clone(), players, Pairing::RoundRobin)
+        );
+        let players = entrants(&["unbeatable@1", "lazy"]);
+        assert!(Tournament::new(board, players, Pairing::RoundRobin).is_ok());
+    }
+
+    #[test]
+    fn it_plays_greedy_players_on_long_winning_lines() {
+        let board = Board::gomoku(6, 6);
+        let tournament = Tournament::new(board, entrants(&["greedy", "lazy"]), Pairing::RoundRobin)
+            .expect("Invalid tournament");
+        let report = tournament.run();
+
+        assert_eq!(2, report.games.len());
+    }
+
+    #[test]
+    fn it_pairs_everyone_once_in_a_round_robin() {
+        let rounds = round_robin(5);
+        let mut pairs: Vec<(usize, usize)> = rounds
+            .iter()
+            .flat_map(|pairs| pairs.iter().map(|&(a, b)| (a.min(b), a.max(b))))
+            .collect();
+        pairs.sort();
+        pairs.dedup();
+
+        assert_eq!(5, rounds.len());
+        assert_eq!(10, pairs.len());
+        assert!(rounds.iter().all(|pairs| pairs.len() == 2));
+    }
+
+    #[test]
+    fn it_plays_both_colours_and_fills_the_crosstable() {
+        let board = Board::new(3).update(4, Cross).update(0, Nought);
+        let players = entrants(&["best=unbeatable", "lazy", "quick=unbeatable@1"]);
+        let tournament =
+            Tournament::new(board, players, Pairing::RoundRobin).expect("Invalid tournament");
+        let mut played = Vec::new();
+        let report = tournament.run_with(|game, _| played.push(game.get_player_names()));
+
+        assert_eq!(3, report.pairings.len());
+        assert_eq!(6, report.games.len());
+        assert_eq!(6, played.len());
+        let (cross, nought, _) = report.games[5];
+        let names = |seat: usize| tournament.get_entrants()[seat].get_rating_name();
+        assert_eq!(vec![names(cross), names(nought)], played[5]);
+        assert!(report.scores.iter().all(|score| score.get_games() == 4));
+        assert_eq!(None, report.crosstable[1][1]);
+        for stats in report.pairings.iter() {
+            assert_eq!(2, stats.first_wins + stats.second_wins + stats.draws);
+            assert_eq!(stats.first_wins + stats.second_wins, stats.cross_wins + stats.nought_wins);
+            let both = report.crosstable[stats.first][stats.second].unwrap()
+                + report.crosstable[stats.second][stats.first].unwrap();
+            assert_eq!(4, both);
+        }
+        assert_eq!(0, report.scores[0].losses);
+    }
+
+    #[test]
+    fn it_gives_a_bye_and_avoids_rematches_in_swiss_rounds() {
+        let board = Board::new(3).update(4, Cross).update(0, Nought);
+        let players = entrants(&["a=unbeatable", "b=greedy", "c=lazy"]);
+        let tournament =
+            Tournament::new(board, players, Pairing::Swiss(3)).expect("Invalid tournament");
+        let report = tournament.run();
+
+        assert_eq!(3, report.pairings.len());
+        assert!(report.scores.iter().all(|score| score.byes == 1));
+        let mut met: Vec<(usize, usize)> = report
+            .pairings
+            .iter()
+            .map(|stats| (stats.first.min(stats.second), stats.first.max(stats.second)))
+            .collect();
+        met.sort();
+        met.dedup();
+        assert_eq!(3, met.len());
+    }
+
+    #[test]
+    fn it_refuses_to_rate_two_players_with_the_same_configuration() {
+        let board = Board::new(3);
+        let players = entrants(&["a=unbeatable", "b=unbeatable@50", "c=lazy"]);
+        let tournament = Tournament::new(board.clone(), players, Pairing::RoundRobin)
+            .expect("Invalid tournament");
+        assert_eq!(
+            Ok(vec![
+                String::from("computer:unbeatable"),
+                String::from("computer:unbeatable@50"),
+                String::from("computer:lazy"),
+            ]),
+            tournament.rating_names()
+        );
+
+        let players = entrants(&["a=unbeatable", "b=unbeatable"]);
+        let tournament =
+            Tournament::new(board, players, Pairing::RoundRobin).expect("Invalid tournament");
+        assert_eq!(
+            Err(String::from("The players a and b are both rated as computer:unbeatable.")),
+            tournament.rating_names()
+        );
+    }
+
+    #[test]
+    fn it_looks_past_the_first_fresh_opponent_to_avoid_a_rematch() {
+        let met = [(0, 2), (2, 3), (1, 3)];
+        let mut crosstable = vec![vec![None; 4]; 4];
+        for &(first, second) in met.iter() {
+            crosstable[first][second] = Some(DRAW_HALVES);
+            crosstable[second][first] = Some(DRAW_HALVES);
+        }
+        let report = Report {
+            names: ["a", "b", "c", "d"].iter().map(|name| String::from(*name)).collect(),
+            crosstable,
+            scores: (0..4)
+                .map(|entrant| Score {
+                    wins: 3 - entrant,
+                    ..Score::default()
+                })
+                .collect(),
+            pairings: Vec::new(),
+            games: Vec::new(),
+        };
+
+        assert_eq!((vec![(0, 3), (1, 2)], None), swiss_round(&report));
+    }
+
+    #[test]
+    fn it_ranks_by_points_then_tiebreak() {
+        let report = Report {
+            names: vec![String::from("a"), String::from("b"), String::from("c")],
+            crosstable: vec![
+                vec![None, Some(2), Some(0)],
+                vec![Some(2), None, Some(2)],
+                vec![Some(4), Some(2), None],
+            ],
+            scores: vec![
+                Score {
+                    wins: 1,
+                    draws: 0,
+                    losses: 3,
+                    byes: 0,
+                },
+                Score {
+                    wins: 1,
+                    draws: 2,
+                    losses: 1,
+                    byes: 0,
+                },
+                Score {
+                    wins: 2,
+                    draws: 2,
+                    losses: 0,
+                    byes: 0,
+                },
+            ],
+            pairings: Vec::new(),
+            games: Vec::new(),
+        };
+
+        assert_eq!(vec![2, 1, 0], report.standings());
+        assert_eq!(6, report.get_halves(2));
+    }
+}
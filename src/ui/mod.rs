@@ -4,10 +4,26 @@ pub mod input;
 pub mod script;
 pub mod view;
 
-use game::Game;
+use analysis::{self, Analysis};
+use book::{Book, SelfPlay};
+use game::{Game, GameState, Turn};
+use history::{History, Query};
+use matches::{self, Match, Side};
+use moves::Move::{Collapse, Defer, Place};
+use opening::{Phase, Seat};
+use player::human;
+use player::strategy::menace::{Menace, Record, Training};
+use ratings::{Rating, Registry, System};
+use replay::{Action, Replay};
+use review;
+use rules;
+use solver::{Problem, Solution};
+use tablebase::Tablebase;
+use tournament::{Report, Tournament};
 use script::Script::*;
 use std::io::Write;
-use token::Token::Empty;
+use std::path::Path;
+use token::Token::{self, Cross, Empty, Nought};
 use ui::color::Color;
 use ui::input::Input;
 use ui::view::View;
@@ -23,26 +39,700 @@ pub fn prompt_turn<W: Write>(game: &mut Game, view: &mut View<W>, color: &Color)
     let board = game.get_board();
     let board_length = board.get_length();
     let token = game.current_player_token();
+    let phase = board.opening_phase();
+    let name = match (game.current_player_role(), phase) {
+        (Some(role), _) => String::from(role.to_str()),
+        (None, Phase::Stones(seat)) | (None, Phase::Choice(seat)) => match seat {
+            Seat::First => String::from(FirstPlayer.to_str()),
+            Seat::Second => String::from(SecondPlayer.to_str()),
+        },
+        (None, Phase::Play) => format!("Player {}", token.to_str()),
+    };
+
+    view.update_with(&presenter::view_turn(board, &token, color));
+
+    if let Some(clock) = game.get_clock() {
+        let times = presenter::view_clock(clock, &game.get_seat_tokens());
+        if !times.is_empty() {
+            view.append_with(&format!("{}{}", TimeLeft.to_str(), times));
+        }
+    }
+
+    if let Some(hint) = game.get_hint() {
+        show_hint(hint, view);
+    }
+
+    if let Some(offer) = game.get_draw_offer() {
+        if *offer != token {
+            view.append_with(&format!("{}{}", offer.to_str(), DrawOffered.to_str()));
+        }
+    }
+
+    if board.must_choose() {
+        let defer = match board.opening_choices().contains(&Defer) {
+            true => DeferChoice.to_str(),
+            false => "",
+        };
+        view.append_with(&format!("[{}] ~ {}{}.", name, ChooseColour.to_str(), defer));
+        return;
+    }
+
+    if let Phase::Stones(_) = phase {
+        view.append_with(&format!(
+            "[{}] ~ {}{}{}{}.",
+            name,
+            PickSpot.to_str(),
+            board_length,
+            OpeningStone.to_str(),
+            token.to_str()
+        ));
+        return;
+    }
+
+    if board.must_slide(&token) {
+        view.append_with(&format!("[{}] ~ {}", name, SlideToken.to_str()));
+        return;
+    }
+
+    if board.must_collapse() {
+        let choices: Vec<String> = board
+            .legal_moves(&token)
+            .iter()
+            .filter_map(|choice| match *choice {
+                Collapse(cell) => Some((cell + 1).to_string()),
+                _ => None,
+            })
+            .collect();
+        view.append_with(&format!(
+            "[{}] ~ {}{}.",
+            name,
+            CollapseCycle.to_str(),
+            choices.join(" or ")
+        ));
+        return;
+    }
+
+    if board.is_quantum() && board.empty_cells().len() > 1 {
+        view.append_with(&format!("[{}] ~ {}", name, EntangleSpots.to_str()));
+        return;
+    }
+
+    if board.is_numerical() {
+        let pool: Vec<String> = board.pool(&token).iter().map(|n| n.to_string()).collect();
+        view.append_with(&format!(
+            "[{}] ~ {}{}{}{}{}{}).",
+            name,
+            PickSpot.to_str(),
+            board_length,
+            PickNumber.to_str(),
+            pool.join(" "),
+            NumberExample.to_str(),
+            pool[0]
+        ));
+        return;
+    }
+
+    if board.get_board_count() > 1 {
+        view.append_with(&format!("[{}] ~ {}", name, PickBoard.to_str()));
+        return;
+    }
+
+    if board.is_wild() {
+        view.append_with(&format!(
+            "[{}] ~ {}{}{}",
+            name,
+            PickSpot.to_str(),
+            board_length,
+            PickSymbol.to_str()
+        ));
+        return;
+    }
 
-    view.update_with(&presenter::view(&board, color));
     view.append_with(&format!(
-        "[Player {}] ~ {}{}.",
-        token.to_str(),
+        "[{}] ~ {}{}.",
+        name,
         PickSpot.to_str(),
         board_length
     ));
 }
 
+fn show_hint<W: Write>(hint: &Result<Vec<Analysis>, String>, view: &mut View<W>) {
+    let analyses = match *hint {
+        Ok(ref analyses) => analyses,
+        Err(ref message) => return view.append_with(&format!("{}{}", NoHint.to_str(), message)),
+    };
+
+    view.append_with(HintTitle.to_str());
+    for analysis in analyses {
+        let spot = match analysis.choice {
+            Place(cell) => (cell + 1).to_string(),
+            _ => continue,
+        };
+        view.append_with(&format!("    {}: {}", spot, explain(analysis)));
+    }
+}
+
+fn explain(analysis: &Analysis) -> String {
+    let reasons: Vec<&str> = analysis.reasons.iter().map(|reason| reason.to_str()).collect();
+
+    match reasons.is_empty() {
+        true => analysis.value.to_text(),
+        false => format!("{} ({})", analysis.value.to_text(), reasons.join(", ")),
+    }
+}
+
+/// Replays the human moves of a finished game with a perfect solver,
+/// flagging each move that gave away a win or a draw and the spot that kept it.
+pub fn show_review<W: Write>(game: &Game, view: &mut View<W>) {
+    let turns: Vec<Turn> = game
+        .get_turns()
+        .iter()
+        .filter(|turn| turn.human)
+        .cloned()
+        .collect();
+    let reviews = review::review(&turns);
+    if reviews.is_empty() {
+        return;
+    }
+
+    view.append_with(ReviewTitle.to_str());
+    for review in reviews.iter() {
+        let (spot, best) = match (review.choice, review.best.choice) {
+            (Place(spot), Place(best)) => (spot + 1, best + 1),
+            _ => continue,
+        };
+        let mut line = format!(
+            "    {}{}, {} on {}: {}",
+            ReviewMove.to_str(),
+            review.ply,
+            review.token.to_str(),
+            spot,
+            review.value.to_text()
+        );
+        if review.is_blunder() {
+            line = format!(
+                "{}{}{}{}{}{}{} {}",
+                line,
+                Blunder.to_str(),
+                review.best.value.outcome_str(),
+                Became.to_str(),
+                review.value.outcome_str(),
+                Better.to_str(),
+                best,
+                explain(&review.best)
+            );
+        }
+        view.append_with(&line);
+    }
+
+    if !reviews.iter().any(|review| review.is_blunder()) {
+        view.append_with(NoBlunders.to_str());
+    }
+}
+
 pub fn show_winner<W: Write>(game: &mut Game, view: &mut View<W>, color: &Color) {
     let board = game.get_board();
     let winner = game.get_winner();
 
     view.update_with(&presenter::view(&board, color));
 
+    if let Some(token) = game.get_flagged_token() {
+        view.append_with(&format!("{}{}", token.to_str(), RanOutOfTime.to_str()));
+    }
+
+    if let Some(token) = game.get_resigned_token() {
+        view.append_with(&format!("{}{}", token.to_str(), Resigns.to_str()));
+    }
+    if *game.get_state() == GameState::DrawAgreed {
+        view.append_with(DrawAgreed.to_str());
+    }
+
+    if let Some(role) = game.get_winning_role() {
+        view.append_with(&format!("{}{}", role.to_str(), Wins.to_str()));
+        return;
+    }
+
     match winner {
         &Empty => view.append_with(Draw.to_str()),
         _ => view.append_with(&format!("{}{}", winner.to_str(), Wins.to_str())),
     };
+
+    if board.is_quantum() {
+        view.append_with(&format!(
+            "{}X {} - O {}",
+            Points.to_str(),
+            rules::get_points(&Cross, board),
+            rules::get_points(&Nought, board)
+        ));
+    }
+}
+
+pub fn show_solution<W: Write>(problem: &Problem, solution: &Solution, view: &mut View<W>) {
+    let moves: Vec<String> = solution
+        .best_moves
+        .iter()
+        .map(|cell| (cell + 1).to_string())
+        .collect();
+
+    view.append_with(&format!(
+        "[{}x{}, {} in a row] {}{}.",
+        problem.size,
+        problem.size,
+        problem.win_length,
+        Solved.to_str(),
+        solution.outcome.to_str()
+    ));
+    view.append_with(&format!("{}{}", ProofSize.to_str(), solution.positions));
+    view.append_with(&format!("{}{}", BestMoves.to_str(), moves.join(" ")));
+}
+
+pub fn show_tablebase<W: Write>(problem: &Problem, tablebase: &Tablebase, view: &mut View<W>) {
+    let path = match problem.table {
+        Some(ref path) => path.display().to_string(),
+        None => String::new(),
+    };
+
+    view.append_with(&format!(
+        "[{}x{}, {} in a row] {}{}.",
+        problem.size,
+        problem.size,
+        problem.win_length,
+        TablebaseWritten.to_str(),
+        path
+    ));
+    view.append_with(&format!("{}{}", TablebaseSize.to_str(), tablebase.len()));
+}
+
+pub fn show_book<W: Write>(
+    problem: &Problem,
+    self_play: &SelfPlay,
+    recorded: Option<usize>,
+    book: &Book,
+    view: &mut View<W>,
+) {
+    let path = match problem.table {
+        Some(ref path) => path.display().to_string(),
+        None => String::new(),
+    };
+
+    view.append_with(&format!(
+        "[{}x{}, {} in a row] {}{}.",
+        problem.size,
+        problem.size,
+        problem.win_length,
+        BookWritten.to_str(),
+        path
+    ));
+    view.append_with(&format!("{}{}", SelfPlayGames.to_str(), self_play.games));
+    if let Some(recorded) = recorded {
+        view.append_with(&format!("{}{}", BookHistoryGames.to_str(), recorded));
+    }
+    view.append_with(&format!("{}{}", BookSize.to_str(), book.len()));
+}
+
+pub fn show_training<W: Write>(
+    problem: &Problem,
+    training: &Training,
+    record: &Record,
+    learner: &Menace,
+    view: &mut View<W>,
+) {
+    let path = match problem.table {
+        Some(ref path) => path.display().to_string(),
+        None => String::new(),
+    };
+
+    view.append_with(&format!(
+        "[{}x{}, {} in a row] {}{}.",
+        problem.size,
+        problem.size,
+        problem.win_length,
+        LearnedWritten.to_str(),
+        path
+    ));
+    view.append_with(&format!(
+        "{}{}: {}",
+        TrainingGames.to_str(),
+        training.opponent.to_str(),
+        training.games
+    ));
+    view.append_with(&format!(
+        "{}{} - {} - {}",
+        TrainingRecord.to_str(),
+        record.wins,
+        record.draws,
+        record.losses
+    ));
+    view.append_with(&format!("{}{}", LearnedSize.to_str(), learner.len()));
+}
+
+pub fn show_match_score<W: Write>(contest: &Match, view: &mut View<W>) {
+    view.append_with(&format!("{}{}", MatchScore.to_str(), match_score(contest)));
+}
+
+pub fn show_match_summary<W: Write>(contest: &Match, view: &mut View<W>) {
+    view.append_with(&format!(
+        "\n{}{}:",
+        MatchSummary.to_str(),
+        contest.get_format().to_text()
+    ));
+    for (number, result) in contest.get_results().iter().enumerate() {
+        let outcome = match result.winner {
+            Some(side) => format!("{}{}", side_name(side), Won.to_str()),
+            None => String::from(Drawn.to_str()),
+        };
+        view.append_with(&format!(
+            "    {}{}: {}{}{}",
+            GameNumber.to_str(),
+            number + 1,
+            side_name(result.starter),
+            Started.to_str(),
+            outcome
+        ));
+    }
+
+    let first = matches::points(contest.get_halves(Side::First));
+    let second = matches::points(contest.get_halves(Side::Second));
+    match contest.winner() {
+        Some(Side::First) => view.append_with(&format!(
+            "{}{}{} - {}.",
+            side_name(Side::First),
+            WinsMatch.to_str(),
+            first,
+            second
+        )),
+        Some(Side::Second) => view.append_with(&format!(
+            "{}{}{} - {}.",
+            side_name(Side::Second),
+            WinsMatch.to_str(),
+            second,
+            first
+        )),
+        None => view.append_with(&format!("{}{} - {}.", MatchDrawn.to_str(), first, second)),
+    }
+}
+
+pub fn next_game<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>) {
+    view.append_with(NextGame.to_str());
+    user_input.read_line();
+}
+
+pub fn show_tournament<W: Write>(tournament: &Tournament, report: &Report, view: &mut View<W>) {
+    let board = tournament.get_board();
+    let width = report.names.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+    let games: usize = report.scores.iter().map(|score| score.get_games()).sum();
+
+    view.append_with(&format!(
+        "[{}x{}, {} in a row] {}{}, {} games.",
+        board.get_row_size(),
+        board.get_row_size(),
+        board.get_win_length(),
+        TournamentPlayed.to_str(),
+        tournament.get_pairing().to_text(),
+        games / 2
+    ));
+
+    view.append_with(&format!("\n{}", Crosstable.to_str()));
+    let numbers: Vec<String> = (1..=report.names.len())
+        .map(|number| format!("{:>3}", number))
+        .collect();
+    view.append_with(&format!("    {:>2}  {:width$} {}", "", "", numbers.concat(), width = width));
+    for (entrant, row) in report.crosstable.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(opponent, halves)| match *halves {
+                _ if opponent == entrant => format!("{:>3}", "-"),
+                Some(halves) => format!("{:>3}", matches::points(halves)),
+                None => format!("{:>3}", "."),
+            })
+            .collect();
+        view.append_with(&format!(
+            "    {:>2}. {:width$} {}",
+            entrant + 1,
+            report.names[entrant],
+            cells.concat(),
+            width = width
+        ));
+    }
+
+    view.append_with(&format!("\n{}", Standings.to_str()));
+    for (place, &entrant) in report.standings().iter().enumerate() {
+        let score = &report.scores[entrant];
+        let byes = match score.byes {
+            0 => String::new(),
+            byes => format!(", {}{}", byes, Byes.to_str()),
+        };
+        view.append_with(&format!(
+            "    {:>2}. {:width$} {:>4}   {} - {} - {}{}",
+            place + 1,
+            report.names[entrant],
+            matches::points(report.get_halves(entrant)),
+            score.wins,
+            score.draws,
+            score.losses,
+            byes,
+            width = width
+        ));
+    }
+
+    view.append_with(&format!("\n{}", Pairings.to_str()));
+    for stats in report.pairings.iter() {
+        view.append_with(&format!(
+            "    {}{}: {} {} - {} {}{}{}{}{}{}{}{}{:.1}",
+            Round.to_str(),
+            stats.round,
+            report.names[stats.first],
+            stats.first_wins,
+            stats.second_wins,
+            report.names[stats.second],
+            PairingDraws.to_str(),
+            stats.draws,
+            CrossWon.to_str(),
+            stats.cross_wins,
+            NoughtWon.to_str(),
+            stats.nought_wins,
+            AveragePlies.to_str(),
+            Report::average_plies(stats)
+        ));
+    }
+}
+
+/// Shows how a rated game moved both players' Elo ratings.
+pub fn show_rating_changes<W: Write>(
+    names: &[String],
+    before: &(Rating, Rating),
+    registry: &Registry,
+    view: &mut View<W>,
+) {
+    let changes: Vec<String> = names
+        .iter()
+        .zip([before.0, before.1].iter())
+        .filter_map(|(name, before)| {
+            registry.get(name).map(|after| {
+                format!("{} {:.0} ({:+.0})", name, after.elo, after.elo - before.elo)
+            })
+        })
+        .collect();
+    view.append_with(&format!("{}{}", RatingChanges.to_str(), changes.join(", ")));
+}
+
+pub fn show_ratings_written<W: Write>(path: &Path, view: &mut View<W>) {
+    view.append_with(&format!("\n{}{}.", RatingsWritten.to_str(), path.display()));
+}
+
+pub fn show_leaderboard<W: Write>(
+    path: &Path,
+    system: System,
+    registry: &Registry,
+    view: &mut View<W>,
+) {
+    if registry.is_empty() {
+        view.append_with(NoRatings.to_str());
+        return;
+    }
+
+    let leaderboard = registry.leaderboard(system);
+    let width = leaderboard.iter().map(|&(name, _)| name.chars().count()).max().unwrap_or(0);
+    view.append_with(&format!(
+        "{}{}, {}:",
+        Leaderboard.to_str(),
+        system.to_str(),
+        path.display()
+    ));
+    for (place, &(name, rating)) in leaderboard.iter().enumerate() {
+        let value = match system {
+            System::Elo => format!("{:>6.0}", rating.elo),
+            System::Glicko => format!(
+                "{:>6.0} ± {:.0}",
+                rating.glicko.rating,
+                rating.glicko.deviation
+            ),
+        };
+        view.append_with(&format!(
+            "    {:>2}. {:width$} {}   {}{}{} - {} - {}",
+            place + 1,
+            name,
+            value,
+            rating.get_games(),
+            Games.to_str(),
+            rating.wins,
+            rating.draws,
+            rating.losses,
+            width = width
+        ));
+    }
+}
+
+pub fn show_history_written<W: Write>(path: &Path, view: &mut View<W>) {
+    view.append_with(&format!("{}{}.", HistoryWritten.to_str(), path.display()));
+}
+
+/// Names the lines of a history file that could not be read, if any.
+pub fn show_skipped_lines<W: Write>(path: &Path, history: &History, view: &mut View<W>) {
+    if history.get_skipped().is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = history.get_skipped().iter().map(usize::to_string).collect();
+    view.append_with(&format!(
+        "{}{}: {}.",
+        SkippedLines.to_str(),
+        path.display(),
+        lines.join(", ")
+    ));
+}
+
+/// Lists the recorded games a query picks, by number, with their totals.
+pub fn show_history<W: Write>(path: &Path, history: &History, query: &Query, view: &mut View<W>) {
+    let selected = history.select(query);
+    if selected.is_empty() {
+        view.append_with(NoHistory.to_str());
+        return;
+    }
+
+    view.append_with(&format!(
+        "{}{}: {} of {}",
+        HistoryGames.to_str(),
+        path.display(),
+        selected.len(),
+        history.get_records().len()
+    ));
+    for &(number, record) in selected.iter() {
+        let players: Vec<String> = record
+            .players
+            .iter()
+            .zip(record.tokens.iter())
+            .map(|(name, token)| format!("{} ({})", name, token.to_str()))
+            .collect();
+        view.append_with(&format!(
+            "    {:>4}. {}  {}  {}  {}  {} moves",
+            number,
+            record.date,
+            record.rules.to_spec(),
+            players.join(" - "),
+            record.result(),
+            record.moves.len()
+        ));
+    }
+
+    let count = |winner| selected.iter().filter(|&&(_, record)| record.winner == winner).count();
+    view.append_with(&format!(
+        "\n{}{} - {} - {}",
+        HistoryResults.to_str(),
+        count(Cross),
+        count(Nought),
+        count(Empty)
+    ));
+
+    if let Some(ref player) = query.player {
+        let tokens: Vec<(Token, Token)> = selected
+            .iter()
+            .filter_map(|&(_, record)| record.token_of(player).map(|token| (token, record.winner)))
+            .collect();
+        let wins = tokens.iter().filter(|&&(token, winner)| winner == token).count();
+        let draws = tokens.iter().filter(|&&(_, winner)| winner == Empty).count();
+        view.append_with(&format!(
+            "{}{}{} - {} - {}",
+            player,
+            HistoryRecord.to_str(),
+            wins,
+            draws,
+            tokens.len() - wins - draws
+        ));
+    }
+}
+
+/// Shows the position a replay has reached, with the move that led to it
+/// and, when the position can be analysed, the value of the best move and
+/// of the move played from it.
+pub fn show_replay<W: Write>(replay: &Replay, view: &mut View<W>, color: &Color) {
+    let record = replay.get_record();
+    let board = replay.get_board();
+    let players: Vec<String> = record
+        .players
+        .iter()
+        .zip(record.tokens.iter())
+        .map(|(name, token)| format!("{} ({})", name, token.to_str()))
+        .collect();
+
+    view.update_with(&presenter::view(board, color));
+    view.append_with(&format!(
+        "{}{}, {}, {}",
+        ReplayOf.to_str(),
+        players.join(" - "),
+        record.rules.to_spec(),
+        record.date
+    ));
+
+    let (ply, length) = (replay.get_ply(), replay.get_length());
+    let mut line = format!("{}{} of {}", ReviewMove.to_str(), ply, length);
+    if let Some((token, choice)) = replay.last_move() {
+        line = format!("{}: {} {}", line, token.to_str(), human::notation(&choice));
+    }
+    view.append_with(&line);
+
+    if let Some(token) = replay.to_move() {
+        if let Ok(analyses) = analysis::analyse(board, &token) {
+            let (_, played) = record.moves[replay.get_ply()];
+            if let Some(best) = analyses.first() {
+                let mut line = format!(
+                    "{}{}: {} {}",
+                    ReplayAnalysis.to_str(),
+                    token.to_str(),
+                    ReplayBest.to_str(),
+                    appraise(best)
+                );
+                let other = analyses.iter().skip(1).find(|analysis| analysis.choice == played);
+                if let Some(analysis) = other {
+                    line = format!("{}, {} {}", line, ReplayPlayed.to_str(), appraise(analysis));
+                }
+                view.append_with(&line);
+            }
+        }
+    }
+
+    if replay.is_over() {
+        match record.winner {
+            Empty => view.append_with(Draw.to_str()),
+            winner => view.append_with(&format!("{}{}", winner.to_str(), Wins.to_str())),
+        }
+    }
+    view.append_with(ReplayPrompt.to_str());
+}
+
+fn appraise(analysis: &Analysis) -> String {
+    format!("{} {}", human::notation(&analysis.choice), explain(analysis))
+}
+
+pub fn select_replay_action<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>) -> Action {
+    loop {
+        match Action::parse(&user_input.read_line()) {
+            Some(action) => return action,
+            None => view.append_with(&format!(
+                "{} {}",
+                InvalidSelection.to_str(),
+                ReplayPrompt.to_str()
+            )),
+        }
+    }
+}
+
+fn match_score(contest: &Match) -> String {
+    format!(
+        "{} {} - {} {}",
+        side_name(Side::First),
+        matches::points(contest.get_halves(Side::First)),
+        side_name(Side::Second),
+        matches::points(contest.get_halves(Side::Second))
+    )
+}
+
+fn side_name(side: Side) -> &'static str {
+    match side {
+        Side::First => PlayerOne.to_str(),
+        Side::Second => PlayerTwo.to_str(),
+    }
 }
 
 pub fn select_exit<I: Input, W: Write>(user_input: &mut I, view: &mut View<W>) -> bool {
@@ -57,11 +747,106 @@ pub fn goodbye<W: Write>(view: &mut View<W>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use board::Board;
     use board::tests::*;
+    use clock::TimeControl;
     use color::Color::Normal;
     use game::tests::*;
+    use matches::Format;
+    use opening::Opening;
+    use player::computer::Computer;
+    use player::human::Human;
+    use player::strategy::lazy::Lazy;
+    use std::time::Duration;
+    use solver::Outcome;
+    use token::Token::Number;
+    use tournament::{Entrant, Pairing};
     use ui::input::tests::MockInput;
 
+    #[test]
+    fn it_shows_a_tournament_report() {
+        let board = Board::new(3).update(4, Cross).update(0, Nought);
+        let entrants = vec![
+            Entrant::parse("best=unbeatable").expect("Invalid player"),
+            Entrant::parse("lazy").expect("Invalid player"),
+        ];
+        let tournament =
+            Tournament::new(board, entrants, Pairing::RoundRobin).expect("Invalid tournament");
+        let report = tournament.run();
+        let mut view = View::new(Vec::new());
+        show_tournament(&tournament, &report, &mut view);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains("[3x3, 3 in a row] Tournament played, round robin, 2 games."));
+        assert!(output.contains("     1. best   -"));
+        assert!(output.contains("     2. lazy   "));
+        assert!(output.contains("Round 1: best "));
+    }
+
+    #[test]
+    fn it_shows_the_leaderboard() {
+        let mut registry = Registry::new();
+        registry.record("ada", "computer:lazy", Some(0));
+        let mut view = View::new(Vec::new());
+        show_leaderboard(Path::new("ladder.txt"), System::Glicko, &registry, &mut view);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains("Leaderboard by Glicko-2, ladder.txt:"));
+        assert!(output.contains("     1. ada             1662 ± 290   1 games"));
+        assert!(output.contains("     2. computer:lazy   1338 ± 290"));
+    }
+
+    #[test]
+    fn it_lists_recorded_games_with_totals() {
+        let history = History::parse(
+            "2026-10-19 08:15:02\t1500\tstandard\tflat\tfree\t-\tada|bo\tX O\tX\t\
+             X:1 O:4 X:2 O:5 X:3\n\
+             2026-10-20 09:00:00\t900\tstandard\tflat\tfree\t-\tbo|ada\tX O\tO\t\
+             X:1 O:2 X:3 O:5 X:4 O:8\n\
+             2026-10-21 09:00:00\t900\tstandard\n",
+        );
+        let query = Query {
+            player: Some(String::from("ada")),
+            ..Query::default()
+        };
+        let mut view = View::new(Vec::new());
+        show_skipped_lines(Path::new("games.txt"), &history, &mut view);
+        show_history(Path::new("games.txt"), &history, &query, &mut view);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains("Unreadable lines skipped in games.txt: 3."));
+        assert!(output.contains("Games found in games.txt: 2 of 2"));
+        let first = "   1. 2026-10-19 08:15:02  standard  ada (X) - bo (O)  X  5 moves";
+        assert!(output.contains(first));
+        assert!(output.contains("X won - O won - drawn: 1 - 1 - 0"));
+        assert!(output.contains("ada won - drawn - lost: 2 - 0 - 0"));
+    }
+
+    #[test]
+    fn it_shows_a_replayed_position_with_its_analysis() {
+        let history = History::parse(
+            "2026-10-19 08:15:02\t1500\tstandard\tflat\tfree\t-\tada|bo\tX O\tX\t\
+             X:5 O:2 X:1 O:9 X:3 O:7 X:4 O:6 X:8\n",
+        );
+        let mut replay = Replay::new(history.get_records()[0].clone()).expect("Unplayable");
+        replay.step(Action::Jump(1));
+        let mut view = View::new(Vec::new());
+        show_replay(&replay, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains("Replay of ada (X) - bo (O), standard, 2026-10-19 08:15:02"));
+        assert!(output.contains("Move 1 of 9: X 5"));
+        assert!(output.contains("Analysis for O: best 1 draws, played 2 loses in 6"));
+        assert!(output.contains(ReplayPrompt.to_str()));
+
+        replay.step(Action::Jump(9));
+        let mut view = View::new(Vec::new());
+        show_replay(&replay, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("X wins!!!"));
+        assert!(!output.contains("Analysis for"));
+    }
+
     #[test]
     fn it_prompts_turn() {
         let board = create_patterned_board(3, (0..8).collect());
@@ -74,6 +859,234 @@ mod tests {
         assert!(output.contains("[Player X] ~ Pick an open spot between 1-9."));
     }
 
+    #[test]
+    fn it_shows_a_hint_with_values_and_reasons() {
+        let board = Board::new(3).update(0, Cross).update(3, Nought).update(1, Cross);
+        let player_one = Box::new(Computer::new(Cross, Lazy::new()));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["hint"])));
+        let mut game = Game::new(board, player_one, player_two);
+        let mut view = View::new(Vec::new());
+        game.next_turn();
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains(HintTitle.to_str()));
+        assert!(output.contains("    3: loses in 4 (blocks a win)"));
+        assert!(output.contains("    6: loses in 2\n"));
+        assert!(output.contains("[Player O] ~ Pick an open spot between 1-9."));
+    }
+
+    #[test]
+    fn it_reviews_human_moves_and_flags_blunders() {
+        let board = Board::new(3).update(0, Cross).update(4, Nought).update(8, Cross);
+        let player_one = Box::new(Computer::new(Cross, Lazy::new()));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["3"])));
+        let mut game = Game::new(board, player_one, player_two);
+        let mut view = View::new(Vec::new());
+        game.next_turn();
+        game.next_turn();
+        show_review(&game, &mut view);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains(ReviewTitle.to_str()));
+        assert!(output.contains(
+            "    Move 4, O on 3: loses in 4. Blunder: a draw became a loss. Better: 2 draws"
+        ));
+        assert!(!output.contains("Move 5"));
+        assert!(!output.contains(NoBlunders.to_str()));
+    }
+
+    #[test]
+    fn it_skips_the_review_without_human_moves() {
+        let mut game = setup_computer_vs_computer(Board::new(3));
+        let mut view = View::new(Vec::new());
+        game.next_turn();
+        show_review(&game, &mut view);
+
+        assert!(view.get_writer().is_empty());
+    }
+
+    #[test]
+    fn it_shows_the_clocks_and_a_loss_on_time() {
+        let control = TimeControl::parse("3").expect("Invalid time control");
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["1"])));
+        let player_two = Box::new(Computer::new(Nought, Lazy::new()));
+        let mut game = Game::new(Board::new(3), player_one, player_two).with_clock(control);
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("Time left: X 3:00 | O 3:00"));
+
+        let control = TimeControl::new().with_move_limit(Duration::from_secs(0));
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["1"])));
+        let player_two = Box::new(Computer::new(Nought, Lazy::new()));
+        let mut game = Game::new(Board::new(3), player_one, player_two).with_clock(control);
+        let mut view = View::new(Vec::new());
+        game.next_turn();
+        show_winner(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("X ran out of time."));
+        assert!(output.contains("O wins!!!"));
+    }
+
+    #[test]
+    fn it_shows_a_draw_offer_and_a_resignation() {
+        let player_one = Box::new(Human::new(Cross, MockInput::new(vec!["draw", "5"])));
+        let player_two = Box::new(Human::new(Nought, MockInput::new(vec!["resign"])));
+        let mut game = Game::new(Board::new(3), player_one, player_two);
+        let mut view = View::new(Vec::new());
+        game.next_turn();
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(!output.contains(DrawOffered.to_str()));
+
+        game.next_turn();
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("X offers a draw: type accept or decline, or just move."));
+
+        game.next_turn();
+        show_winner(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("O resigns."));
+        assert!(output.contains("X wins!!!"));
+    }
+
+    #[test]
+    fn it_shows_the_match_score_and_summary() {
+        let mut contest = Match::new(Format::BestOf(3));
+        contest.record(&Cross, &[Cross, Nought]);
+        contest.record(&Empty, &[Nought, Cross]);
+        contest.record(&Cross, &[Nought, Cross]);
+        let mut view = View::new(Vec::new());
+        show_match_score(&contest, &mut view);
+        show_match_summary(&contest, &mut view);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+
+        assert!(output.contains("Match score: Player 1 1½ - Player 2 1½"));
+        assert!(output.contains("Match summary, best of 3:"));
+        assert!(output.contains("    Game 1: Player 1 started, Player 1 won"));
+        assert!(output.contains("    Game 2: Player 2 started, drawn"));
+        assert!(output.contains("    Game 3: Player 1 started, Player 2 won"));
+        assert!(output.contains("The match is drawn 1½ - 1½."));
+    }
+
+    #[test]
+    fn it_prompts_slide_once_pieces_are_placed() {
+        let board = create_sliding_board(vec![0, 1, 5, 3, 7, 8]);
+        let mut game = setup_computer_vs_computer(board);
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[Player X] ~ Move one of your tokens to an adjacent open spot"));
+    }
+
+    #[test]
+    fn it_prompts_for_symbol_in_wild_game() {
+        let mut game = setup_computer_vs_computer(Board::wild(3));
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[Player X] ~ Pick an open spot between 1-9, then x or o (e.g. 5x)."));
+    }
+
+    #[test]
+    fn it_prompts_order_and_chaos_by_role() {
+        let mut game = setup_computer_vs_computer(Board::order_and_chaos(6, 5));
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[Order] ~ Pick an open spot between 1-36, then x or o (e.g. 5x)."));
+    }
+
+    #[test]
+    fn it_prompts_for_spooky_mark_then_collapse() {
+        let board = Board::quantum(3).entangle(0, 8, Cross);
+        let mut game = setup_computer_vs_computer(board.clone());
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[Player O] ~ Pick two open spots for your spooky mark (e.g. 3+7)."));
+
+        let mut game = setup_computer_vs_computer(board.entangle(8, 0, Nought));
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[Player X] ~ A cycle closed. Pick the spot where the last spooky mark lands: 9 or 1."));
+    }
+
+    #[test]
+    fn it_shows_points_after_quantum_game() {
+        let board = Board::quantum(3)
+            .entangle(0, 1, Cross)
+            .entangle(1, 3, Nought)
+            .entangle(3, 4, Cross)
+            .entangle(4, 6, Nought)
+            .entangle(6, 7, Cross)
+            .entangle(7, 0, Nought)
+            .collapse(7);
+        let mut game = setup_computer_vs_computer(board);
+        let mut view = View::new(Vec::new());
+        show_winner(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("X wins!!!"));
+        assert!(output.contains("Points: X 1 - O 0.5"));
+    }
+
+    #[test]
+    fn it_prompts_for_spot_and_number_from_pool() {
+        let board = Board::numerical(3).update(4, Number(5));
+        let mut game = setup_computer_vs_computer(board);
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains(
+            "[Player O] ~ Pick an open spot between 1-9, then one of your numbers 2 4 6 8 (e.g. 5=2)."
+        ));
+    }
+
+    #[test]
+    fn it_prompts_for_board_and_spot_in_notakto() {
+        let mut game = setup_computer_vs_computer(Board::notakto(3));
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[Player X] ~ Pick a board and an open spot on it (e.g. 2:5)."));
+    }
+
+    #[test]
+    fn it_prompts_opening_stones_then_colour_choice() {
+        let board = Board::gomoku(15, 5).with_opening(Opening::Swap2).update(112, Cross);
+        let mut game = setup_computer_vs_computer(board.clone());
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains(
+            "[First player] ~ Pick an open spot between 1-225 for the next opening stone: O."
+        ));
+
+        let mut game = setup_computer_vs_computer(board.update(113, Nought).update(96, Cross));
+        let mut view = View::new(Vec::new());
+        prompt_turn(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains(
+            "[Second player] ~ Choose your colour, x to play X or o to play O, or + to place one more stone of each and hand the choice back."
+        ));
+    }
+
+    #[test]
+    fn it_shows_winning_role() {
+        let mut board = Board::order_and_chaos(6, 5);
+        for i in [0, 7, 14, 21, 28] {
+            board = board.update(i, Nought);
+        }
+        let mut game = setup_computer_vs_computer(board);
+        let mut view = View::new(Vec::new());
+        show_winner(&mut game, &mut view, &Normal);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("Order wins!!!"));
+    }
+
     #[test]
     fn it_shows_winner() {
         let board = create_patterned_board(3, (0..9).collect());
@@ -98,6 +1111,22 @@ mod tests {
         assert!(output.contains("It's a draw"));
     }
 
+    #[test]
+    fn it_shows_solution_with_moves_numbered_from_one() {
+        let problem = Problem::new(4, 3);
+        let solution = Solution {
+            outcome: Outcome::FirstPlayerWin,
+            positions: 42,
+            best_moves: vec![0, 5],
+        };
+        let mut view = View::new(Vec::new());
+        show_solution(&problem, &solution, &mut view);
+        let output = String::from_utf8(view.get_writer().clone()).expect("Not UTF-8");
+        assert!(output.contains("[4x4, 3 in a row] With perfect play the first player wins."));
+        assert!(output.contains("Positions in the proof: 42"));
+        assert!(output.contains("Optimal first moves: 1 6"));
+    }
+
     #[test]
     fn it_relays_preference_to_play_again() {
         let input = vec!["n"];
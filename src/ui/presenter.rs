@@ -1,33 +1,187 @@
 use board::Board;
+use clock::Clock;
+use script::Script::Captures;
+use std::cmp;
+use std::time::Duration;
 use ui::color::Color;
-use token::Token::{self, Empty};
+use token::Token::{self, Cross, Empty, Nought};
 
 const NEW_LINE: &str = "\n";
 const PLUS: &str = "+";
-const SEGMENT: &str = "---";
+const DASH: &str = "-";
+const PADDING: usize = 2;
 const VBAR: &str = "|";
 const OFFSET: usize = 1;
+const BOARD_GAP: &str = "   ";
+const MARK_GAP: &str = " ";
+const SUBSCRIPT_ZERO: u32 = 0x2080;
+const SECONDS_PER_MINUTE: u64 = 60;
+const CLOCK_GAP: &str = " | ";
+const ESCAPE: char = '\x1B';
+const ESCAPE_END: char = 'm';
 
 pub fn view(board: &Board, color: &Color) -> String {
+    render(board, None, color)
+}
+
+pub fn view_turn(board: &Board, token: &Token, color: &Color) -> String {
+    render(board, board.vanishing_cell(token), color)
+}
+
+/// The time left for each seat, as in `X 2:58 | O 3:00`, or nothing when
+/// the clock only limits single moves.
+pub fn view_clock(clock: &Clock, tokens: &[Token]) -> String {
+    let times: Vec<String> = tokens
+        .iter()
+        .enumerate()
+        .filter_map(|(seat, token)| {
+            clock
+                .get_remaining(seat)
+                .map(|remaining| format!("{} {}", token.to_str(), minutes(remaining)))
+        })
+        .collect();
+
+    times.join(CLOCK_GAP)
+}
+
+fn minutes(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}", seconds / SECONDS_PER_MINUTE, seconds % SECONDS_PER_MINUTE)
+}
+
+fn render(board: &Board, vanishing: Option<usize>, color: &Color) -> String {
+    if board.get_board_count() > 1 {
+        return render_boards(board, color);
+    }
+
     let mut board_display = String::new();
+    let width = cell_width(board);
 
     for (i, cell) in board.get_cells().iter().enumerate() {
-        let token = determine_token(i, cell, color);
+        let spooky = spooky_label(board, i);
+        let token = match vanishing {
+            Some(oldest) if oldest == i => color.fill(&align(cell.to_str(), width)),
+            _ if *cell == Empty && !spooky.is_empty() => align(&spooky, width),
+            _ => determine_token(i, cell, width, color),
+        };
         let delimiter = match_cell_delimiter(i, board);
         board_display.push_str(&pad_sides(&token));
         board_display.push_str(&delimiter);
     }
 
+    if board.is_capturing() {
+        board_display.push_str(&render_captures(board));
+    }
+
     board_display
 }
 
-fn determine_token(index: usize, cell: &Token, color: &Color) -> String {
+fn render_captures(board: &Board) -> String {
+    format!(
+        "{}{}X {} - O {}{}",
+        NEW_LINE,
+        Captures.to_str(),
+        board.captured_pairs(&Cross),
+        board.captured_pairs(&Nought),
+        NEW_LINE
+    )
+}
+
+fn render_boards(board: &Board, color: &Color) -> String {
+    let views: Vec<Vec<String>> = board
+        .boards()
+        .iter()
+        .enumerate()
+        .map(|(index, sub_board)| {
+            let display = match board.is_dead_board(index) {
+                true => render_dead(sub_board, color),
+                false => render(sub_board, None, color),
+            };
+            display.lines().map(String::from).collect()
+        })
+        .collect();
+    let width = views[0].iter().map(|line| visible_width(line)).max().unwrap_or(0);
+    let headers: Vec<String> = (0..views.len())
+        .map(|index| format!("{:^width$}", format!("Board {}", index + OFFSET), width = width))
+        .collect();
+    let mut rows = vec![headers.join(BOARD_GAP)];
+
+    for line in 0..views[0].len() {
+        let row: Vec<&str> = views.iter().map(|view| view[line].as_str()).collect();
+        rows.push(row.join(BOARD_GAP));
+    }
+
+    format!("{}{}", rows.join(NEW_LINE), NEW_LINE)
+}
+
+/// The columns a line takes on screen: its characters less color escapes.
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut escaped = false;
+
+    for symbol in line.chars() {
+        match symbol {
+            ESCAPE => escaped = true,
+            ESCAPE_END if escaped => escaped = false,
+            _ if !escaped => width += 1,
+            _ => {}
+        }
+    }
+
+    width
+}
+
+fn render_dead(board: &Board, color: &Color) -> String {
+    let mut board_display = String::new();
+    let width = cell_width(board);
+
+    for (i, cell) in board.get_cells().iter().enumerate() {
+        board_display.push_str(&color.fill(&pad_sides(&align(cell.to_str(), width))));
+        board_display.push_str(&match_cell_delimiter(i, board));
+    }
+
+    board_display
+}
+
+fn determine_token(index: usize, cell: &Token, width: usize, color: &Color) -> String {
     match cell {
-        &Empty => color.fill(&format!("{}", index + OFFSET)),
-        _ => String::from(cell.to_str()),
+        &Empty => color.fill(&align(&format!("{}", index + OFFSET), width)),
+        _ => align(cell.to_str(), width),
     }
 }
 
+fn cell_width(board: &Board) -> usize {
+    let spooky_width = (0..board.get_length())
+        .map(|i| spooky_label(board, i).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    cmp::max(format!("{}", board.get_length()).len(), spooky_width)
+}
+
+fn spooky_label(board: &Board, index: usize) -> String {
+    let marks: Vec<String> = board
+        .spooky_marks_in(index)
+        .iter()
+        .map(|mark| format!("{}{}", mark.token.to_str(), subscript(mark.turn)))
+        .collect();
+
+    marks.join(MARK_GAP)
+}
+
+fn subscript(number: usize) -> String {
+    number
+        .to_string()
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .filter_map(|digit| char::from_u32(SUBSCRIPT_ZERO + digit))
+        .collect()
+}
+
+fn align(label: &str, width: usize) -> String {
+    format!("{:>width$}", label, width = width)
+}
+
 fn match_cell_delimiter(index: usize, board: &Board) -> String {
     let size = board.get_row_size();
     let divider = generate_divider(board);
@@ -42,7 +196,8 @@ fn match_cell_delimiter(index: usize, board: &Board) -> String {
 }
 
 fn generate_divider(board: &Board) -> String {
-    let divider = vec![SEGMENT; board.get_row_size()];
+    let segment = DASH.repeat(cell_width(board) + PADDING);
+    let divider = vec![segment; board.get_row_size()];
     format!("\n{}\n", divider.join(PLUS))
 }
 
@@ -54,7 +209,8 @@ fn pad_sides(token: &str) -> String {
 mod tests {
     use super::*;
     use board::tests::*;
-    use ui::color::Color::Normal;
+    use clock::TimeControl;
+    use ui::color::Color::{Dim, Normal};
 
     #[test]
     fn it_formats_size_3_board_to_string_view() {
@@ -70,4 +226,98 @@ mod tests {
 
         assert_eq!(expected, view(&board, &Normal));
     }
+
+    #[test]
+    fn it_aligns_cells_on_larger_boards() {
+        let board = Board::order_and_chaos(4, 3).update(9, Cross);
+        let display = view(&board, &Normal);
+        let lines: Vec<&str> = display.lines().collect();
+
+        assert_eq!("  1 |  2 |  3 |  4 ", lines[0]);
+        assert_eq!("----+----+----+----", lines[1]);
+        assert_eq!("  9 |  X | 11 | 12 ", lines[4]);
+        assert_eq!(" 13 | 14 | 15 | 16 ", lines[6]);
+    }
+
+    #[test]
+    fn it_renders_notakto_boards_side_by_side() {
+        let board = Board::notakto(2)
+            .update(0, Cross)
+            .update(4, Cross)
+            .update(8, Cross)
+            .update(13, Cross);
+        let display = view(&board, &Dim);
+        let lines: Vec<&str> = display.lines().collect();
+
+        assert_eq!("  Board 1       Board 2  ", lines[0]);
+        assert!(lines[1].starts_with("\x1B[2m X \x1B[0m|\x1B[2m   \x1B[0m|"));
+        assert!(lines[1].ends_with("   \x1B[2m1\x1B[0m | \x1B[2m2\x1B[0m | \x1B[2m3\x1B[0m "));
+        assert_eq!("---+---+---   ---+---+---", lines[2]);
+        assert_eq!(6, lines.len());
+        assert!(lines[3].ends_with(" X | \x1B[2m6\x1B[0m "));
+    }
+
+    #[test]
+    fn it_measures_lines_without_color_escapes() {
+        assert_eq!(11, visible_width(&format!("{}|{}", Dim.fill(" X "), Dim.fill(" 2 | 3 "))));
+        assert_eq!(3, visible_width(" X₁"));
+    }
+
+    #[test]
+    fn it_renders_spooky_marks_with_subscripts() {
+        let board = Board::quantum(3)
+            .entangle(0, 4, Cross)
+            .entangle(4, 8, Nought);
+        let display = view(&board, &Normal);
+        let lines: Vec<&str> = display.lines().collect();
+
+        assert_eq!("    X₁ |     2 |     3 ", lines[0]);
+        assert_eq!("-------+-------+-------", lines[1]);
+        assert_eq!("     4 | X₁ O₂ |     6 ", lines[2]);
+        assert_eq!("     7 |     8 |    O₂ ", lines[4]);
+    }
+
+    #[test]
+    fn it_shows_captured_pairs_under_the_board() {
+        let board = Board::pente(19, 5)
+            .update(0, Cross)
+            .update(1, Nought)
+            .update(2, Nought)
+            .update(3, Cross);
+        let output = view(&board, &Normal);
+
+        assert!(output.ends_with("\n\nCaptures: X 1 - O 0\n"));
+        assert!(!view(&Board::gomoku(15, 5), &Normal).contains("Captures"));
+    }
+
+    #[test]
+    fn it_dims_mark_about_to_vanish() {
+        let board = create_limited_board(3, vec![4, 0, 2, 6, 8, 1]);
+        let display = view_turn(&board, &Cross, &Dim);
+
+        assert!(display.contains(" \x1B[2mX\x1B[0m "));
+        assert_eq!(1, display.matches("\x1B[2mX").count());
+        assert_eq!(0, display.matches("\x1B[2mO").count());
+    }
+
+    #[test]
+    fn it_shows_turn_view_unchanged_below_limit() {
+        let board = create_patterned_board(3, vec![5, 6]);
+        assert_eq!(view(&board, &Dim), view_turn(&board, &Cross, &Dim));
+    }
+
+    #[test]
+    fn it_formats_the_time_left_for_each_seat() {
+        let mut clock = Clock::new(TimeControl::parse("3").expect("Invalid time control"), 2);
+        clock.punch(0, Duration::from_millis(1500));
+
+        assert_eq!("X 2:58 | O 3:00", view_clock(&clock, &[Cross, Nought]));
+    }
+
+    #[test]
+    fn it_shows_no_time_for_a_move_limit_alone() {
+        let control = TimeControl::new().with_move_limit(Duration::from_secs(5));
+
+        assert_eq!("", view_clock(&Clock::new(control, 2), &[Cross, Nought]));
+    }
 }
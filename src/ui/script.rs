@@ -1,12 +1,93 @@
 pub enum Script {
     Welcome,
     ModeSelection,
+    RoleSelection,
+    SeatSelection,
+    SeatOptions,
+    FirstPlayer,
+    SecondPlayer,
     PickSpot,
+    OpeningStone,
+    ChooseColour,
+    DeferChoice,
+    PickBoard,
+    PickNumber,
+    EntangleSpots,
+    CollapseCycle,
+    Points,
+    Captures,
+    NumberExample,
+    SlideToken,
+    PickSymbol,
     InvalidSelection,
     Draw,
     Wins,
     PlayAgain,
     Goodbye,
+    Solved,
+    ProofSize,
+    BestMoves,
+    TablebaseWritten,
+    TablebaseSize,
+    BookWritten,
+    SelfPlayGames,
+    BookSize,
+    LearnedWritten,
+    TrainingGames,
+    TrainingRecord,
+    LearnedSize,
+    HintTitle,
+    NoHint,
+    ReviewTitle,
+    ReviewMove,
+    Blunder,
+    Became,
+    Better,
+    NoBlunders,
+    TimeLeft,
+    RanOutOfTime,
+    DrawOffered,
+    Resigns,
+    DrawAgreed,
+    PlayerOne,
+    PlayerTwo,
+    MatchScore,
+    NextGame,
+    MatchSummary,
+    GameNumber,
+    Started,
+    Won,
+    Drawn,
+    WinsMatch,
+    MatchDrawn,
+    TournamentPlayed,
+    Crosstable,
+    Standings,
+    Byes,
+    Pairings,
+    Round,
+    PairingDraws,
+    CrossWon,
+    NoughtWon,
+    AveragePlies,
+    RatingChanges,
+    RatingsWritten,
+    Leaderboard,
+    NoRatings,
+    Games,
+    HistoryWritten,
+    HistoryGames,
+    NoHistory,
+    SkippedLines,
+    HistoryResults,
+    HistoryRecord,
+    BookHistoryGames,
+    ReplayOf,
+    ReplayAnalysis,
+    ReplayBest,
+    ReplayPlayed,
+    ReplayPrompt,
+    Usage,
 }
 
 impl Script {
@@ -21,6 +102,12 @@ impl Script {
                  \nwith your token and you win. But\
                  \nif you opponent does the same,\
                  \nthey win. So watch out!\
+                 \n\nType hint on your turn to see\
+                 \nhow every open spot plays out,\
+                 \nresign to give up, draw to offer\
+                 \na draw, accept or decline to answer\
+                 \none, or pass to skip a turn where\
+                 \nthe game allows it.\
                  \n\nPress [enter] to play."
             }
             Script::ModeSelection => {
@@ -31,7 +118,41 @@ impl Script {
                  \n    [4] computer vs. computer.
                  \nSelection:"
             }
+            Script::RoleSelection => {
+                "Select your role:\n\
+                 \n    [1] Order, moves first and wins with five in a row of either symbol,\
+                 \n    [2] Chaos, wins if the board fills up without five in a row.\
+                 \nSelection:"
+            }
+            Script::FirstPlayer => "First player",
+            Script::SecondPlayer => "Second player",
+            Script::SeatSelection => "Who plays ",
+            Script::SeatOptions => {
+                "?\n\
+                 \n    [1] a human,\
+                 \n    [2] the computer (easy),\
+                 \n    [3] the computer (hard).\
+                 \nSelection:"
+            }
             Script::PickSpot => "Pick an open spot between 1-",
+            Script::OpeningStone => " for the next opening stone: ",
+            Script::ChooseColour => "Choose your colour, x to play X or o to play O",
+            Script::DeferChoice => {
+                ", or + to place one more stone of each and hand the choice back"
+            }
+            Script::PickBoard => "Pick a board and an open spot on it (e.g. 2:5).",
+            Script::PickNumber => ", then one of your numbers ",
+            Script::NumberExample => " (e.g. 5=",
+            Script::EntangleSpots => "Pick two open spots for your spooky mark (e.g. 3+7).",
+            Script::CollapseCycle => {
+                "A cycle closed. Pick the spot where the last spooky mark lands: "
+            }
+            Script::Points => "Points: ",
+            Script::Captures => "Captures: ",
+            Script::PickSymbol => ", then x or o (e.g. 5x).",
+            Script::SlideToken => {
+                "Move one of your tokens to an adjacent open spot (e.g. 7-8)."
+            }
             Script::InvalidSelection => "Invalid selection.",
             Script::Draw => "It's a draw.",
             Script::Wins => " wins!!!",
@@ -41,6 +162,202 @@ impl Script {
                  \n| ~~~~~ Goodbye and thanks. ~~~~~ | \
                  \n+---------------------------------+\n"
             }
+            Script::Solved => "With perfect play ",
+            Script::ProofSize => "Positions in the proof: ",
+            Script::BestMoves => "Optimal first moves: ",
+            Script::TablebaseWritten => "Tablebase written to ",
+            Script::TablebaseSize => "Positions in the tablebase: ",
+            Script::BookWritten => "Opening book written to ",
+            Script::SelfPlayGames => "Self-play games: ",
+            Script::BookSize => "Positions in the book: ",
+            Script::LearnedWritten => "Learned table written to ",
+            Script::TrainingGames => "Training games against ",
+            Script::TrainingRecord => "Won - drawn - lost: ",
+            Script::LearnedSize => "Positions in the table: ",
+            Script::HintTitle => "Hint, from the best spot to the worst:",
+            Script::NoHint => "No hint: ",
+            Script::ReviewTitle => "Review of your moves:",
+            Script::ReviewMove => "Move ",
+            Script::Blunder => ". Blunder: ",
+            Script::Became => " became ",
+            Script::Better => ". Better: ",
+            Script::NoBlunders => "No blunders, well played.",
+            Script::TimeLeft => "Time left: ",
+            Script::RanOutOfTime => " ran out of time.",
+            Script::DrawOffered => " offers a draw: type accept or decline, or just move.",
+            Script::Resigns => " resigns.",
+            Script::DrawAgreed => "Draw agreed.",
+            Script::PlayerOne => "Player 1",
+            Script::PlayerTwo => "Player 2",
+            Script::MatchScore => "Match score: ",
+            Script::NextGame => "Press [enter] to start the next game.",
+            Script::MatchSummary => "Match summary, ",
+            Script::GameNumber => "Game ",
+            Script::Started => " started, ",
+            Script::Won => " won",
+            Script::Drawn => "drawn",
+            Script::WinsMatch => " wins the match ",
+            Script::MatchDrawn => "The match is drawn ",
+            Script::TournamentPlayed => "Tournament played, ",
+            Script::Crosstable => "Crosstable, points scored against each opponent:",
+            Script::Standings => "Standings, points and won - drawn - lost:",
+            Script::Byes => " byes",
+            Script::Pairings => "Pairings, one game with each colour:",
+            Script::Round => "Round ",
+            Script::PairingDraws => ", drawn ",
+            Script::CrossWon => ", X won ",
+            Script::NoughtWon => ", O won ",
+            Script::AveragePlies => ", plies per game ",
+            Script::RatingChanges => "Elo ratings: ",
+            Script::RatingsWritten => "Ratings written to ",
+            Script::Leaderboard => "Leaderboard by ",
+            Script::NoRatings => "No rated games yet.",
+            Script::Games => " games, won - drawn - lost ",
+            Script::HistoryWritten => "Game added to ",
+            Script::HistoryGames => "Games found in ",
+            Script::NoHistory => "No recorded games match.",
+            Script::SkippedLines => "Unreadable lines skipped in ",
+            Script::HistoryResults => "X won - O won - drawn: ",
+            Script::HistoryRecord => " won - drawn - lost: ",
+            Script::BookHistoryGames => "Recorded games: ",
+            Script::ReplayOf => "Replay of ",
+            Script::ReplayAnalysis => "Analysis for ",
+            Script::ReplayBest => "best",
+            Script::ReplayPlayed => "played",
+            Script::ReplayPrompt => {
+                "[enter] or n next move, p previous move, <number> jump to after that move, \
+                 a autoplay, q quit."
+            }
+            Script::Usage => {
+                "Usage: ttt_bin [--variant <name>] [--limit <marks>] [--boards <count>]\
+                 \n               [--topology <flat|torus>] [--layout <spec>]\
+                 \n               [--obstacles <count>] [--seed <number>]\
+                 \n               [--opening <free|swap|swap2>] [--tablebase <file>]\
+                 \n               [--book <file>] [--learned <file>]\
+                 \n               [--time <minutes[+seconds]>] [--move-time <seconds>]\
+                 \n               [--match <best-of-<games>|first-to-<points>>]\
+                 \n               [--ratings <file>] [--name <name>] [--name <name>]\
+                 \n               [--history <file>]\
+                 \n       ttt_bin solve [--size <spots>] [--k <length>] [--table <file>]\
+                 \n       ttt_bin tablebase [--size <spots>] [--k <length>] [--output <file>]\
+                 \n       ttt_bin book [--size <spots>] [--k <length>] [--output <file>]\
+                 \n                    [--games <count>] [--plies <count>] [--seed <number>]\
+                 \n                    [--history <file>]\
+                 \n       ttt_bin train [--size <spots>] [--k <length>] [--output <file>]\
+                 \n                     [--games <count>] [--opponent <self|lazy|unbeatable>]\
+                 \n                     [--seed <number>]\
+                 \n       ttt_bin tournament --player <spec> --player <spec> [...]\
+                 \n                          [--pairing <round-robin|swiss>] [--rounds <count>]\
+                 \n                          [--size <spots>] [--k <length>] [--ratings <file>]\
+                 \n                          [--history <file>]\
+                 \n       ttt_bin ratings [--ratings <file>] [--system <elo|glicko>]\
+                 \n       ttt_bin history [--history <file>] [--player <name>]\
+                 \n                       [--result <x|o|draw|win|loss>] [--opening <moves>]\
+                 \n                       [--date <yyyy-mm-dd>]\
+                 \n       ttt_bin replay [--history <file>] [--game <number>]\
+                 \n                      [--delay <milliseconds>]\n\
+                 \nVariants:\
+                 \n    standard       classic tic tac toe (default),\
+                 \n    three-marks    each player keeps at most <marks> marks (default 3),\
+                 \n                   placing another removes that player's oldest mark,\
+                 \n    morris         each player places <marks> tokens (default 3),\
+                 \n                   then slides them to adjacent open spots,\
+                 \n    wild           either player may place an X or an O; whoever\
+                 \n                   completes a line of three identical symbols wins,\
+                 \n    order-and-chaos\
+                 \n                   6x6 board where either player may place an X or an O;\
+                 \n                   Order wants five in a row, Chaos wants to stop it,\
+                 \n    numerical      one player places the odd numbers 1-9, the other the\
+                 \n                   even ones, each used once; a full line summing to 15 wins,\
+                 \n    notakto        both players place X on <count> boards (default 3);\
+                 \n                   a board with three in a row is dead and whoever\
+                 \n                   kills the last board loses,\
+                 \n    quantum        each move puts a spooky mark in two spots; when the\
+                 \n                   marks form a cycle the opponent chooses how it collapses,\
+                 \n    gomoku         15x15 board where five or more in a row wins,\
+                 \n    pente          19x19 board where five in a row or five captured pairs\
+                 \n                   wins; flank two opposing stones with your own to\
+                 \n                   capture them,\
+                 \n    three-player   X, O and Δ take turns on a 4x4 board; three in a row\
+                 \n                   wins, and you choose who plays each token.\n\
+                 \nTopologies:\
+                 \n    flat           lines stop at the edges of the board (default),\
+                 \n    torus          rows, columns and diagonals wrap around the edges.\n\
+                 \nOpenings (gomoku and pente):\
+                 \n    free           X simply moves first (default),\
+                 \n    swap           the first player places two X and one O, then the\
+                 \n                   second player chooses a colour; O moves next,\
+                 \n    swap2          as swap, but the second player may instead place one\
+                 \n                   more stone of each and let the first player choose.\n\
+                 \nClocks:\
+                 \n    --time gives each player <minutes> for the whole game, with\
+                 \n    <seconds> added after every move (e.g. 3+2); --move-time limits\
+                 \n    every single move. A player who runs out of time loses, and the\
+                 \n    computer thinks less when its time runs short.\n\
+                 \nMatches:\
+                 \n    --match plays games between the same two players until one has\
+                 \n    won more than half of <games>, or first reaches <points>; a draw\
+                 \n    is worth half a point and the first move alternates each game.\n\
+                 \nSolve:\
+                 \n    proves whether the first player wins, draws or loses on an empty\
+                 \n    <spots>x<spots> board (default 3) with <length> in a row (default\
+                 \n    <spots>); --table keeps solved positions in <file> for later runs.\n\
+                 \nTablebase:\
+                 \n    stores the value and best moves of every position reachable on the\
+                 \n    same board in <file> (default <spots>x<spots>k<length>.tb); playing\
+                 \n    with --tablebase <file> makes the hard computer look its moves up.\n\
+                 \nOpening books:\
+                 \n    book plays <count> self-play games (default 100), random for the first\
+                 \n    <count> plies (default 4), and adds the points each opening move\
+                 \n    scored to <file> (default <spots>x<spots>k<length>.book). Books are\
+                 \n    plain text and may be edited; playing with --book <file> makes the\
+                 \n    hard computer pick booked moves by weight before searching. With\
+                 \n    --history the openings of the recorded games on the same board are\
+                 \n    added too, and self-play only runs when --games is given.\n\
+                 \nTraining:\
+                 \n    train teaches a matchbox learner (MENACE) by playing <count> games\
+                 \n    (default 1000) against itself, random moves or the unbeatable\
+                 \n    computer, and keeps its bead counts in <file> (default\
+                 \n    <spots>x<spots>k<length>.menace) so later runs continue from them;\
+                 \n    playing with --learned <file> makes the hard computer the learner.\n\
+                 \nTournaments:\
+                 \n    tournament plays every pairing of computer players twice, once with\
+                 \n    each colour, and prints a crosstable, standings and pairing stats.\
+                 \n    A <spec> is [<name>=]<strategy>[@<milliseconds>], where the strategy\
+                 \n    is lazy, greedy, unbeatable, learned:<file> or tablebase:<file> and\
+                 \n    the milliseconds cap its thinking time per move; unbeatable needs\
+                 \n    them on boards over 9 spots. Round robin meets everyone once; swiss\
+                 \n    plays <count> rounds (default enough to single out a winner) between\
+                 \n    players on similar scores, with no rematches while fresh opponents\
+                 \n    remain and a bye worth a drawn pairing.\n\
+                 \nRatings:\
+                 \n    --ratings rates every finished two-player game in <file> with both\
+                 \n    Elo and Glicko-2. Humans are rated under the names given with --name\
+                 \n    in seat order, or as guest; computers under their strategy, such as\
+                 \n    computer:unbeatable@50, so a tournament refuses to rate two players\
+                 \n    with the same strategy. ratings lists everyone in <file> (default\
+                 \n    ratings.txt) from the highest rated down.\n\
+                 \nGame history:\
+                 \n    --history adds every finished game, with its players, rules, moves,\
+                 \n    result, length and date, to the end of <file>, one line per game.\
+                 \n    history lists the games in <file> (default history.txt) that match\
+                 \n    every filter given: a player, a result (win and loss are for that\
+                 \n    player), the first moves as typed (e.g. \"5 1\") or the start of\
+                 \n    the date (e.g. 2026-10), with totals for what it finds. Lines that\
+                 \n    cannot be read are skipped and their numbers reported.\n\
+                 \nReplays:\
+                 \n    replay steps through game <number> in <file> (default the last game\
+                 \n    in history.txt): enter shows the next move, p the previous one, a\
+                 \n    move number the position after it, and a plays the rest with\
+                 \n    <milliseconds> between moves (default 1000). Positions small enough\
+                 \n    to solve show the best move and the value of the move played.\n\
+                 \nLayouts:\
+                 \n    <spec> lists every spot row by row: . open, # blocked, x or o for\
+                 \n    a handicap stone, with optional / between rows (e.g. #../.o./...).\
+                 \n    The player with fewer handicap stones moves first. --obstacles\
+                 \n    blocks that many random open spots, the same ones for the same\
+                 \n    --seed."
+            }
         }
     }
 }
@@ -0,0 +1,177 @@
+use board::{Board, Limit};
+
+const SIZE: usize = 3;
+const DEFAULT_LIMIT: usize = 3;
+const ORDER_AND_CHAOS_SIZE: usize = 6;
+const ORDER_AND_CHAOS_LINE: usize = 5;
+const DEFAULT_BOARDS: usize = 3;
+const GOMOKU_SIZE: usize = 15;
+const GOMOKU_LINE: usize = 5;
+const PENTE_SIZE: usize = 19;
+const THREE_PLAYER_SIZE: usize = 4;
+const THREE_PLAYER_LINE: usize = 3;
+const THREE_PLAYERS: usize = 3;
+const PARAMETER_SEPARATOR: char = ':';
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Variant {
+    Standard,
+    ThreeMarks(usize),
+    Morris(usize),
+    Wild,
+    OrderAndChaos,
+    Notakto(usize),
+    Numerical,
+    Quantum,
+    Gomoku,
+    Pente,
+    ThreePlayer,
+}
+
+impl Variant {
+    pub fn from_name(name: &str, limit: Option<usize>, boards: Option<usize>) -> Option<Variant> {
+        match name {
+            "standard" => Some(Variant::Standard),
+            "three-marks" => Some(Variant::ThreeMarks(limit.unwrap_or(DEFAULT_LIMIT))),
+            "morris" => Some(Variant::Morris(limit.unwrap_or(DEFAULT_LIMIT))),
+            "wild" => Some(Variant::Wild),
+            "order-and-chaos" => Some(Variant::OrderAndChaos),
+            "numerical" => Some(Variant::Numerical),
+            "quantum" => Some(Variant::Quantum),
+            "gomoku" => Some(Variant::Gomoku),
+            "pente" => Some(Variant::Pente),
+            "three-player" => Some(Variant::ThreePlayer),
+            "notakto" => Some(Variant::Notakto(boards.unwrap_or(DEFAULT_BOARDS))),
+            _ => None,
+        }
+    }
+
+    /// Reads a name as written by `to_spec`, with its limit or board count.
+    pub fn from_spec(spec: &str) -> Option<Variant> {
+        let mut parts = spec.splitn(2, PARAMETER_SEPARATOR);
+        let name = parts.next().unwrap_or("");
+        let parameter = match parts.next() {
+            Some(parameter) => Some(parameter.parse().ok()?),
+            None => None,
+        };
+
+        Variant::from_name(name, parameter, parameter)
+    }
+
+    /// The variant's name, followed by its limit or board count when it
+    /// has one, as in `three-marks:4`.
+    pub fn to_spec(&self) -> String {
+        match *self {
+            Variant::Standard => String::from("standard"),
+            Variant::ThreeMarks(limit) => format!("three-marks{}{}", PARAMETER_SEPARATOR, limit),
+            Variant::Morris(pieces) => format!("morris{}{}", PARAMETER_SEPARATOR, pieces),
+            Variant::Wild => String::from("wild"),
+            Variant::OrderAndChaos => String::from("order-and-chaos"),
+            Variant::Notakto(boards) => format!("notakto{}{}", PARAMETER_SEPARATOR, boards),
+            Variant::Numerical => String::from("numerical"),
+            Variant::Quantum => String::from("quantum"),
+            Variant::Gomoku => String::from("gomoku"),
+            Variant::Pente => String::from("pente"),
+            Variant::ThreePlayer => String::from("three-player"),
+        }
+    }
+
+    pub fn board(&self) -> Board {
+        match *self {
+            Variant::Standard => Board::new(SIZE),
+            Variant::ThreeMarks(limit) => Board::with_limit(SIZE, Limit::Vanish(limit)),
+            Variant::Morris(pieces) => Board::with_limit(SIZE, Limit::Slide(pieces)),
+            Variant::Wild => Board::wild(SIZE),
+            Variant::OrderAndChaos => {
+                Board::order_and_chaos(ORDER_AND_CHAOS_SIZE, ORDER_AND_CHAOS_LINE)
+            }
+            Variant::Notakto(boards) => Board::notakto(boards),
+            Variant::Numerical => Board::numerical(SIZE),
+            Variant::Quantum => Board::quantum(SIZE),
+            Variant::Gomoku => Board::gomoku(GOMOKU_SIZE, GOMOKU_LINE),
+            Variant::Pente => Board::pente(PENTE_SIZE, GOMOKU_LINE),
+            Variant::ThreePlayer => {
+                Board::gomoku(THREE_PLAYER_SIZE, THREE_PLAYER_LINE)
+                    .with_players(THREE_PLAYERS)
+                    .with_passing()
+            }
+        }
+    }
+
+    pub fn has_roles(&self) -> bool {
+        *self == Variant::OrderAndChaos
+    }
+
+    pub fn has_openings(&self) -> bool {
+        *self == Variant::Gomoku || *self == Variant::Pente
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::Variant::*;
+
+    #[test]
+    fn it_finds_variant_by_name() {
+        assert_eq!(Some(Standard), Variant::from_name("standard", None, None));
+        assert_eq!(Some(ThreeMarks(3)), Variant::from_name("three-marks", None, None));
+        assert_eq!(Some(ThreeMarks(4)), Variant::from_name("three-marks", Some(4), None));
+        assert_eq!(Some(Morris(3)), Variant::from_name("morris", None, None));
+        assert_eq!(Some(Wild), Variant::from_name("wild", None, None));
+        assert_eq!(Some(OrderAndChaos), Variant::from_name("order-and-chaos", None, None));
+        assert_eq!(Some(Numerical), Variant::from_name("numerical", None, None));
+        assert_eq!(Some(Quantum), Variant::from_name("quantum", None, None));
+        assert_eq!(Some(Gomoku), Variant::from_name("gomoku", None, None));
+        assert_eq!(Some(Pente), Variant::from_name("pente", None, None));
+        assert_eq!(Some(ThreePlayer), Variant::from_name("three-player", None, None));
+        assert_eq!(Some(Notakto(3)), Variant::from_name("notakto", None, None));
+        assert_eq!(Some(Notakto(2)), Variant::from_name("notakto", None, Some(2)));
+        assert_eq!(None, Variant::from_name("four-marks", None, None));
+    }
+
+    #[test]
+    fn it_reads_back_the_spec_it_writes() {
+        for variant in [Standard, ThreeMarks(4), Morris(3), Notakto(2), Pente, ThreePlayer].iter() {
+            assert_eq!(Some(*variant), Variant::from_spec(&variant.to_spec()));
+        }
+        assert_eq!("three-marks:4", ThreeMarks(4).to_spec());
+        assert_eq!(None, Variant::from_spec("notakto:x"));
+    }
+
+    #[test]
+    fn it_creates_board_for_variant() {
+        assert_eq!(Board::new(3), Standard.board());
+        assert_eq!(Some(Limit::Vanish(2)), ThreeMarks(2).board().get_limit());
+        assert_eq!(Some(Limit::Slide(3)), Morris(3).board().get_limit());
+        assert!(Wild.board().is_wild());
+        assert_eq!(5, OrderAndChaos.board().get_win_length());
+        assert_eq!(2, Notakto(2).board().get_board_count());
+        assert!(Numerical.board().is_numerical());
+        assert!(Quantum.board().is_quantum());
+        assert_eq!(225, Gomoku.board().get_length());
+        assert!(Pente.board().is_capturing());
+        assert_eq!(3, ThreePlayer.board().get_player_count());
+    }
+
+    #[test]
+    fn it_lets_only_three_player_games_pass() {
+        assert!(ThreePlayer.board().allows_pass());
+        assert!(!Morris(3).board().allows_pass());
+        assert!(!Standard.board().allows_pass());
+        assert!(!Gomoku.board().allows_pass());
+    }
+
+    #[test]
+    fn it_informs_if_variant_has_roles() {
+        assert!(OrderAndChaos.has_roles());
+        assert!(!Wild.has_roles());
+    }
+
+    #[test]
+    fn it_informs_if_variant_has_opening_rules() {
+        assert!(Gomoku.has_openings());
+        assert!(Pente.has_openings());
+        assert!(!OrderAndChaos.has_openings());
+    }
+}
@@ -4,6 +4,8 @@ extern crate ttt_lib;
 #[cfg(test)]
 mod tests {
     use assert_cli;
+    use std::env;
+    use std::fs;
     use ttt_lib::board::Board;
     use ttt_lib::token::Token::*;
     use ttt_lib::ui::color::Color::Dim;
@@ -82,4 +84,484 @@ mod tests {
             .contains(Goodbye.to_str())
             .unwrap();
     }
+
+    #[test]
+    fn it_runs_three_marks_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "three-marks"])
+            .stdin("\n4\nn\n")
+            .stdout()
+            .contains("[Player X] ~ Pick an open spot between 1-9.")
+            .stdout()
+            .contains(Goodbye.to_str())
+            .unwrap();
+    }
+
+    #[test]
+    fn it_runs_morris_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "morris"])
+            .stdin("\n1\n1\n2\n4\n5\n9\n7\n9-6\n2-3\nn\n")
+            .stdout()
+            .contains(format!("[Player X] ~ {}", SlideToken.to_str()).as_str())
+            .stdout()
+            .contains("O wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_runs_wild_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "wild"])
+            .stdin("\n1\n1o\n2o\n3o\nn\n")
+            .stdout()
+            .contains("[Player O] ~ Pick an open spot between 1-9, then x or o (e.g. 5x).")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_runs_numerical_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "numerical"])
+            .stdin("\n1\n1=9\n4=2\n2=1\n5=4\n3=5\nn\n")
+            .stdout()
+            .contains("[Player O] ~ Pick an open spot between 1-9, then one of your numbers 2 4 6 8 (e.g. 5=2).")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_runs_quantum_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "quantum"])
+            .stdin("\n1\n1+2\n1+2\n1\n4+5\n4+5\n4\n7+8\n7+8\n7\nn\n")
+            .stdout()
+            .contains("[Player X] ~ A cycle closed. Pick the spot where the last spooky mark lands: 1 or 2.")
+            .stdout()
+            .contains("X wins!!!")
+            .stdout()
+            .contains("Points: X 1 - O 0.5")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_runs_notakto_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "notakto", "--boards", "2"])
+            .stdin("\n1\n1:1\n1:2\n1:3\n2:1\n2:2\n2:3\nn\n")
+            .stdout()
+            .contains("[Player O] ~ Pick a board and an open spot on it (e.g. 2:5).")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_runs_order_and_chaos_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "order-and-chaos"])
+            .stdin("\n4\nn\n")
+            .stdout()
+            .contains("[Order] ~ Pick an open spot between 1-36, then x or o (e.g. 5x).")
+            .stdout()
+            .contains("[Chaos] ~ Pick an open spot between 1-36, then x or o (e.g. 5x).")
+            .stdout()
+            .contains(" wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_wins_across_the_edge_of_a_torus() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--topology", "torus"])
+            .stdin("\n1\n2\n1\n6\n3\n7\nn\n")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_starts_with_blocked_cells_and_handicap_stones() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--layout", "#../.o./..."])
+            .stdin("\n1\n3\n2\n6\n8\nn\n")
+            .stdout()
+            .contains(" # |")
+            .stdout()
+            .contains("O wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_lets_the_second_player_choose_a_colour_after_a_swap_opening() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "gomoku", "--opening", "swap"])
+            .stdin("\n1\n1\n16\n2\nx\n17\n3\n18\n4\n19\n5\nn\n")
+            .stdout()
+            .contains("[First player] ~ Pick an open spot between 1-225 for the next opening stone: O.")
+            .stdout()
+            .contains("[Second player] ~ Choose your colour, x to play X or o to play O.")
+            .stdout()
+            .contains("[Player O] ~ Pick an open spot between 1-225.")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_counts_captured_pairs_in_pente() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "pente"])
+            .stdin("\n1\n1\n2\n20\n3\n4\n100\n2\n101\n3\n102\n5\nn\n")
+            .stdout()
+            .contains("Captures: X 1 - O 0")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_rotates_turns_between_three_players() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "three-player"])
+            .stdin("\n1\n1\n1\n1\n5\n9\n2\n6\n10\n3\nn\n")
+            .stdout()
+            .contains("Who plays Δ?")
+            .stdout()
+            .contains("[Player Δ] ~ Pick an open spot between 1-16.")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_gives_a_hint_without_using_the_turn() {
+        assert_cli::Assert::main_binary()
+            .stdin("\n1\n1\n4\n2\nhint\n3\n5\n7\n9\n6\n8\nn\n")
+            .stdout()
+            .contains(HintTitle.to_str())
+            .stdout()
+            .contains("    3: loses in 4 (blocks a win)")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_reviews_the_human_moves_after_the_game() {
+        assert_cli::Assert::main_binary()
+            .stdin("\n1\n1\n4\n2\nhint\n3\n5\n7\n9\n6\n8\nn\n")
+            .stdout()
+            .contains(ReviewTitle.to_str())
+            .stdout()
+            .contains("    Move 2, O on 4: loses in 6. Blunder: a draw became a loss. Better: 5 draws")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_loses_on_time() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--time", "0"])
+            .stdin("\n1\n5\nn\n")
+            .stdout()
+            .contains("X ran out of time.")
+            .stdout()
+            .contains("O wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_shows_the_time_left_in_a_timed_game() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--time", "5+2"])
+            .stdin("\n4\nn\n")
+            .stdout()
+            .contains("Time left: X 5:00 | O 5:00")
+            .stdout()
+            .contains("It's a draw.")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_ends_the_game_on_a_resignation() {
+        assert_cli::Assert::main_binary()
+            .stdin("\n1\n5\nresign\nn\n")
+            .stdout()
+            .contains("O resigns.")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_agrees_a_draw() {
+        assert_cli::Assert::main_binary()
+            .stdin("\n1\ndraw\n5\naccept\nn\n")
+            .stdout()
+            .contains("X offers a draw")
+            .stdout()
+            .contains("Draw agreed.")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_plays_a_match_alternating_the_first_move() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--match", "best-of-2"])
+            .stdin("\n1\n1\n4\n2\n5\n3\n\n5\n1\n9\nresign\nn\n")
+            .stdout()
+            .contains("Match score: Player 1 1 - Player 2 0")
+            .stdout()
+            .contains("    Game 2: Player 2 started, Player 2 won")
+            .stdout()
+            .contains("The match is drawn 1 - 1.")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_solves_an_m_n_k_game() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["solve", "--size", "4", "--k", "3"])
+            .stdout()
+            .contains("[4x4, 3 in a row] With perfect play the first player wins.")
+            .stdout()
+            .contains("Positions in the proof: ")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_plays_from_a_generated_tablebase() {
+        let path = env::temp_dir().join("ttt_runner_test_3x3k3.tb");
+        let file = path.to_str().expect("Not UTF-8");
+
+        assert_cli::Assert::main_binary()
+            .with_args(&["tablebase", "--output", file])
+            .stdout()
+            .contains("[3x3, 3 in a row] Tablebase written to ")
+            .stdout()
+            .contains("Positions in the tablebase: 627")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["--tablebase", file])
+            .stdin("\n4\nn\n")
+            .stdout()
+            .contains(Draw.to_str())
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["--tablebase", file, "--variant", "wild"])
+            .fails()
+            .unwrap();
+
+        fs::remove_file(path).expect("Undeletable tablebase");
+    }
+
+    #[test]
+    fn it_plays_from_an_opening_book_built_by_self_play() {
+        let path = env::temp_dir().join("ttt_runner_test_3x3k3.book");
+        let file = path.to_str().expect("Not UTF-8");
+
+        assert_cli::Assert::main_binary()
+            .with_args(&["book", "--games", "20", "--seed", "5", "--output", file])
+            .stdout()
+            .contains("[3x3, 3 in a row] Opening book written to ")
+            .stdout()
+            .contains("Self-play games: 20")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["--book", file])
+            .stdin("\n4\nn\n")
+            .stdout()
+            .contains(PlayAgain.to_str())
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["--book", file, "--variant", "gomoku"])
+            .fails()
+            .unwrap();
+
+        fs::remove_file(path).expect("Undeletable book");
+    }
+
+    #[test]
+    fn it_trains_a_learner_and_plays_against_it() {
+        let path = env::temp_dir().join("ttt_runner_test_3x3k3.menace");
+        let file = path.to_str().expect("Not UTF-8");
+
+        assert_cli::Assert::main_binary()
+            .with_args(&["train", "--games", "200", "--seed", "8", "--output", file])
+            .stdout()
+            .contains("Training games against itself: 200")
+            .stdout()
+            .contains("Learned table written to ")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["--learned", file])
+            .stdin("\n4\nn\n")
+            .stdout()
+            .contains(PlayAgain.to_str())
+            .unwrap();
+
+        fs::remove_file(path).expect("Undeletable learned table");
+    }
+
+    #[test]
+    fn it_runs_a_tournament_between_computer_players() {
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "tournament", "--player", "lazy", "--player", "greedy", "--player",
+                "quick=unbeatable@20",
+            ])
+            .stdout()
+            .contains("[3x3, 3 in a row] Tournament played, round robin, 6 games.")
+            .stdout()
+            .contains("Crosstable, points scored against each opponent:")
+            .stdout()
+            .contains("Round 3: ")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["tournament", "--player", "lazy", "--player", "mcts"])
+            .fails()
+            .stdout()
+            .contains("Unknown player mcts.")
+            .unwrap();
+    }
+
+    #[test]
+    fn it_rates_games_and_lists_the_leaderboard() {
+        let path = env::temp_dir().join("ttt_runner_test_ratings.txt");
+        let file = path.to_str().expect("Not UTF-8");
+        let _ = fs::remove_file(&path);
+
+        assert_cli::Assert::main_binary()
+            .with_args(&["--ratings", file, "--name", "ada", "--name", "bo"])
+            .stdin("\n1\n1\n4\n2\n5\n3\nn\n")
+            .stdout()
+            .contains("Elo ratings: ada 1516 (+16), bo 1484 (-16)")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "tournament", "--player", "lazy", "--player", "greedy", "--ratings", file,
+            ])
+            .stdout()
+            .contains("Ratings written to ")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["ratings", "--ratings", file])
+            .stdout()
+            .contains("Leaderboard by Elo, ")
+            .stdout()
+            .contains("computer:greedy")
+            .stdout()
+            .contains("1484   1 games, won - drawn - lost 0 - 0 - 1")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "tournament", "--player", "a=lazy", "--player", "b=lazy", "--ratings", file,
+            ])
+            .fails()
+            .stdout()
+            .contains("The players a and b are both rated as computer:lazy.")
+            .unwrap();
+
+        fs::remove_file(path).expect("Undeletable ratings");
+    }
+
+    #[test]
+    fn it_keeps_a_history_of_games_and_queries_it() {
+        let path = env::temp_dir().join("ttt_runner_test_history.txt");
+        let file = path.to_str().expect("Not UTF-8");
+        let book = env::temp_dir().join("ttt_runner_test_history_3x3k3.book");
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_file(&book);
+
+        assert_cli::Assert::main_binary()
+            .with_args(&["--history", file, "--name", "ada", "--name", "bo"])
+            .stdin("\n1\n1\n4\n2\n5\n3\nn\n")
+            .stdout()
+            .contains("Game added to ")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "tournament", "--player", "lazy", "--player", "greedy", "--history", file,
+            ])
+            .stdout()
+            .contains("Game added to ")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["history", "--history", file, "--player", "ada", "--result", "win"])
+            .stdout()
+            .contains(": 1 of 3")
+            .stdout()
+            .contains("ada (X) - bo (O)  X  5 moves")
+            .stdout()
+            .contains("ada won - drawn - lost: 1 - 0 - 0")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["history", "--history", file, "--opening", "1 4 2"])
+            .stdout()
+            .contains(": 1 of 3")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&[
+                "book", "--history", file, "--output", book.to_str().expect("Not UTF-8"),
+            ])
+            .stdout()
+            .contains("Self-play games: 0")
+            .stdout()
+            .contains("Recorded games: 3")
+            .unwrap();
+
+        fs::remove_file(path).expect("Undeletable history");
+        fs::remove_file(book).expect("Undeletable book");
+    }
+
+    #[test]
+    fn it_replays_a_game_from_the_history() {
+        let path = env::temp_dir().join("ttt_runner_test_replay.txt");
+        let file = path.to_str().expect("Not UTF-8");
+        let _ = fs::remove_file(&path);
+
+        assert_cli::Assert::main_binary()
+            .with_args(&["--history", file])
+            .stdin("\n1\n1\n4\n2\n5\n3\nn\n")
+            .unwrap();
+        let text = fs::read_to_string(&path).expect("Unreadable history");
+        fs::write(&path, format!("{}{}", text, &text[..40])).expect("Unwritable history");
+        assert_cli::Assert::main_binary()
+            .with_args(&["replay", "--history", file, "--delay", "1"])
+            .stdin("3\na\nq\n")
+            .stdout()
+            .contains("Unreadable lines skipped in ")
+            .stdout()
+            .contains(": 2.")
+            .stdout()
+            .contains("Replay of guest (X) - guest (O), standard, ")
+            .stdout()
+            .contains("Move 3 of 5: X 2")
+            .stdout()
+            .contains("Analysis for O: best 3 ")
+            .stdout()
+            .contains("X wins!!!")
+            .unwrap();
+        assert_cli::Assert::main_binary()
+            .with_args(&["replay", "--history", file, "--game", "2"])
+            .fails()
+            .stdout()
+            .contains("has no game 2.")
+            .unwrap();
+
+        fs::remove_file(path).expect("Undeletable history");
+    }
+
+    #[test]
+    fn it_rejects_unknown_variant() {
+        assert_cli::Assert::main_binary()
+            .with_args(&["--variant", "chess"])
+            .fails()
+            .stdout()
+            .contains(Usage.to_str())
+            .unwrap();
+    }
 }